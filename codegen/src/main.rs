@@ -1,4 +1,4 @@
-use crate::schema::{ModelInfo, Parameter, ServiceInfo, StateVariable};
+use crate::schema::{ModelInfo, Parameter, ServiceInfo, StateVariable, ValueRange};
 use inflector::Inflector;
 use serde::Deserialize;
 use serde_json::Value;
@@ -115,6 +115,11 @@ impl VersionedService {
                 name: "CurrentTrackMetaData",
                 type_name: "TrackMetaData",
             },
+            Entry::Name("ValidPlayModes"),
+            Entry::Alias {
+                name: "CurrentValidPlayModes",
+                type_name: "ValidPlayModes",
+            },
             Entry::StructField {
                 containing_struct_name: "BrowseResponse",
                 name: "Result",
@@ -166,6 +171,7 @@ pub struct VersionedAction {
     pub name: String,
     pub inputs: Vec<VersionedParameter>,
     pub outputs: Vec<VersionedParameter>,
+    pub supported_by: BTreeSet<String>,
 }
 
 #[derive(Debug, Eq, PartialEq)]
@@ -219,6 +225,63 @@ fn merge_allowed_values(target: &mut Option<Value>, source: &Option<Value>) {
     }
 }
 
+/// Models may declare different ranges for the same variable;
+/// use the widest range, and only keep the step if they agree
+fn merge_allowed_value_range(target: &mut Option<ValueRange>, source: &Option<ValueRange>) {
+    match (target.as_mut(), source) {
+        (Some(target), Some(source)) => {
+            target.minimum = target.minimum.min(source.minimum);
+            target.maximum = target.maximum.max(source.maximum);
+            if target.step != source.step {
+                target.step = None;
+            }
+        }
+        (None, Some(source)) => {
+            target.replace(source.clone());
+        }
+        (_, None) => {}
+    }
+}
+
+/// Emits `NAME_MIN`, `NAME_MAX` and `NAME_STEP` constants for a
+/// numeric state variable that declares an `allowedValueRange`
+fn emit_value_range_consts(types: &mut String, name: &str, sv: &StateVariable) {
+    let Some(range) = &sv.allowed_value_range else {
+        return;
+    };
+    let ty = match sv.data_type.as_str() {
+        "ui4" => "u32",
+        "ui2" => "u16",
+        "i4" => "i32",
+        "i2" => "i16",
+        _ => return,
+    };
+    let const_name = refine_name(name).to_screaming_snake_case();
+    let ValueRange {
+        minimum,
+        maximum,
+        step,
+    } = range;
+
+    writeln!(
+        types,
+        "/// The minimum value of `{name}` declared by the device descriptions
+        pub const {const_name}_MIN: {ty} = {minimum};
+        /// The maximum value of `{name}` declared by the device descriptions
+        pub const {const_name}_MAX: {ty} = {maximum};"
+    )
+    .ok();
+    if let Some(step) = step {
+        writeln!(
+            types,
+            "/// The step between values of `{name}` declared by the device descriptions
+            pub const {const_name}_STEP: {ty} = {step};"
+        )
+        .ok();
+    }
+    writeln!(types).ok();
+}
+
 #[derive(Deserialize, Debug)]
 struct Documentation {
     services: BTreeMap<String, ServiceDocs>,
@@ -286,6 +349,11 @@ const LAST_NS: &[LastMeta] = &[
 ];
 
 fn main() {
+    std::fs::write("../src/generated.rs", generate()).unwrap();
+}
+
+/// Produces the unformatted content of `src/generated.rs`
+fn generate() -> String {
     let mut models = BTreeMap::new();
     let docs: Documentation =
         serde_json::from_slice(&std::fs::read("data/documentation.json").unwrap()).unwrap();
@@ -326,6 +394,10 @@ fn main() {
                 // it will be a runtime error if the model doesn't support it.
                 var_entry.send_events = var_entry.send_events || var.send_events;
                 merge_allowed_values(&mut var_entry.allowed_values, &var.allowed_values);
+                merge_allowed_value_range(
+                    &mut var_entry.allowed_value_range,
+                    &var.allowed_value_range,
+                );
             }
 
             for action in &service.actions {
@@ -337,7 +409,9 @@ fn main() {
                             name: action.name.clone(),
                             inputs: vec![],
                             outputs: vec![],
+                            supported_by: BTreeSet::new(),
                         });
+                action_entry.supported_by.insert(info.model.to_string());
                 apply_parameter(&mut action_entry.inputs, &action.inputs, &info.model);
                 apply_parameter(&mut action_entry.outputs, &action.outputs, &info.model);
             }
@@ -348,6 +422,10 @@ fn main() {
     let mut types = String::new();
     let mut impls = String::new();
     let mut prelude = String::new();
+    let mut supported = String::new();
+    let mut known_services = String::new();
+    let mut known_service_types = String::new();
+    let mut known_service_from_types = String::new();
 
     for (service_name, service) in &services {
         let service_module = to_snake_case(service_name);
@@ -385,6 +463,45 @@ use super::*;
         )
         .ok();
 
+        for (name, sv) in &service.state_variables {
+            emit_value_range_consts(&mut types, name, sv);
+        }
+
+        writeln!(
+            &mut types,
+            "/// The device models that are known to support each action
+            /// of this service, sorted by action name.
+            /// The models are identified by their `modelNumber`.
+            pub const ACTION_MODELS: &[(&str, &[&str])] = &["
+        )
+        .ok();
+        for (action_name, action) in &service.actions {
+            let models = action
+                .supported_by
+                .iter()
+                .map(|m| format!("{m:?}"))
+                .collect::<Vec<_>>()
+                .join(", ");
+            writeln!(&mut types, "(\"{action_name}\", &[{models}]),").ok();
+        }
+        writeln!(&mut types, "];\n").ok();
+        writeln!(
+            &mut supported,
+            "{service_module}::SERVICE_TYPE => {service_module}::ACTION_MODELS,"
+        )
+        .ok();
+        writeln!(&mut known_services, "{service_name},").ok();
+        writeln!(
+            &mut known_service_types,
+            "Self::{service_name} => {service_module}::SERVICE_TYPE,"
+        )
+        .ok();
+        writeln!(
+            &mut known_service_from_types,
+            "{service_module}::SERVICE_TYPE => Some(Self::{service_name}),"
+        )
+        .ok();
+
         let mut event_fields = BTreeMap::new();
         for (name, sv) in &service.state_variables {
             if sv.send_events {
@@ -410,7 +527,7 @@ use super::*;
                     for p in &action.inputs {
                         let field_name = to_snake_case(&p.param.name);
                         let field_type =
-                            service.resolve_type_for_param(&p, false, &request_type_name);
+                            service.resolve_type_for_param(p, false, &request_type_name);
 
                         if let Some(doc) = docs
                             .services
@@ -430,6 +547,47 @@ use super::*;
                         writeln!(&mut types, "  pub {field_name}: {field_type},").ok();
                     }
                     writeln!(&mut types, "}}\n").ok();
+
+                    // Provide a constructor for the more verbose request
+                    // types.  InstanceID is almost always 0 for Sonos, so
+                    // it is defaulted rather than being a parameter.
+                    if action.inputs.len() > 3 {
+                        let mut params = vec![];
+                        let mut fields = vec![];
+                        for p in &action.inputs {
+                            let field_name = to_snake_case(&p.param.name);
+                            if p.param.name == "InstanceID" {
+                                fields.push(format!("{field_name}: 0"));
+                            } else {
+                                let field_type =
+                                    service.resolve_type_for_param(p, false, &request_type_name);
+                                params.push(format!("{field_name}: {field_type}"));
+                                fields.push(field_name);
+                            }
+                        }
+                        let allow = if params.len() > 7 {
+                            "#[allow(clippy::too_many_arguments)]\n"
+                        } else {
+                            ""
+                        };
+                        let doc = if params.len() < action.inputs.len() {
+                            "/// Constructs a request with `instance_id` set to 0"
+                        } else {
+                            "/// Constructs a request from its fields"
+                        };
+                        let params = params.join(", ");
+                        let fields = fields.join(", ");
+                        writeln!(
+                            &mut types,
+                            "impl {request_type_name} {{
+                                {doc}
+                                {allow}pub fn new({params}) -> Self {{
+                                    Self {{ {fields} }}
+                                }}
+                            }}\n"
+                        )
+                        .ok();
+                    }
                 }
                 format!("{service_module}::{request_type_name}")
             };
@@ -447,7 +605,7 @@ use super::*;
                 writeln!(&mut types, "pub struct {response_type_name} {{").ok();
                 for p in &action.outputs {
                     let field_name = to_snake_case(&p.param.name);
-                    let field_type = service.resolve_type_for_param(&p, true, &response_type_name);
+                    let field_type = service.resolve_type_for_param(p, true, &response_type_name);
                     writeln!(
                         &mut types,
                         "  #[xml(rename=\"{}\", ns(\"\"))]",
@@ -462,7 +620,8 @@ use super::*;
                     "
 impl crate::DecodeSoapResponse for {response_type_name} {{
     fn decode_soap_xml(xml: &str) -> crate::Result<Self> {{
-        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)
+            .map_err(|error| crate::Error::response_decode(\"{service_module}::{response_type_name}\", error, xml))?;
         Ok(envelope.body.payload)
     }}
 }}
@@ -479,7 +638,7 @@ impl crate::DecodeSoapResponse for {response_type_name} {{
             };
 
             let encode_payload = if !action.inputs.is_empty() {
-                format!("request")
+                "request".to_string()
             } else {
                 "crate::soap::Unit{}".to_string()
             };
@@ -502,7 +661,7 @@ impl crate::DecodeSoapResponse for {response_type_name} {{
                 "async fn {method_name}(&self{params}) -> Result<{response_type_name}> {{"
             )
             .ok();
-            writeln!(&mut impls, "  self.action(&{service_module}::SERVICE_TYPE, \"{action_name}\", {encode_payload}).await").ok();
+            writeln!(&mut impls, "  self.action({service_module}::SERVICE_TYPE, \"{action_name}\", {encode_payload}).await").ok();
             writeln!(&mut impls, "}}\n").ok();
             writeln!(&mut impls).ok();
         }
@@ -531,8 +690,8 @@ pub struct {service_name}Event {{"
                     format!("Option<DecodeXmlString<{service_name}LastChangeMap>>")
                 } else {
                     service.resolve_type_for_sv(
-                        &name,
-                        &name,
+                        name,
+                        name,
                         sv,
                         true,
                         &format!("{service_name}Event"),
@@ -569,8 +728,8 @@ struct {service_name}Property {{
                     format!("Option<DecodeXmlString<{service_name}LastChangeMap>>")
                 } else {
                     service.resolve_type_for_sv(
-                        &name,
-                        &name,
+                        name,
+                        name,
                         sv,
                         true,
                         &format!("{service_name}Event"),
@@ -594,7 +753,7 @@ impl DecodeXml for {service_name}Event {{
             )
             .ok();
 
-            for (name, _sv) in &event_fields {
+            for name in event_fields.keys() {
                 let field_name = to_snake_case(name);
                 writeln!(
                     &mut types,
@@ -616,7 +775,7 @@ impl DecodeXml for {service_name}Event {{
 impl crate::SonosDevice {{
     /// Subscribe to events from the `{service_name}` service on this device
     pub async fn subscribe_{service_module}(&self) -> crate::Result<crate::upnp::EventStream<{service_name}Event>> {{
-        self.subscribe_helper(&SERVICE_TYPE).await
+        self.subscribe_helper(SERVICE_TYPE).await
     }}
 }}
 "#).ok();
@@ -656,7 +815,7 @@ struct {service_name}LastChangeRootObject {{
                     if name == "LastChange" {
                         continue;
                     }
-                    let name = refine_name(&name);
+                    let name = refine_name(name);
                     if names_done.contains(&name) {
                         continue;
                     }
@@ -703,7 +862,7 @@ struct {service_name}LastChange{name} {{
                     writeln!(
                         &mut decode_logic,
                         r#"
-result.{field_name} = item.{field_name}.and_then(|v| v.val);
+{field_name}: item.{field_name}.and_then(|v| v.val),
                     "#
                     )
                     .ok();
@@ -734,9 +893,9 @@ impl DecodeXml for {service_name}LastChangeMap {{
         let mut map = std::collections::BTreeMap::new();
 
         for item in last_change.instance {{
-            let mut result = {service_name}LastChange::default();
-
-            {decode_logic}
+            let result = {service_name}LastChange {{
+                {decode_logic}
+            }};
 
             map.insert(item.object_instance_id_, result);
         }}
@@ -781,22 +940,26 @@ impl DecodeXml for {service_name}LastChangeMap {{
                 writeln!(&mut types, "  Unspecified(String),").ok();
                 writeln!(&mut types, "}}\n").ok();
 
-                writeln!(&mut types, "impl ToString for {enum_name} {{").ok();
-                writeln!(&mut types, "fn to_string(&self) -> String {{").ok();
+                writeln!(&mut types, "impl std::fmt::Display for {enum_name} {{").ok();
+                writeln!(
+                    &mut types,
+                    "fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {{"
+                )
+                .ok();
                 writeln!(&mut types, "match self {{").ok();
 
                 for item in allowed {
                     let variant = item.to_string().to_pascal_case();
                     writeln!(
                         &mut types,
-                        "  {enum_name}::{variant} => {item}.to_string(),"
+                        "  {enum_name}::{variant} => f.write_str({item}),"
                     )
                     .ok();
                 }
 
                 writeln!(
                     &mut types,
-                    "  {enum_name}::Unspecified(s) => s.to_string(),"
+                    "  {enum_name}::Unspecified(s) => f.write_str(s),"
                 )
                 .ok();
                 writeln!(&mut types, "}}").ok();
@@ -882,10 +1045,20 @@ impl<'xml> instant_xml::FromXml<'xml> for {enum_name} {{
         }
     }
 
-    std::fs::write(
-        "../src/generated.rs",
-        format!(
-            "// This file was auto-generated by codegen! Do not edit!
+    let known_services_all = services
+        .keys()
+        .map(|name| format!("Self::{name},"))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let known_models = models
+        .keys()
+        .map(|m| format!("{m:?}"))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    format!(
+        "// This file was auto-generated by codegen! Do not edit!
 
 use std::str::FromStr;
 use crate::SonosDevice;
@@ -898,6 +1071,53 @@ use crate::xmlutil::DecodeXmlString;
 {traits}
 {impls}
 
+/// The device models for which service descriptions were available
+/// when this crate was generated, identified by their `modelNumber`.
+pub const KNOWN_MODELS: &[&str] = &[{known_models}];
+
+/// Returns the device models that are known to support `action` on
+/// the service with the specified service type, or None if either
+/// the service or the action is not known to this crate.
+/// Models that are not in `KNOWN_MODELS` may also support the action.
+pub fn action_supported_models(service_type: &str, action: &str) -> Option<&'static [&'static str]> {{
+    let table = match service_type {{
+        {supported}
+        _ => return None,
+    }};
+    table
+        .binary_search_by_key(&action, |(name, _)| name)
+        .ok()
+        .map(|idx| table[idx].1)
+}}
+
+/// The services that are known to this crate
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum KnownService {{
+    {known_services}
+}}
+
+impl KnownService {{
+    pub const ALL: &[KnownService] = &[
+        {known_services_all}
+    ];
+
+    /// Returns the service corresponding to a service type URN,
+    /// such as `urn:schemas-upnp-org:service:AVTransport:1`
+    pub fn from_service_type(service_type: &str) -> Option<Self> {{
+        match service_type {{
+            {known_service_from_types}
+            _ => None,
+        }}
+    }}
+
+    /// Returns the service type URN for this service
+    pub fn service_type(self) -> &'static str {{
+        match self {{
+            {known_service_types}
+        }}
+    }}
+}}
+
 /// The prelude makes it convenient to use the methods of `SonosDevice`.
 /// Intended usage is `use sonos::prelude::*;` and then you don't have
 /// to worry about importing the individual service traits.
@@ -905,9 +1125,7 @@ pub mod prelude {{
 {prelude}
 }}
 "
-        ),
     )
-    .unwrap();
 }
 
 fn to_snake_case(s: &str) -> String {
@@ -923,3 +1141,96 @@ fn to_snake_case(s: &str) -> String {
         result
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::io::Write;
+    use std::process::{Command, Stdio};
+
+    /// Formats the generated code the same way that `make regenerate` does
+    fn rustfmt(source: &str) -> String {
+        let mut child = Command::new("rustfmt")
+            .args([
+                "+nightly",
+                "--edition",
+                "2021",
+                "--config-path",
+                "../.rustfmt.toml",
+                "--emit",
+                "stdout",
+            ])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()
+            .expect("nightly rustfmt to be installed");
+        child
+            .stdin
+            .take()
+            .unwrap()
+            .write_all(source.as_bytes())
+            .unwrap();
+        let output = child.wait_with_output().unwrap();
+        assert!(output.status.success(), "rustfmt failed");
+        String::from_utf8(output.stdout).unwrap()
+    }
+
+    #[test]
+    fn value_range_consts() {
+        let sv = |data_type: &str, step| StateVariable {
+            name: "Volume".to_string(),
+            data_type: data_type.to_string(),
+            send_events: false,
+            allowed_values: None,
+            allowed_value_range: Some(ValueRange {
+                minimum: 0,
+                maximum: 100,
+                step,
+            }),
+        };
+
+        let mut range = sv("ui2", Some(1)).allowed_value_range;
+        merge_allowed_value_range(
+            &mut range,
+            &Some(ValueRange {
+                minimum: -10,
+                maximum: 10,
+                step: Some(2),
+            }),
+        );
+        assert_eq!(
+            range,
+            Some(ValueRange {
+                minimum: -10,
+                maximum: 100,
+                step: None,
+            })
+        );
+
+        let mut types = String::new();
+        emit_value_range_consts(&mut types, "A_ARG_TYPE_Volume", &sv("ui2", Some(1)));
+        let types = rustfmt(&types);
+        assert!(types.contains("pub const VOLUME_MIN: u16 = 0;"), "{types}");
+        assert!(
+            types.contains("pub const VOLUME_MAX: u16 = 100;"),
+            "{types}"
+        );
+        assert!(types.contains("pub const VOLUME_STEP: u16 = 1;"), "{types}");
+
+        let mut types = String::new();
+        emit_value_range_consts(&mut types, "Volume", &sv("string", None));
+        assert!(types.is_empty());
+    }
+
+    #[test]
+    fn generated_is_up_to_date() {
+        let generated = rustfmt(&generate());
+        let committed = std::fs::read_to_string("../src/generated.rs").unwrap();
+        assert!(
+            generated == committed,
+            "src/generated.rs is out of date with respect to the codegen \
+             and its data files. Run `make regenerate` from the top of the \
+             repo and commit the result. Do not edit src/generated.rs by hand!"
+        );
+    }
+}
@@ -37,6 +37,18 @@ pub struct StateVariable {
     pub send_events: bool,
     #[serde(default)]
     pub allowed_values: Option<Value>,
+    #[serde(default)]
+    pub allowed_value_range: Option<ValueRange>,
+}
+
+/// The `allowedValueRange` of a numeric state variable
+#[derive(Debug, Serialize, Deserialize, Clone, Eq, PartialEq)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct ValueRange {
+    pub minimum: i64,
+    pub maximum: i64,
+    #[serde(default)]
+    pub step: Option<i64>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Eq, PartialEq, Hash)]
@@ -1,4 +1,5 @@
 #[tokio::main]
+#[allow(clippy::result_large_err)]
 async fn main() -> sonos::Result<()> {
     env_logger::init();
 
@@ -7,8 +8,23 @@ async fn main() -> sonos::Result<()> {
         match device.name().await {
             Ok(name) => {
                 println!("{name}");
-                if let Ok(state) = device.get_zone_group_state().await {
-                    println!("{state:?}");
+                if let Ok(groups) = device.get_zone_group_state().await {
+                    let state = sonos::ZoneGroupState::from(groups);
+                    for zone in state.all_zones_flat(true) {
+                        println!(
+                            "  {} {} in group {}{}",
+                            zone.zone_name,
+                            zone.uuid,
+                            zone.group_id,
+                            if zone.is_coordinator() {
+                                " (coordinator)"
+                            } else if zone.is_satellite() {
+                                " (satellite)"
+                            } else {
+                                ""
+                            }
+                        );
+                    }
                 }
             }
             Err(err) => {
@@ -1,4 +1,5 @@
 #[tokio::main]
+#[allow(clippy::result_large_err)]
 async fn main() -> sonos::Result<()> {
     env_logger::init();
 
@@ -1,6 +1,7 @@
 use sonos::SonosDevice;
 
 #[tokio::main]
+#[allow(clippy::result_large_err)]
 async fn main() -> sonos::Result<()> {
     env_logger::init();
 
@@ -11,7 +12,7 @@ async fn main() -> sonos::Result<()> {
     //let mut events = device.subscribe_rendering_control().await?;
     //let mut events = device.subscribe_virtual_line_in().await?;
 
-    while let Some(event) = events.recv().await {
+    while let Some(event) = events.recv().await? {
         println!("{event:#?}");
     }
 
@@ -0,0 +1,271 @@
+use crate::content_directory::{BrowseResponse, ContentDirectoryEvent, SERVICE_TYPE};
+use crate::queue::{self, QueueEvent};
+use crate::{DecodeXmlString, TrackMetaData, TrackMetaDataList};
+use instant_xml::{FromXml, ToXml};
+
+/// One page of results from a ContentDirectory or Queue Browse,
+/// or a ContentDirectory Search
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct BrowseResult {
+    pub items: Vec<TrackMetaData>,
+    pub number_returned: u32,
+    /// The total number of matching items; use this together with
+    /// `number_returned` to decide whether to fetch another page
+    pub total_matches: u32,
+    pub update_id: Option<u32>,
+}
+
+impl BrowseResult {
+    fn from_fields(
+        result: Option<DecodeXmlString<TrackMetaDataList>>,
+        number_returned: Option<u32>,
+        total_matches: Option<u32>,
+        update_id: Option<u32>,
+    ) -> Self {
+        let items = result
+            .and_then(|list| list.into_inner())
+            .map(|list| list.tracks)
+            .unwrap_or_default();
+        Self {
+            number_returned: number_returned.unwrap_or(items.len() as u32),
+            total_matches: total_matches.unwrap_or(items.len() as u32),
+            items,
+            update_id,
+        }
+    }
+}
+
+impl From<BrowseResponse> for BrowseResult {
+    fn from(response: BrowseResponse) -> Self {
+        Self::from_fields(
+            response.result,
+            response.number_returned,
+            response.total_matches,
+            response.update_id,
+        )
+    }
+}
+
+impl From<queue::BrowseResponse> for BrowseResult {
+    fn from(response: queue::BrowseResponse) -> Self {
+        Self::from_fields(
+            response.result,
+            response.number_returned,
+            response.total_matches,
+            response.update_id,
+        )
+    }
+}
+
+impl QueueEvent {
+    /// Returns the UpdateID of the local queue (QueueID 0) reported
+    /// by this event, if any.  The UpdateID changes whenever the queue
+    /// is edited, so it can be compared with the `update_id` of a
+    /// previously fetched `BrowseResult` or with `queue_version`
+    /// to determine whether a cached copy of the queue is stale.
+    pub fn update_id(&self) -> Option<u32> {
+        self.last_change.as_ref()?.as_ref()?.map.get(&0)?.update_id
+    }
+
+    /// Returns the queues that this event reports as having changed.
+    /// Compare `update_id` with that of a previously fetched
+    /// `BrowseResult` to decide whether the queue needs to be browsed
+    /// again.
+    pub fn changes(&self) -> Vec<QueueChange> {
+        let Some(map) = self.last_change.as_ref().and_then(|lc| lc.as_ref()) else {
+            return vec![];
+        };
+        map.map
+            .iter()
+            .map(|(&queue_id, change)| QueueChange {
+                queue_id,
+                update_id: change.update_id,
+                curated: change.curated,
+            })
+            .collect()
+    }
+}
+
+/// Describes a change to a queue, as reported by a `QueueEvent`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QueueChange {
+    /// The id of the queue that changed; the local queue is 0
+    pub queue_id: u32,
+    /// The new UpdateID of the queue
+    pub update_id: Option<u32>,
+    /// Whether the queue is curated by a music service rather
+    /// than being editable by the user
+    pub curated: Option<bool>,
+}
+
+impl ContentDirectoryEvent {
+    /// Returns the containers that this event reports as having
+    /// changed, parsed from its `ContainerUpdateIDs`, which has the
+    /// form `FV:2,179,SQ:,12`.  Compare `update_id` with that of a
+    /// previously fetched `BrowseResult` for the same container to
+    /// decide whether a cached copy of it is stale.
+    pub fn container_updates(&self) -> Vec<ContainerUpdate> {
+        let Some(ids) = self.container_update_ids.as_deref() else {
+            return vec![];
+        };
+        let fields: Vec<&str> = ids.split(',').collect();
+        fields
+            .chunks_exact(2)
+            .filter_map(|pair| {
+                Some(ContainerUpdate {
+                    container_id: pair[0].to_string(),
+                    update_id: pair[1].parse().ok()?,
+                })
+            })
+            .collect()
+    }
+}
+
+/// Describes a change to a ContentDirectory container, such as the
+/// favorites (`FV:2`) or the saved queues (`SQ:`), as reported by
+/// a `ContentDirectoryEvent`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContainerUpdate {
+    pub container_id: String,
+    /// The new UpdateID of the container
+    pub update_id: u32,
+}
+
+impl From<SearchResponse> for BrowseResult {
+    fn from(response: SearchResponse) -> Self {
+        Self::from_fields(
+            response.result,
+            response.number_returned,
+            response.total_matches,
+            response.update_id,
+        )
+    }
+}
+
+/// The ContentDirectory Search action is not present in the
+/// service descriptions from which the generated code is produced,
+/// so its request and response are defined here by hand.
+/// Use `SonosDevice::search` rather than using this directly.
+#[derive(ToXml, Debug, Clone, PartialEq, Default)]
+#[xml(rename = "Search", ns(SERVICE_TYPE))]
+pub struct SearchRequest {
+    #[xml(rename = "ContainerID", ns(""))]
+    pub container_id: String,
+    #[xml(rename = "SearchCriteria", ns(""))]
+    pub search_criteria: String,
+    #[xml(rename = "Filter", ns(""))]
+    pub filter: String,
+    #[xml(rename = "StartingIndex", ns(""))]
+    pub starting_index: u32,
+    #[xml(rename = "RequestedCount", ns(""))]
+    pub requested_count: u32,
+    #[xml(rename = "SortCriteria", ns(""))]
+    pub sort_criteria: String,
+}
+
+#[derive(FromXml, Debug, Clone, PartialEq)]
+#[xml(rename = "SearchResponse", ns(SERVICE_TYPE))]
+pub struct SearchResponse {
+    #[xml(rename = "Result", ns(""))]
+    pub result: Option<DecodeXmlString<TrackMetaDataList>>,
+    #[xml(rename = "NumberReturned", ns(""))]
+    pub number_returned: Option<u32>,
+    #[xml(rename = "TotalMatches", ns(""))]
+    pub total_matches: Option<u32>,
+    #[xml(rename = "UpdateID", ns(""))]
+    pub update_id: Option<u32>,
+}
+
+impl crate::DecodeSoapResponse for SearchResponse {
+    fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
+        let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)
+            .map_err(|error| crate::Error::response_decode("SearchResponse", error, xml))?;
+        Ok(envelope.body.payload)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{DecodeSoapResponse, DecodeXml};
+
+    #[test]
+    fn test_search_response() {
+        let response = SearchResponse::decode_soap_xml(
+            r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:SearchResponse xmlns:u="urn:schemas-upnp-org:service:ContentDirectory:1"><Result>&lt;DIDL-Lite xmlns:dc=&quot;http://purl.org/dc/elements/1.1/&quot; xmlns:upnp=&quot;urn:schemas-upnp-org:metadata-1-0/upnp/&quot; xmlns:r=&quot;urn:schemas-rinconnetworks-com:metadata-1-0/&quot; xmlns=&quot;urn:schemas-upnp-org:metadata-1-0/DIDL-Lite/&quot;&gt;&lt;item id=&quot;S://nas/music/track.flac&quot; parentID=&quot;A:TRACKS&quot; restricted=&quot;true&quot;&gt;&lt;res protocolInfo=&quot;x-file-cifs:*:audio/flac:*&quot;&gt;x-file-cifs://nas/music/track.flac&lt;/res&gt;&lt;dc:title&gt;Track&lt;/dc:title&gt;&lt;upnp:class&gt;object.item.audioItem.musicTrack&lt;/upnp:class&gt;&lt;dc:creator&gt;Artist&lt;/dc:creator&gt;&lt;/item&gt;&lt;/DIDL-Lite&gt;</Result><NumberReturned>1</NumberReturned><TotalMatches>12</TotalMatches><UpdateID>3</UpdateID></u:SearchResponse></s:Body></s:Envelope>"#,
+        )
+        .unwrap();
+        let result = BrowseResult::from(response);
+        k9::assert_equal!(result.number_returned, 1);
+        k9::assert_equal!(result.total_matches, 12);
+        k9::assert_equal!(result.items[0].title, "Track");
+        k9::assert_equal!(result.items[0].creator.as_deref(), Some("Artist"));
+    }
+
+    #[test]
+    fn test_container_updates() {
+        let event = ContentDirectoryEvent::decode_xml(
+            r#"<e:propertyset xmlns:e="urn:schemas-upnp-org:event-1-0"><e:property><SystemUpdateID>57</SystemUpdateID></e:property><e:property><ContainerUpdateIDs>FV:2,179,SQ:,12</ContainerUpdateIDs></e:property></e:propertyset>"#,
+        )
+        .unwrap();
+        k9::assert_equal!(event.system_update_id, Some(57));
+        k9::snapshot!(
+            event.container_updates(),
+            r#"
+[
+    ContainerUpdate {
+        container_id: "FV:2",
+        update_id: 179,
+    },
+    ContainerUpdate {
+        container_id: "SQ:",
+        update_id: 12,
+    },
+]
+"#
+        );
+        k9::assert_equal!(ContentDirectoryEvent::default().container_updates(), vec![]);
+    }
+
+    #[test]
+    fn test_queue_update_id() {
+        let event = QueueEvent::decode_xml(
+            r#"<e:propertyset xmlns:e="urn:schemas-upnp-org:event-1-0"><e:property><LastChange>&lt;Event xmlns=&quot;urn:schemas-sonos-com:metadata-1-0/Queue/&quot;&gt;&lt;QueueID val=&quot;0&quot;&gt;&lt;UpdateID val=&quot;42&quot;/&gt;&lt;/QueueID&gt;&lt;/Event&gt;</LastChange></e:property></e:propertyset>"#,
+        )
+        .unwrap();
+        k9::assert_equal!(event.update_id(), Some(42));
+    }
+
+    #[test]
+    fn test_queue_changes() {
+        let event = QueueEvent::decode_xml(
+            r#"<e:propertyset xmlns:e="urn:schemas-upnp-org:event-1-0"><e:property><LastChange>&lt;Event xmlns=&quot;urn:schemas-sonos-com:metadata-1-0/Queue/&quot;&gt;&lt;QueueID val=&quot;0&quot;&gt;&lt;UpdateID val=&quot;12&quot;/&gt;&lt;Curated val=&quot;0&quot;/&gt;&lt;/QueueID&gt;&lt;QueueID val=&quot;1&quot;&gt;&lt;UpdateID val=&quot;3&quot;/&gt;&lt;Curated val=&quot;1&quot;/&gt;&lt;/QueueID&gt;&lt;/Event&gt;</LastChange></e:property></e:propertyset>"#,
+        )
+        .unwrap();
+        k9::snapshot!(
+            event.changes(),
+            "
+[
+    QueueChange {
+        queue_id: 0,
+        update_id: Some(
+            12,
+        ),
+        curated: Some(
+            false,
+        ),
+    },
+    QueueChange {
+        queue_id: 1,
+        update_id: Some(
+            3,
+        ),
+        curated: Some(
+            true,
+        ),
+    },
+]
+"
+        );
+    }
+}
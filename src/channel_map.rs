@@ -0,0 +1,169 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// The role that a device plays in a stereo pair or home theater
+/// setup, as used in a `ChannelMap`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ChannelRole {
+    /// `LF`
+    LeftFront,
+    /// `RF`
+    RightFront,
+    /// `LR`
+    LeftRear,
+    /// `RR`
+    RightRear,
+    /// `SW`
+    Subwoofer,
+    /// A code that is not otherwise known to this crate
+    Other(String),
+}
+
+impl ChannelRole {
+    fn parse(code: &str) -> Self {
+        match code {
+            "LF" => Self::LeftFront,
+            "RF" => Self::RightFront,
+            "LR" => Self::LeftRear,
+            "RR" => Self::RightRear,
+            "SW" => Self::Subwoofer,
+            other => Self::Other(other.to_string()),
+        }
+    }
+
+    pub fn code(&self) -> &str {
+        match self {
+            Self::LeftFront => "LF",
+            Self::RightFront => "RF",
+            Self::LeftRear => "LR",
+            Self::RightRear => "RR",
+            Self::Subwoofer => "SW",
+            Self::Other(code) => code,
+        }
+    }
+}
+
+impl fmt::Display for ChannelRole {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+/// The channels assigned to one device in a `ChannelMap`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChannelMapEntry {
+    pub uuid: String,
+    /// The channels that the device plays.  A member of a stereo pair
+    /// lists its channel twice (eg: `LF,LF`), whereas the main unit of
+    /// a home theater setup lists both front channels (`LF,RF`).
+    pub channels: Vec<ChannelRole>,
+}
+
+/// Describes which physical device plays which channel(s) in a stereo
+/// pair (the `ChannelMapSet` of a zone) or a home theater setup (the
+/// `HTSatChanMapSet`), eg:
+/// `RINCON_A:LF,LF;RINCON_B:RF,RF`.
+/// Use `SonosDevice::channel_map` to obtain the map for a device.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ChannelMap {
+    pub entries: Vec<ChannelMapEntry>,
+}
+
+impl ChannelMap {
+    /// Returns the map for a stereo pair of the specified devices,
+    /// eg: `RINCON_LEFT:LF,LF;RINCON_RIGHT:RF,RF`
+    pub fn stereo_pair(left_uuid: &str, right_uuid: &str) -> Self {
+        let entry = |uuid: &str, role: ChannelRole| ChannelMapEntry {
+            uuid: uuid.to_string(),
+            channels: vec![role.clone(), role],
+        };
+        Self {
+            entries: vec![
+                entry(left_uuid, ChannelRole::LeftFront),
+                entry(right_uuid, ChannelRole::RightFront),
+            ],
+        }
+    }
+
+    /// Returns the channels played by the device with the specified uuid
+    pub fn channels_for(&self, uuid: &str) -> Option<&[ChannelRole]> {
+        self.entries
+            .iter()
+            .find(|entry| entry.uuid == uuid)
+            .map(|entry| entry.channels.as_slice())
+    }
+
+    /// Returns the uuid of the first device that plays `role`
+    pub fn uuid_for(&self, role: &ChannelRole) -> Option<&str> {
+        self.entries
+            .iter()
+            .find(|entry| entry.channels.contains(role))
+            .map(|entry| entry.uuid.as_str())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl FromStr for ChannelMap {
+    type Err = crate::Error;
+
+    fn from_str(s: &str) -> crate::Result<Self> {
+        let mut entries = vec![];
+        for entry in s.split(';').filter(|entry| !entry.is_empty()) {
+            let (uuid, channels) = entry
+                .split_once(':')
+                .ok_or_else(|| crate::Error::InvalidChannelMap(s.to_string()))?;
+            entries.push(ChannelMapEntry {
+                uuid: uuid.to_string(),
+                channels: channels.split(',').map(ChannelRole::parse).collect(),
+            });
+        }
+        Ok(Self { entries })
+    }
+}
+
+impl fmt::Display for ChannelMap {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (idx, entry) in self.entries.iter().enumerate() {
+            if idx > 0 {
+                f.write_str(";")?;
+            }
+            write!(f, "{}:", entry.uuid)?;
+            for (idx, channel) in entry.channels.iter().enumerate() {
+                if idx > 0 {
+                    f.write_str(",")?;
+                }
+                write!(f, "{channel}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_channel_map() {
+        let text = "RINCON_A:LF,RF;RINCON_B:LR;RINCON_C:RR;RINCON_D:SW";
+        let map: ChannelMap = text.parse().unwrap();
+        k9::assert_equal!(map.to_string(), text);
+        k9::assert_equal!(map.uuid_for(&ChannelRole::Subwoofer), Some("RINCON_D"));
+        k9::assert_equal!(
+            map.channels_for("RINCON_A"),
+            Some([ChannelRole::LeftFront, ChannelRole::RightFront].as_slice())
+        );
+
+        let pair: ChannelMap = "RINCON_A:LF,LF;RINCON_B:RF,RF".parse().unwrap();
+        k9::assert_equal!(ChannelMap::stereo_pair("RINCON_A", "RINCON_B"), pair);
+        k9::assert_equal!(pair.uuid_for(&ChannelRole::RightFront), Some("RINCON_B"));
+        assert!(matches!(
+            "RINCON_A".parse::<ChannelMap>(),
+            Err(crate::Error::InvalidChannelMap(text)) if text == "RINCON_A"
+        ));
+        assert!("".parse::<ChannelMap>().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,170 @@
+use crate::alarm_clock::{GetTimeNowResponse, GetTimeZoneResponse};
+use crate::{Error, Result};
+use std::fmt;
+use std::str::FromStr;
+
+/// A date and time as reported by the AlarmClock service, in the
+/// `YYYY-MM-DD HH:MM:SS` form.  No time zone is associated with the
+/// value itself; see `TimeNow` for the context in which it was reported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DeviceDateTime {
+    pub year: i32,
+    pub month: u8,
+    pub day: u8,
+    pub hour: u8,
+    pub minute: u8,
+    pub second: u8,
+}
+
+impl DeviceDateTime {
+    /// Returns the number of seconds since 1970-01-01 00:00:00,
+    /// treating this value as though it were UTC
+    pub fn seconds_since_epoch(&self) -> i64 {
+        // Howard Hinnant's days_from_civil algorithm
+        let year = i64::from(self.year) - i64::from(self.month <= 2);
+        let era = year.div_euclid(400);
+        let year_of_era = year - era * 400;
+        let month = i64::from(self.month);
+        let day_of_year =
+            (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + i64::from(self.day) - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+        let days = era * 146097 + day_of_era - 719468;
+
+        days * 86400
+            + i64::from(self.hour) * 3600
+            + i64::from(self.minute) * 60
+            + i64::from(self.second)
+    }
+}
+
+impl FromStr for DeviceDateTime {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        fn parse(s: &str) -> Option<DeviceDateTime> {
+            let (date, time) = s.split_once([' ', 'T'])?;
+            let mut date = date.splitn(3, '-');
+            let mut time = time.splitn(3, ':');
+            let result = DeviceDateTime {
+                year: date.next()?.parse().ok()?,
+                month: date.next()?.parse().ok()?,
+                day: date.next()?.parse().ok()?,
+                hour: time.next()?.parse().ok()?,
+                minute: time.next()?.parse().ok()?,
+                second: time.next()?.parse().ok()?,
+            };
+            let valid = (1..=12).contains(&result.month)
+                && (1..=31).contains(&result.day)
+                && result.hour < 24
+                && result.minute < 60
+                && result.second < 60;
+            valid.then_some(result)
+        }
+
+        parse(s.trim()).ok_or_else(|| Error::InvalidDateTime(s.to_string()))
+    }
+}
+
+impl fmt::Display for DeviceDateTime {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{:04}-{:02}-{:02} {:02}:{:02}:{:02}",
+            self.year, self.month, self.day, self.hour, self.minute, self.second
+        )
+    }
+}
+
+/// The current time as reported by a device.
+/// Use `SonosDevice::get_time_now` to obtain this.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimeNow {
+    pub utc: DeviceDateTime,
+    pub local: DeviceDateTime,
+    /// The time zone rule that the device is using, as reported by
+    /// the device
+    pub time_zone: Option<String>,
+    /// Incremented by the device each time its clock configuration
+    /// changes
+    pub generation: Option<u32>,
+}
+
+impl TimeNow {
+    /// Returns the offset of local time from UTC, in seconds,
+    /// rounded to the nearest minute
+    pub fn utc_offset_seconds(&self) -> i64 {
+        let delta = self.local.seconds_since_epoch() - self.utc.seconds_since_epoch();
+        (delta as f64 / 60.).round() as i64 * 60
+    }
+}
+
+impl TryFrom<GetTimeNowResponse> for TimeNow {
+    type Error = Error;
+
+    fn try_from(response: GetTimeNowResponse) -> Result<Self> {
+        Ok(Self {
+            utc: response
+                .current_utc_time
+                .ok_or(Error::MissingResponseField("CurrentUTCTime"))?
+                .parse()?,
+            local: response
+                .current_local_time
+                .ok_or(Error::MissingResponseField("CurrentLocalTime"))?
+                .parse()?,
+            time_zone: response.current_time_zone.filter(|tz| !tz.is_empty()),
+            generation: response.current_time_generation,
+        })
+    }
+}
+
+/// The time zone configuration of a device.
+/// `index` selects one of the device's built-in time zone rules;
+/// use `AlarmClock::get_time_zone_rule` to see the rule for an index.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimeZone {
+    pub index: i32,
+    /// Whether the device automatically adjusts for daylight saving time
+    pub auto_adjust_dst: bool,
+}
+
+impl TryFrom<GetTimeZoneResponse> for TimeZone {
+    type Error = Error;
+
+    fn try_from(response: GetTimeZoneResponse) -> Result<Self> {
+        Ok(Self {
+            index: response.index.ok_or(Error::MissingResponseField("Index"))?,
+            auto_adjust_dst: response
+                .auto_adjust_dst
+                .ok_or(Error::MissingResponseField("AutoAdjustDst"))?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::DecodeSoapResponse;
+
+    #[test]
+    fn test_time_now() {
+        let response = GetTimeNowResponse::decode_soap_xml(
+            r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:GetTimeNowResponse xmlns:u="urn:schemas-upnp-org:service:AlarmClock:1"><CurrentUTCTime>2024-03-01 02:30:00</CurrentUTCTime><CurrentLocalTime>2024-02-29 18:30:01</CurrentLocalTime><CurrentTimeZone>0800</CurrentTimeZone><CurrentTimeGeneration>7</CurrentTimeGeneration></u:GetTimeNowResponse></s:Body></s:Envelope>"#,
+        )
+        .unwrap();
+        let now = TimeNow::try_from(response).unwrap();
+        k9::assert_equal!(now.utc.to_string(), "2024-03-01 02:30:00");
+        k9::assert_equal!(now.local.day, 29);
+        k9::assert_equal!(now.utc_offset_seconds(), -8 * 3600);
+        k9::assert_equal!(now.generation, Some(7));
+
+        k9::assert_equal!(
+            "1970-01-01 00:00:00"
+                .parse::<DeviceDateTime>()
+                .unwrap()
+                .seconds_since_epoch(),
+            0
+        );
+        assert!("2024-13-01 00:00:00".parse::<DeviceDateTime>().is_err());
+        assert!("yesterday".parse::<DeviceDateTime>().is_err());
+    }
+}
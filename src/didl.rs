@@ -1,5 +1,7 @@
+use crate::xmlutil::unescape_entities;
 use crate::{DecodeXml, EncodeXml, Error, Result};
 use instant_xml::{FromXml, ToXml};
+use std::borrow::Cow;
 use std::time::Duration;
 
 const XMLNS_DIDL_LITE: &str = "urn:schemas-upnp-org:metadata-1-0/DIDL-Lite/";
@@ -20,6 +22,20 @@ pub struct TrackMetaData {
     pub mime_type: Option<String>,
     pub art_url: Option<String>,
     pub class: ObjectClass,
+    /// The DIDL-Lite item id.  Items provided by music services
+    /// encode the service-specific identifier of the track here,
+    /// so it must be preserved in order to re-enqueue them.
+    /// `to_didl_string` uses `-1` when this is None.
+    pub id: Option<String>,
+    /// The DIDL-Lite parent id.
+    /// `to_didl_string` uses `-1` when this is None.
+    pub parent_id: Option<String>,
+    /// The protocolInfo of the `res` element, such as
+    /// `sonos.com-spotify:*:audio/x-spotify:*`.  When this is None,
+    /// `to_didl_string` assumes an HTTP URL of type `mime_type`.
+    pub protocol_info: Option<String>,
+    /// Identifies the music service account that provides this item
+    pub desc: Option<Desc>,
 }
 
 impl DecodeXml for TrackMetaData {
@@ -90,21 +106,62 @@ pub fn duration_to_hms(d: Duration) -> String {
 }
 
 /// Convert a string of the form `HH:MM:SS` into a Duration.
+/// The seconds field may include a fractional part, as in `HH:MM:SS.mmm`.
+/// Returns `Duration::ZERO` if the string cannot be parsed; use
+/// `try_hms_to_duration` to distinguish that case from a zero duration.
 pub fn hms_to_duration(hms: &str) -> Duration {
-    let mut result = Duration::ZERO;
+    try_hms_to_duration(hms).unwrap_or_default()
+}
 
-    for (field, factor) in hms.split(':').rev().zip(HMS_FACTORS.iter().rev()) {
-        let Ok(v) = field.parse::<u64>() else {
-            return Duration::ZERO;
-        };
-        result += Duration::from_secs(v * factor);
+/// Convert a string of the form `HH:MM:SS` into a Duration,
+/// returning None if it cannot be parsed, as is the case for
+/// `NOT_IMPLEMENTED` and other placeholder values that devices
+/// report when the position of a live stream is unknown.
+pub fn try_hms_to_duration(hms: &str) -> Option<Duration> {
+    let fields: Vec<&str> = hms.trim().split(':').collect();
+    if fields.len() > HMS_FACTORS.len() {
+        return None;
     }
 
-    result
+    let mut result = Duration::ZERO;
+    for (field, factor) in fields.iter().rev().zip(HMS_FACTORS.iter().rev()) {
+        if *factor == 1 {
+            let v = field.parse::<f64>().ok()?;
+            if !v.is_finite() || v < 0. {
+                return None;
+            }
+            result = result.checked_add(Duration::try_from_secs_f64(v).ok()?)?;
+            continue;
+        }
+        let v = field.parse::<u64>().ok()?;
+        result = result.checked_add(Duration::from_secs(v.checked_mul(*factor)?))?;
+    }
+
+    Some(result)
 }
 
 impl TrackMetaData {
+    /// Encodes the track as DIDL-Lite xml.
+    /// The `class`, `id`, `parent_id`, `protocol_info` and `desc` fields
+    /// are emitted as-is, so that metadata obtained from the device for
+    /// a service provided item can be passed back to it unchanged.
     pub fn to_didl_string(&self) -> String {
+        let res = if self.url.is_empty() && self.protocol_info.is_none() {
+            None
+        } else {
+            Some(Res {
+                protocol_info: Some(self.protocol_info.clone().unwrap_or_else(|| {
+                    // Note that this assumes that the URL is an HTTP URL
+                    format!(
+                        "http-get:*:{}",
+                        self.mime_type.as_deref().unwrap_or("audio/mpeg")
+                    )
+                })),
+                duration: self.duration.map(duration_to_hms),
+                url: self.url.to_string(),
+            })
+        };
+
         let didl = DidlLite {
             item: vec![UpnpItem {
                 queue_item_id: None,
@@ -113,18 +170,10 @@ impl TrackMetaData {
                     .clone()
                     .map(|mime_type| MimeType { mime_type }),
                 duration: None,
-                id: "-1".to_string(),
-                parent_id: "-1".to_string(),
+                id: self.id.clone().unwrap_or_else(|| "-1".to_string()),
+                parent_id: self.parent_id.clone().unwrap_or_else(|| "-1".to_string()),
                 restricted: Some(true),
-                res: Some(Res {
-                    // Note that this assumes that the URL is an HTTP URL
-                    protocol_info: Some(format!(
-                        "http-get:*:{}",
-                        self.mime_type.as_deref().unwrap_or("audio/mpeg")
-                    )),
-                    duration: self.duration.map(duration_to_hms),
-                    url: self.url.to_string(),
-                }),
+                res,
                 title: Some(Title {
                     title: self.title.to_string(),
                 }),
@@ -135,29 +184,66 @@ impl TrackMetaData {
                     .map(|album_title| AlbumTitle { album_title }),
                 creator: self.creator.clone().map(|artist| Creator { artist }),
                 artist: self.creator.clone().map(|artist| Artist { artist }),
-                class: Some(ObjectClass::MusicTrack),
+                class: Some(self.class.clone()),
+                desc: self.desc.clone(),
             }],
         };
         instant_xml::to_string(&didl).expect("infallible xml encode!?")
     }
 
+    /// Returns `art_url` as a URL that can be fetched directly.
+    /// The device usually reports album art as a path such as
+    /// `/getaa?s=1&u=...` that is served by the device itself, so
+    /// relative URLs are joined to `base`, which should be the
+    /// `SonosDevice::base_url` of the device that reported the track.
+    /// Absolute URLs are returned unchanged.
+    pub fn absolute_art_url(&self, base: &url::Url) -> Option<url::Url> {
+        let art_url = self.art_url.as_deref().filter(|url| !url.is_empty())?;
+        base.join(art_url).ok()
+    }
+
+    /// Parses DIDL-Lite xml into a list of tracks.
+    ///
+    /// When an item has both a `<res duration="HH:MM:SS">` attribute and
+    /// a `<upnp:duration>` element, the `res` duration is used: it describes
+    /// the resource that is actually being played, whereas `upnp:duration`
+    /// may be a stale value from the library that the item came from.
+    /// `upnp:duration` is used only when `res` doesn't specify a duration.
+    ///
+    /// Some sources escape the metadata one time too many; if the
+    /// document itself is still escaped it is unescaped before parsing,
+    /// and a remaining layer of entity escaping in the title, creator
+    /// and album is decoded.
     pub fn from_didl_str(didl: &str) -> Result<Vec<Self>> {
-        let didl: DidlLite = instant_xml::from_str(didl)?;
+        let didl = if didl.trim_start().starts_with("&lt;") {
+            unescape_entities(didl)
+        } else {
+            didl.into()
+        };
+        let didl: DidlLite = instant_xml::from_str(&didl).map_err(|error| Error::DidlParse {
+            error,
+            text: didl.to_string(),
+        })?;
+        let unescape = |text: String| match unescape_entities(&text) {
+            Cow::Borrowed(_) => text,
+            Cow::Owned(text) => text,
+        };
         let mut result = vec![];
         for item in didl.item {
             result.push(Self {
                 class: item.class.unwrap_or_default(),
-                album: item.album_title.map(|a| a.album_title),
-                creator: item.creator.map(|a| a.artist),
+                album: item.album_title.map(|a| unescape(a.album_title)),
+                creator: item.creator.map(|a| unescape(a.artist)),
                 art_url: item.album_art.map(|a| a.uri),
-                title: item.title.map(|a| a.title).unwrap_or_else(String::new),
-                duration: match item.duration {
-                    Some(d) => Some(Duration::from_secs(d.duration)),
-                    None => item
-                        .res
-                        .as_ref()
-                        .and_then(|r| r.duration.as_ref().map(|s| hms_to_duration(s))),
-                },
+                title: item
+                    .title
+                    .map(|a| unescape(a.title))
+                    .unwrap_or_else(String::new),
+                duration: item
+                    .res
+                    .as_ref()
+                    .and_then(|r| r.duration.as_deref().map(hms_to_duration))
+                    .or_else(|| item.duration.map(|d| Duration::from_secs(d.duration))),
                 url: item
                     .res
                     .as_ref()
@@ -167,6 +253,10 @@ impl TrackMetaData {
                     let fields: Vec<&str> = r.protocol_info.as_ref()?.split(':').collect();
                     fields.get(2).map(|mime_type| mime_type.to_string())
                 }),
+                protocol_info: item.res.as_ref().and_then(|r| r.protocol_info.clone()),
+                id: Some(item.id),
+                parent_id: Some(item.parent_id),
+                desc: item.desc,
             });
         }
         Ok(result)
@@ -199,6 +289,21 @@ pub struct UpnpItem {
     pub class: Option<ObjectClass>,
     pub mime_type: Option<MimeType>,
     pub queue_item_id: Option<QueueItemId>,
+    pub desc: Option<Desc>,
+}
+
+/// The `<desc>` element that Sonos uses to associate an item
+/// with the music service account that provides it, for example
+/// `<desc id="cdudn" nameSpace="urn:schemas-rinconnetworks-com:metadata-1-0/">SA_RINCON2311_X_#Svc2311-0-Token</desc>`
+#[derive(Debug, Clone, PartialEq, Eq, FromXml, ToXml)]
+#[xml(rename = "desc", ns(XMLNS_DIDL_LITE))]
+pub struct Desc {
+    #[xml(attribute)]
+    pub id: String,
+    #[xml(attribute, rename = "nameSpace")]
+    pub name_space: String,
+    #[xml(direct)]
+    pub value: String,
 }
 
 #[derive(Debug, FromXml, ToXml)]
@@ -282,12 +387,38 @@ pub enum ObjectClass {
     Container,
     #[xml(rename = "object.item")]
     Item,
+    /// An entry in the Sonos Favorites container (`FV:2`).  The
+    /// duplicated `itemobject` is how Sonos actually spells it.
+    #[xml(rename = "object.itemobject.item.sonos-favorite")]
+    SonosFavorite,
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
 
+    #[test]
+    fn test_absolute_art_url() {
+        let base: url::Url = "http://10.10.10.10:1400/".parse().unwrap();
+        let mut track = TrackMetaData {
+            art_url: Some("/getaa?s=1&u=x-sonos-spotify%3aabc".to_string()),
+            ..Default::default()
+        };
+        k9::assert_equal!(
+            track.absolute_art_url(&base).unwrap().as_str(),
+            "http://10.10.10.10:1400/getaa?s=1&u=x-sonos-spotify%3aabc"
+        );
+
+        track.art_url = Some("https://i.scdn.co/image/abc".to_string());
+        k9::assert_equal!(
+            track.absolute_art_url(&base).unwrap().as_str(),
+            "https://i.scdn.co/image/abc"
+        );
+
+        track.art_url = Some(String::new());
+        k9::assert_equal!(track.absolute_art_url(&base), None);
+    }
+
     #[test]
     fn test_didl() {
         let didl = DidlLite {
@@ -317,6 +448,7 @@ mod test {
                 title: Some(Title {
                     title: "Track Title".to_string(),
                 }),
+                desc: None,
             }],
         };
         k9::snapshot!(
@@ -328,7 +460,7 @@ mod test {
     #[test]
     fn test_real_didl() {
         let input = r#"<DIDL-Lite xmlns:dc="http://purl.org/dc/elements/1.1/" xmlns:upnp="urn:schemas-upnp-org:metadata-1-0/upnp/" xmlns="urn:schemas-upnp-org:metadata-1-0/DIDL-Lite/" xmlns:dlna="urn:schemas-dlna-org:metadata-1-0/"><item id="1" parentID="0" restricted="1"><dc:title>Late Nights and Sneaky Moms</dc:title><dc:creator>DJ Birchy</dc:creator><upnp:album>[Unknown Album]</upnp:album><upnp:artist>DJ Borchy</upnp:artist><upnp:duration>4364</upnp:duration><dc:queueItemId>http://192.168.1.214:8097/single/RINCON_XXX/51f8b02b9d3b4a88b97dd385ba2b572b.flac?ts=1716507641</dc:queueItemId><upnp:albumArtURI>http://192.168.1.214:8097/imageproxy?path=al-573b45a1bde2b333c07b41545898da44_59330182&amp;provider=opensubsonic--EcQ6qYKn&amp;size=0&amp;fmt=png</upnp:albumArtURI><upnp:class>object.item.audioItem.audioBroadcast</upnp:class><upnp:mimeType>audio/flac</upnp:mimeType><res duration="1:12:44.000" protocolInfo="http-get:*:audio/flac:DLNA.ORG_PN=FLAC;DLNA.ORG_OP=01;DLNA.ORG_CI=0;DLNA.ORG_FLAGS=0d500000000000000000000000000000">http://192.168.1.214:8097/single/RINCON_XXX/51f8b02b9d3b4a88b97dd385ba2b572b.flac?ts=1716507641</res></item></DIDL-Lite>"#;
-        let didl: DidlLite = instant_xml::from_str(&input).unwrap();
+        let didl: DidlLite = instant_xml::from_str(input).unwrap();
         k9::snapshot!(
             didl,
             r#"
@@ -394,6 +526,7 @@ DidlLite {
                     id: "http://192.168.1.214:8097/single/RINCON_XXX/51f8b02b9d3b4a88b97dd385ba2b572b.flac?ts=1716507641",
                 },
             ),
+            desc: None,
         },
     ],
 }
@@ -405,7 +538,7 @@ DidlLite {
     fn test_empty_album_art() {
         let input = r#"<DIDL-Lite xmlns:dc="http://purl.org/dc/elements/1.1/" xmlns:upnp="urn:schemas-upnp-org:metadata-1-0/upnp/" xmlns:r="urn:schemas-rinconnetworks-com:metadata-1-0/" xmlns="urn:schemas-upnp-org:metadata-1-0/DIDL-Lite/"><item id="00080000A%3aTRACKS" parentID="-1" restricted="true"><dc:title>Tracks</dc:title><upnp:class>object.container</upnp:class><desc id="cdudn" nameSpace="urn:schemas-rinconnetworks-com:metadata-1-0/"></desc><upnp:albumArtURI></upnp:albumArtURI></item></DIDL-Lite>"#;
 
-        let didl: DidlLite = instant_xml::from_str(&input).unwrap();
+        let didl: DidlLite = instant_xml::from_str(input).unwrap();
         k9::snapshot!(
             didl,
             r#"
@@ -437,6 +570,13 @@ DidlLite {
             ),
             mime_type: None,
             queue_item_id: None,
+            desc: Some(
+                Desc {
+                    id: "cdudn",
+                    name_space: "urn:schemas-rinconnetworks-com:metadata-1-0/",
+                    value: "",
+                },
+            ),
         },
     ],
 }
@@ -454,5 +594,119 @@ DidlLite {
         r("00:02:31", 151);
         r("01:00:31", 3631);
         r("3:01:00:31", 262831);
+
+        assert_eq!(hms_to_duration("1:12:44.000"), Duration::from_secs(4364));
+        assert_eq!(hms_to_duration("0:00:01.500"), Duration::from_millis(1500));
+        assert_eq!(try_hms_to_duration("0:00:00"), Some(Duration::ZERO));
+        assert_eq!(try_hms_to_duration("NOT_IMPLEMENTED"), None);
+        assert_eq!(try_hms_to_duration("-0:00:01"), None);
+        assert_eq!(try_hms_to_duration(""), None);
+        assert_eq!(try_hms_to_duration("0:0:1e30"), None);
+        assert_eq!(try_hms_to_duration("5124095576030431:59:0"), None);
+        assert_eq!(hms_to_duration("NOT_IMPLEMENTED"), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_duration_prefers_res() {
+        let input = r#"<DIDL-Lite xmlns:dc="http://purl.org/dc/elements/1.1/" xmlns:upnp="urn:schemas-upnp-org:metadata-1-0/upnp/" xmlns="urn:schemas-upnp-org:metadata-1-0/DIDL-Lite/"><item id="1" parentID="0" restricted="1"><dc:title>Title</dc:title><upnp:duration>4364</upnp:duration><res duration="0:03:20.000" protocolInfo="http-get:*:audio/flac">http://track.flac</res></item></DIDL-Lite>"#;
+        let tracks = TrackMetaData::from_didl_str(input).unwrap();
+        assert_eq!(tracks[0].duration, Some(Duration::from_secs(200)));
+
+        let input = r#"<DIDL-Lite xmlns:dc="http://purl.org/dc/elements/1.1/" xmlns:upnp="urn:schemas-upnp-org:metadata-1-0/upnp/" xmlns="urn:schemas-upnp-org:metadata-1-0/DIDL-Lite/"><item id="1" parentID="0" restricted="1"><dc:title>Title</dc:title><upnp:duration>4364</upnp:duration><res protocolInfo="http-get:*:audio/flac">http://track.flac</res></item></DIDL-Lite>"#;
+        let tracks = TrackMetaData::from_didl_str(input).unwrap();
+        assert_eq!(tracks[0].duration, Some(Duration::from_secs(4364)));
+    }
+
+    #[test]
+    fn test_service_item_round_trip() {
+        let input = r#"<DIDL-Lite xmlns:dc="http://purl.org/dc/elements/1.1/" xmlns:upnp="urn:schemas-upnp-org:metadata-1-0/upnp/" xmlns:r="urn:schemas-rinconnetworks-com:metadata-1-0/" xmlns="urn:schemas-upnp-org:metadata-1-0/DIDL-Lite/"><item id="00032020spotify%3atrack%3a4uLU6hMCjMI75M1A2tKUQC" parentID="-1" restricted="true"><res protocolInfo="sonos.com-spotify:*:audio/x-spotify:*">x-sonos-spotify:spotify%3atrack%3a4uLU6hMCjMI75M1A2tKUQC?sid=12&amp;flags=8224&amp;sn=1</res><dc:title>Never Gonna Give You Up</dc:title><upnp:class>object.item.audioItem.musicTrack</upnp:class><desc id="cdudn" nameSpace="urn:schemas-rinconnetworks-com:metadata-1-0/">SA_RINCON2311_X_#Svc2311-0-Token</desc></item></DIDL-Lite>"#;
+        let track = TrackMetaData::from_didl_str(input).unwrap().pop().unwrap();
+        k9::snapshot!(
+            &track.desc,
+            r#"
+Some(
+    Desc {
+        id: "cdudn",
+        name_space: "urn:schemas-rinconnetworks-com:metadata-1-0/",
+        value: "SA_RINCON2311_X_#Svc2311-0-Token",
+    },
+)
+"#
+        );
+
+        let encoded = track.to_didl_string();
+        k9::snapshot!(
+            &encoded,
+            r#"<DIDL-Lite xmlns="urn:schemas-upnp-org:metadata-1-0/DIDL-Lite/" xmlns:dc="http://purl.org/dc/elements/1.1/" xmlns:r="urn:schemas-rinconnetworks-com:metadata-1-0/" xmlns:upnp="urn:schemas-upnp-org:metadata-1-0/upnp/"><item id="00032020spotify%3atrack%3a4uLU6hMCjMI75M1A2tKUQC" parentID="-1" restricted="true"><res protocolInfo="sonos.com-spotify:*:audio/x-spotify:*">x-sonos-spotify:spotify%3atrack%3a4uLU6hMCjMI75M1A2tKUQC?sid=12&amp;flags=8224&amp;sn=1</res><dc:title>Never Gonna Give You Up</dc:title><upnp:class>object.item.audioItem.musicTrack</upnp:class><upnp:mimeType>audio/x-spotify</upnp:mimeType><desc id="cdudn" nameSpace="urn:schemas-rinconnetworks-com:metadata-1-0/">SA_RINCON2311_X_#Svc2311-0-Token</desc></item></DIDL-Lite>"#
+        );
+
+        let decoded = TrackMetaData::from_didl_str(&encoded)
+            .unwrap()
+            .pop()
+            .unwrap();
+        k9::assert_equal!(decoded, track);
+    }
+
+    #[test]
+    fn test_double_escaped() {
+        let input = r#"<DIDL-Lite xmlns:dc="http://purl.org/dc/elements/1.1/" xmlns:upnp="urn:schemas-upnp-org:metadata-1-0/upnp/" xmlns:r="urn:schemas-rinconnetworks-com:metadata-1-0/" xmlns="urn:schemas-upnp-org:metadata-1-0/DIDL-Lite/"><item id="-1" parentID="-1" restricted="true"><res protocolInfo="http-get:*:audio/mpeg:*">http://example.com/track.mp3?a=1&amp;b=2</res><dc:title>Rock &amp;amp; Roll Ain&amp;#39;t Noise Pollution</dc:title><dc:creator>AC&amp;amp;DC</dc:creator><upnp:class>object.item.audioItem.musicTrack</upnp:class></item></DIDL-Lite>"#;
+        let track = TrackMetaData::from_didl_str(input).unwrap().pop().unwrap();
+        k9::assert_equal!(track.title, "Rock & Roll Ain't Noise Pollution");
+        k9::assert_equal!(track.creator.as_deref(), Some("AC&DC"));
+        k9::assert_equal!(track.url, "http://example.com/track.mp3?a=1&b=2");
+
+        // The whole document arriving still escaped
+        let escaped = input
+            .replace('&', "&amp;")
+            .replace('<', "&lt;")
+            .replace('>', "&gt;")
+            .replace('"', "&quot;");
+        let track = TrackMetaData::from_didl_str(&escaped)
+            .unwrap()
+            .pop()
+            .unwrap();
+        k9::assert_equal!(track.title, "Rock & Roll Ain't Noise Pollution");
+    }
+
+    #[test]
+    fn test_didl_parse_error() {
+        let input = "<DIDL-Lite><item id=\"1\"></DIDL-Lite>";
+        match TrackMetaData::from_didl_str(input) {
+            Err(Error::DidlParse { text, .. }) => {
+                k9::assert_equal!(text, input);
+            }
+            other => panic!("expected DidlParse, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_favorites_per_item_desc() {
+        // Each favorite carries its own desc identifying its music service
+        // account; the second uses a locally declared prefix for the
+        // DIDL-Lite namespace
+        let input = r#"<DIDL-Lite xmlns:dc="http://purl.org/dc/elements/1.1/" xmlns:upnp="urn:schemas-upnp-org:metadata-1-0/upnp/" xmlns:r="urn:schemas-rinconnetworks-com:metadata-1-0/" xmlns="urn:schemas-upnp-org:metadata-1-0/DIDL-Lite/"><item id="FV:2/5" parentID="FV:2" restricted="false"><dc:title>Radio Paradise</dc:title><upnp:class>object.itemobject.item.sonos-favorite</upnp:class><r:type>instantPlay</r:type><res protocolInfo="x-sonosapi-stream:*:*:*">x-sonosapi-stream:s13606?sid=254&amp;flags=8224&amp;sn=0</res><r:description>TuneIn Station</r:description><desc id="cdudn" nameSpace="urn:schemas-rinconnetworks-com:metadata-1-0/">SA_RINCON65031_</desc></item><item id="FV:2/7" parentID="FV:2" restricted="false"><dc:title>Discover Weekly</dc:title><upnp:class>object.itemobject.item.sonos-favorite</upnp:class><r:type>instantPlay</r:type><res protocolInfo="x-rincon-cpcontainer:*:*:*">x-rincon-cpcontainer:1006206cspotify%3aplaylist%3a37i9dQZEVXcQ?sid=12&amp;flags=8300&amp;sn=1</res><d:desc xmlns:d="urn:schemas-upnp-org:metadata-1-0/DIDL-Lite/" id="cdudn" nameSpace="urn:schemas-rinconnetworks-com:metadata-1-0/">SA_RINCON2311_X_#Svc2311-0-Token</d:desc></item></DIDL-Lite>"#;
+        let tracks = TrackMetaData::from_didl_str(input).unwrap();
+        k9::snapshot!(
+            tracks
+                .iter()
+                .map(|t| (t.title.as_str(), t.desc.as_ref().map(|d| d.value.as_str())))
+                .collect::<Vec<_>>(),
+            r#"
+[
+    (
+        "Radio Paradise",
+        Some(
+            "SA_RINCON65031_",
+        ),
+    ),
+    (
+        "Discover Weekly",
+        Some(
+            "SA_RINCON2311_X_#Svc2311-0-Token",
+        ),
+    ),
+]
+"#
+        );
     }
 }
@@ -1,8 +1,11 @@
-use crate::{Result, SonosDevice};
-use std::collections::BTreeMap;
+use crate::{Error, Result, SonosDevice};
+use std::collections::{BTreeMap, HashSet};
+use std::net::{IpAddr, Ipv4Addr};
+use std::sync::Arc;
 use std::time::Duration;
 use tokio::net::UdpSocket;
 use tokio::sync::mpsc::{channel, Receiver};
+use tokio::sync::Mutex;
 
 /// URN identifying Sonos ZonePlayer compatible products.
 /// This is used internally by the `discover` function but is
@@ -10,79 +13,335 @@ use tokio::sync::mpsc::{channel, Receiver};
 /// own custom discovery functionality.
 pub const SONOS_URN: &str = "urn:schemas-upnp-org:device:ZonePlayer:1";
 
+/// The port on which Sonos devices serve their UPnP description
+/// and control endpoints
+const DEVICE_PORT: u16 = 1400;
+
+/// Reports the local address that the host uses to reach the device
+/// at `sample_device_ip`, by connecting to it and observing which
+/// interface was selected.
+/// This is useful on multi-homed machines, for diagnosing discovery
+/// problems and for choosing the address to pass to
+/// `SonosDevice::set_subscription_local_ip`.
+pub async fn probe_local_interface(sample_device_ip: Ipv4Addr) -> Result<Ipv4Addr> {
+    match crate::upnp::local_ip_for(sample_device_ip.into(), DEVICE_PORT).await? {
+        IpAddr::V4(v4) => Ok(v4),
+        IpAddr::V6(v6) => v6.to_ipv4_mapped().ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::AddrNotAvailable,
+                format!("{sample_device_ip} was reached via IPv6 address {v6}"),
+            )
+            .into()
+        }),
+    }
+}
+
 /// Discover SonosDevices on the network, stopping once the specified
 /// timeout is reached.
 /// Returns a channel that will yield `SonosDevice` instances as responses
 /// to discovery requests are detected.
-/// Note that it is possible (likely) for duplicates to be returned.
+/// Repeated responses from the same device are ignored, using the
+/// uuid from its `USN`, so each device is normally yielded only once.
 pub async fn discover(timeout: Duration) -> Result<Receiver<SonosDevice>> {
-    const MX: usize = 3;
+    discover_matching(timeout, |_| true).await
+}
 
-    let timeout = if timeout.as_secs() as usize <= MX {
-        Duration::from_secs(MX as u64 + 1)
-    } else {
-        timeout
-    };
+/// Discover a single SonosDevice on the network.
+/// Returns the first device that responds, or `Error::NoDevicesFound`
+/// if none respond before the specified timeout is reached.
+/// Discovery stops as soon as a device has been found.
+pub async fn discover_any(timeout: Duration) -> Result<SonosDevice> {
+    let mut rx = discover(timeout).await?;
+    rx.recv().await.ok_or(Error::NoDevicesFound)
+}
+
+/// Discover SonosDevices on the network, waiting until the specified
+/// timeout is reached and then returning all of the devices that
+/// were found.
+/// Unlike `discover`, devices are also deduplicated by the uuid
+/// in their device description, so each device appears only once.
+pub async fn discover_collect(timeout: Duration) -> Result<Vec<SonosDevice>> {
+    let mut rx = discover(timeout).await?;
+    let mut seen = HashSet::new();
+    let mut devices = vec![];
+    while let Some(device) = rx.recv().await {
+        let key = match device.device_spec().uuid() {
+            Some(uuid) => uuid.to_string(),
+            None => device.url().to_string(),
+        };
+        if seen.insert(key) {
+            devices.push(device);
+        }
+    }
+    Ok(devices)
+}
+
+/// Discover SonosDevices on the network that satisfy `predicate`,
+/// stopping once the specified timeout is reached.
+/// The predicate is evaluated by the discovery task as each device
+/// responds, and devices for which it returns false are not sent
+/// to the returned channel.
+/// The predicate should be cheap; `SonosDevice::device_spec` is
+/// already populated, so eg: `device_spec().room_name` can be
+/// tested without further network I/O.
+/// As with `discover`, dropping the receiver stops discovery, so
+/// a caller that only needs the first match can stop early.
+pub async fn discover_matching<F>(timeout: Duration, predicate: F) -> Result<Receiver<SonosDevice>>
+where
+    F: Fn(&SonosDevice) -> bool + Send + 'static,
+{
+    run_discovery(DiscoveryOptions::new(timeout), predicate).await
+}
+
+/// Controls the behavior of `discover_with_options`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiscoveryOptions {
+    /// How long to wait for responses
+    pub timeout: Duration,
+    /// The multicast TTL for the search request.  The default of 2
+    /// keeps the search local; raise it to reach devices that are
+    /// one or more router hops away, such as on another VLAN.
+    pub multicast_ttl: u32,
+}
+
+impl DiscoveryOptions {
+    pub const DEFAULT_MULTICAST_TTL: u32 = 2;
+
+    /// Returns the default options with the specified timeout
+    pub fn new(timeout: Duration) -> Self {
+        Self {
+            timeout,
+            multicast_ttl: Self::DEFAULT_MULTICAST_TTL,
+        }
+    }
+}
+
+impl Default for DiscoveryOptions {
+    fn default() -> Self {
+        Self::new(Duration::from_secs(15))
+    }
+}
+
+/// Discover SonosDevices on the network, as `discover` does,
+/// but with additional control over how the search is performed.
+pub async fn discover_with_options(options: DiscoveryOptions) -> Result<Receiver<SonosDevice>> {
+    run_discovery(options, |_| true).await
+}
+
+async fn run_discovery<F>(options: DiscoveryOptions, predicate: F) -> Result<Receiver<SonosDevice>>
+where
+    F: Fn(&SonosDevice) -> bool + Send + 'static,
+{
+    let discovery = Discovery::bind().await?;
+    discovery.set_multicast_ttl(options.multicast_ttl).ok();
+    discovery.search_matching(options.timeout, predicate).await
+}
+
+/// A long-lived alternative to `discover` that owns its socket, so
+/// that applications that periodically re-scan the network can do
+/// so without binding a new socket for each search.
+pub struct Discovery {
+    socket: Arc<UdpSocket>,
+    /// Held by the task that services a search, so that only one
+    /// search at a time is reading responses from the socket
+    active: Arc<Mutex<()>>,
+}
+
+impl Discovery {
+    /// Binds the socket used for discovery
+    pub async fn bind() -> Result<Self> {
+        let socket = UdpSocket::bind("0.0.0.0:0").await?;
+        socket
+            .set_multicast_ttl_v4(DiscoveryOptions::DEFAULT_MULTICAST_TTL)
+            .ok();
+        Ok(Self {
+            socket: Arc::new(socket),
+            active: Arc::new(Mutex::new(())),
+        })
+    }
+
+    /// Sets the multicast TTL for subsequent searches.
+    /// See `DiscoveryOptions::multicast_ttl` for more information.
+    pub fn set_multicast_ttl(&self, ttl: u32) -> Result<()> {
+        Ok(self.socket.set_multicast_ttl_v4(ttl)?)
+    }
+
+    /// Searches for SonosDevices, as `discover` does.
+    /// If a previous search is still in progress, this waits for it
+    /// to complete before starting; drop the receiver of the previous
+    /// search to end it early.
+    pub async fn search(&self, timeout: Duration) -> Result<Receiver<SonosDevice>> {
+        self.search_matching(timeout, |_| true).await
+    }
 
-    let disco_packet = format!(
-        "M-SEARCH * HTTP/1.1\r\n\
-        HOST: 239.255.255.250:1900\r\n\
-        MAN: ssdp:discover\r\n\
-        MX: {MX}\r\n\
-        ST: {SONOS_URN}\r\n\r\n"
-    );
-    const DEFAULT_SEARCH_TTL: u32 = 2;
-
-    let socket = UdpSocket::bind("0.0.0.0:0").await?;
-    socket.set_multicast_ttl_v4(DEFAULT_SEARCH_TTL).ok();
-    socket
-        .send_to(disco_packet.as_bytes(), "239.255.255.250:1900")
-        .await?;
-
-    let deadline = tokio::time::Instant::now() + timeout;
-
-    let (tx, rx) = channel(8);
-
-    tokio::spawn(async move {
-        let mut buf = [0u8; 2048];
-
-        loop {
-            match tokio::time::timeout_at(deadline, socket.recv_from(&mut buf)).await {
-                Ok(Ok((n_read, peer))) => {
-                    let buf = &buf[0..n_read];
-                    let buf = String::from_utf8_lossy(&buf);
-                    log::trace!("DISCO: ({peer:?}) {buf}");
-                    let mut headers: BTreeMap<String, String> = BTreeMap::new();
-                    for line in buf.lines() {
-                        let Some((name, value)) = line.split_once(':') else {
-                            continue;
-                        };
-
-                        headers.insert(name.trim().to_ascii_lowercase(), value.trim().to_string());
+    /// Searches for SonosDevices that satisfy `predicate`, as
+    /// `discover_matching` does.
+    /// See `search` for how this interacts with a previous search.
+    pub async fn search_matching<F>(
+        &self,
+        timeout: Duration,
+        predicate: F,
+    ) -> Result<Receiver<SonosDevice>>
+    where
+        F: Fn(&SonosDevice) -> bool + Send + 'static,
+    {
+        const MX: usize = 3;
+
+        let timeout = if timeout.as_secs() as usize <= MX {
+            Duration::from_secs(MX as u64 + 1)
+        } else {
+            timeout
+        };
+
+        let disco_packet = format!(
+            "M-SEARCH * HTTP/1.1\r\n\
+            HOST: 239.255.255.250:1900\r\n\
+            MAN: ssdp:discover\r\n\
+            MX: {MX}\r\n\
+            ST: {SONOS_URN}\r\n\r\n"
+        );
+
+        let active = Arc::clone(&self.active).lock_owned().await;
+
+        self.socket
+            .send_to(disco_packet.as_bytes(), "239.255.255.250:1900")
+            .await?;
+
+        let deadline = tokio::time::Instant::now() + timeout;
+        let socket = Arc::clone(&self.socket);
+
+        let (tx, rx) = channel(8);
+
+        tokio::spawn(async move {
+            let _active = active;
+            let mut buf = [0u8; 2048];
+            let mut seen = HashSet::new();
+
+            loop {
+                let received = tokio::select! {
+                    received = tokio::time::timeout_at(deadline, socket.recv_from(&mut buf)) => received,
+                    _ = tx.closed() => {
+                        // The caller is no longer interested; allow
+                        // another search to begin
+                        log::trace!("Discovery receiver was dropped");
+                        break;
                     }
-                    log::trace!("Headers: {headers:?}");
-
-                    match (headers.get("st"), headers.get("location")) {
-                        (Some(st), Some(url)) if st == SONOS_URN => {
-                            if let Ok(url) = url.parse() {
-                                if let Ok(device) = SonosDevice::from_url(url).await {
-                                    if tx.send(device).await.is_err() {
-                                        break;
+                };
+                match received {
+                    Ok(Ok((n_read, peer))) => {
+                        let buf = &buf[0..n_read];
+                        let buf = String::from_utf8_lossy(buf);
+                        log::trace!("DISCO: ({peer:?}) {buf}");
+                        let mut headers: BTreeMap<String, String> = BTreeMap::new();
+                        for line in buf.lines() {
+                            let Some((name, value)) = line.split_once(':') else {
+                                continue;
+                            };
+
+                            headers
+                                .insert(name.trim().to_ascii_lowercase(), value.trim().to_string());
+                        }
+                        log::trace!("Headers: {headers:?}");
+
+                        match (headers.get("st"), headers.get("location")) {
+                            (Some(st), Some(url)) if st == SONOS_URN => {
+                                // Devices can respond more than once, so
+                                // identify them by the uuid in their USN,
+                                // falling back to the location, to avoid
+                                // fetching the same description repeatedly
+                                let key = match headers.get("usn").and_then(|usn| parse_usn(usn)) {
+                                    Some(usn) if !usn.is_root_zone_player() => {
+                                        log::trace!("Ignoring non-ZonePlayer USN {usn:?}");
+                                        continue;
+                                    }
+                                    Some(usn) => usn.uuid.to_string(),
+                                    None => url.to_string(),
+                                };
+                                if seen.contains(&key) {
+                                    continue;
+                                }
+                                if let Ok(url) = url.parse() {
+                                    if let Ok(device) = SonosDevice::from_url(url).await {
+                                        seen.insert(key);
+                                        if !predicate(&device) {
+                                            continue;
+                                        }
+                                        if tx.send(device).await.is_err() {
+                                            break;
+                                        }
                                     }
                                 }
                             }
+                            _ => {}
                         }
-                        _ => {}
                     }
+                    Ok(Err(err)) => {
+                        log::error!("{err:#}");
+                        break;
+                    }
+                    Err(_) => break,
                 }
-                Ok(Err(err)) => {
-                    log::error!("{err:#}");
-                    break;
-                }
-                Err(_) => break,
             }
-        }
-    });
+        });
+
+        Ok(rx)
+    }
+}
+
+/// The parts of an SSDP `USN` header, such as
+/// `uuid:RINCON_000E58A0123401400::urn:schemas-upnp-org:device:ZonePlayer:1`
+#[derive(Debug, PartialEq, Eq)]
+struct Usn<'a> {
+    uuid: &'a str,
+    /// The device or service type, if any
+    urn: Option<&'a str>,
+}
+
+impl Usn<'_> {
+    /// Returns true if this USN identifies the ZonePlayer device
+    /// itself, rather than one of its embedded devices, such as
+    /// `RINCON_000E58A0123401400_MR` for its MediaRenderer
+    fn is_root_zone_player(&self) -> bool {
+        self.urn.map(|urn| urn == SONOS_URN).unwrap_or(true)
+            && !self.uuid.ends_with("_MR")
+            && !self.uuid.ends_with("_MS")
+    }
+}
+
+fn parse_usn(usn: &str) -> Option<Usn<'_>> {
+    let usn = usn.strip_prefix("uuid:")?;
+    let (uuid, urn) = match usn.split_once("::") {
+        Some((uuid, urn)) => (uuid, Some(urn)),
+        None => (usn, None),
+    };
+    (!uuid.is_empty()).then_some(Usn { uuid, urn })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_usn() {
+        let usn =
+            parse_usn("uuid:RINCON_000E58A0123401400::urn:schemas-upnp-org:device:ZonePlayer:1")
+                .unwrap();
+        k9::assert_equal!(usn.uuid, "RINCON_000E58A0123401400");
+        assert!(usn.is_root_zone_player());
+
+        let usn = parse_usn(
+            "uuid:RINCON_000E58A0123401400_MR::urn:schemas-upnp-org:device:MediaRenderer:1",
+        )
+        .unwrap();
+        assert!(!usn.is_root_zone_player());
 
-    Ok(rx)
+        k9::assert_equal!(
+            parse_usn("uuid:RINCON_000E58A0123401400"),
+            Some(Usn {
+                uuid: "RINCON_000E58A0123401400",
+                urn: None
+            })
+        );
+        k9::assert_equal!(parse_usn("RINCON_000E58A0123401400"), None);
+    }
 }
@@ -14,6 +14,306 @@ pub mod av_transport {
     /// `urn:schemas-upnp-org:service:AVTransport:1`
     pub const SERVICE_TYPE: &str = "urn:schemas-upnp-org:service:AVTransport:1";
 
+    /// The device models that are known to support each action
+    /// of this service, sorted by action name.
+    /// The models are identified by their `modelNumber`.
+    pub const ACTION_MODELS: &[(&str, &[&str])] = &[
+        (
+            "AddMultipleURIsToQueue",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "AddURIToQueue",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "AddURIToSavedQueue",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "BackupQueue",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "BecomeCoordinatorOfStandaloneGroup",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "BecomeGroupCoordinator",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "BecomeGroupCoordinatorAndSource",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "ChangeCoordinator",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "ChangeTransportSettings",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "ConfigureSleepTimer",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "CreateSavedQueue",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "DelegateGroupCoordinationTo",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "EndDirectControlSession",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "GetCrossfadeMode",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "GetCurrentTransportActions",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "GetDeviceCapabilities",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "GetMediaInfo",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "GetPositionInfo",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "GetRemainingSleepTimerDuration",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "GetRunningAlarmProperties",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "GetTransportInfo",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "GetTransportSettings",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "Next",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "NotifyDeletedURI",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "Pause",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "Play",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "Previous",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "RemoveAllTracksFromQueue",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "RemoveTrackFromQueue",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "RemoveTrackRangeFromQueue",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "ReorderTracksInQueue",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "ReorderTracksInSavedQueue",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "RunAlarm",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "SaveQueue",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "Seek",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "SetAVTransportURI",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "SetCrossfadeMode",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "SetNextAVTransportURI",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "SetPlayMode",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "SnoozeAlarm",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "StartAutoplay",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "Stop",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+    ];
+
     #[derive(ToXml, Debug, Clone, PartialEq, Default)]
     #[xml(rename = "AddMultipleURIsToQueue", ns(SERVICE_TYPE))]
     pub struct AddMultipleUrisToQueueRequest {
@@ -37,6 +337,33 @@ pub mod av_transport {
         pub enqueue_as_next: bool,
     }
 
+    impl AddMultipleUrisToQueueRequest {
+        /// Constructs a request with `instance_id` set to 0
+        #[allow(clippy::too_many_arguments)]
+        pub fn new(
+            update_id: u32,
+            number_of_uris: u32,
+            enqueued_uris: String,
+            enqueued_uris_meta_data: String,
+            container_uri: String,
+            container_meta_data: String,
+            desired_first_track_number_enqueued: u32,
+            enqueue_as_next: bool,
+        ) -> Self {
+            Self {
+                instance_id: 0,
+                update_id,
+                number_of_uris,
+                enqueued_uris,
+                enqueued_uris_meta_data,
+                container_uri,
+                container_meta_data,
+                desired_first_track_number_enqueued,
+                enqueue_as_next,
+            }
+        }
+    }
+
     #[derive(FromXml, Debug, Clone, PartialEq)]
     #[xml(rename = "AddMultipleURIsToQueueResponse", ns(SERVICE_TYPE))]
     pub struct AddMultipleUrisToQueueResponse {
@@ -52,7 +379,14 @@ pub mod av_transport {
 
     impl crate::DecodeSoapResponse for AddMultipleUrisToQueueResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode(
+                        "av_transport::AddMultipleUrisToQueueResponse",
+                        error,
+                        xml,
+                    )
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -73,6 +407,24 @@ pub mod av_transport {
         pub enqueue_as_next: bool,
     }
 
+    impl AddUriToQueueRequest {
+        /// Constructs a request with `instance_id` set to 0
+        pub fn new(
+            enqueued_uri: String,
+            enqueued_uri_meta_data: DecodeXmlString<crate::TrackMetaData>,
+            desired_first_track_number_enqueued: u32,
+            enqueue_as_next: bool,
+        ) -> Self {
+            Self {
+                instance_id: 0,
+                enqueued_uri,
+                enqueued_uri_meta_data,
+                desired_first_track_number_enqueued,
+                enqueue_as_next,
+            }
+        }
+    }
+
     #[derive(FromXml, Debug, Clone, PartialEq)]
     #[xml(rename = "AddURIToQueueResponse", ns(SERVICE_TYPE))]
     pub struct AddUriToQueueResponse {
@@ -86,7 +438,10 @@ pub mod av_transport {
 
     impl crate::DecodeSoapResponse for AddUriToQueueResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode("av_transport::AddUriToQueueResponse", error, xml)
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -108,6 +463,26 @@ pub mod av_transport {
         pub add_at_index: u32,
     }
 
+    impl AddUriToSavedQueueRequest {
+        /// Constructs a request with `instance_id` set to 0
+        pub fn new(
+            object_id: String,
+            update_id: u32,
+            enqueued_uri: String,
+            enqueued_uri_meta_data: DecodeXmlString<crate::TrackMetaData>,
+            add_at_index: u32,
+        ) -> Self {
+            Self {
+                instance_id: 0,
+                object_id,
+                update_id,
+                enqueued_uri,
+                enqueued_uri_meta_data,
+                add_at_index,
+            }
+        }
+    }
+
     #[derive(FromXml, Debug, Clone, PartialEq)]
     #[xml(rename = "AddURIToSavedQueueResponse", ns(SERVICE_TYPE))]
     pub struct AddUriToSavedQueueResponse {
@@ -121,7 +496,14 @@ pub mod av_transport {
 
     impl crate::DecodeSoapResponse for AddUriToSavedQueueResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode(
+                        "av_transport::AddUriToSavedQueueResponse",
+                        error,
+                        xml,
+                    )
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -154,7 +536,14 @@ pub mod av_transport {
 
     impl crate::DecodeSoapResponse for BecomeCoordinatorOfStandaloneGroupResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode(
+                        "av_transport::BecomeCoordinatorOfStandaloneGroupResponse",
+                        error,
+                        xml,
+                    )
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -188,6 +577,39 @@ pub mod av_transport {
         pub current_vli_state: String,
     }
 
+    impl BecomeGroupCoordinatorRequest {
+        /// Constructs a request with `instance_id` set to 0
+        #[allow(clippy::too_many_arguments)]
+        pub fn new(
+            current_coordinator: String,
+            current_group_id: String,
+            other_members: String,
+            transport_settings: String,
+            current_uri: String,
+            current_uri_meta_data: DecodeXmlString<crate::TrackMetaData>,
+            sleep_timer_state: String,
+            alarm_state: String,
+            stream_restart_state: String,
+            current_queue_track_list: String,
+            current_vli_state: String,
+        ) -> Self {
+            Self {
+                instance_id: 0,
+                current_coordinator,
+                current_group_id,
+                other_members,
+                transport_settings,
+                current_uri,
+                current_uri_meta_data,
+                sleep_timer_state,
+                alarm_state,
+                stream_restart_state,
+                current_queue_track_list,
+                current_vli_state,
+            }
+        }
+    }
+
     #[derive(ToXml, Debug, Clone, PartialEq, Default)]
     #[xml(rename = "BecomeGroupCoordinatorAndSource", ns(SERVICE_TYPE))]
     pub struct BecomeGroupCoordinatorAndSourceRequest {
@@ -219,6 +641,41 @@ pub mod av_transport {
         pub resume_playback: bool,
     }
 
+    impl BecomeGroupCoordinatorAndSourceRequest {
+        /// Constructs a request with `instance_id` set to 0
+        #[allow(clippy::too_many_arguments)]
+        pub fn new(
+            current_coordinator: String,
+            current_group_id: String,
+            other_members: String,
+            current_uri: String,
+            current_uri_meta_data: DecodeXmlString<crate::TrackMetaData>,
+            sleep_timer_state: String,
+            alarm_state: String,
+            stream_restart_state: String,
+            current_avt_track_list: String,
+            current_queue_track_list: String,
+            current_source_state: String,
+            resume_playback: bool,
+        ) -> Self {
+            Self {
+                instance_id: 0,
+                current_coordinator,
+                current_group_id,
+                other_members,
+                current_uri,
+                current_uri_meta_data,
+                sleep_timer_state,
+                alarm_state,
+                stream_restart_state,
+                current_avt_track_list,
+                current_queue_track_list,
+                current_source_state,
+                resume_playback,
+            }
+        }
+    }
+
     #[derive(ToXml, Debug, Clone, PartialEq, Default)]
     #[xml(rename = "ChangeCoordinator", ns(SERVICE_TYPE))]
     pub struct ChangeCoordinatorRequest {
@@ -234,6 +691,24 @@ pub mod av_transport {
         pub current_av_transport_uri: String,
     }
 
+    impl ChangeCoordinatorRequest {
+        /// Constructs a request with `instance_id` set to 0
+        pub fn new(
+            current_coordinator: String,
+            new_coordinator: String,
+            new_transport_settings: String,
+            current_av_transport_uri: String,
+        ) -> Self {
+            Self {
+                instance_id: 0,
+                current_coordinator,
+                new_coordinator,
+                new_transport_settings,
+                current_av_transport_uri,
+            }
+        }
+    }
+
     #[derive(ToXml, Debug, Clone, PartialEq, Default)]
     #[xml(rename = "ChangeTransportSettings", ns(SERVICE_TYPE))]
     pub struct ChangeTransportSettingsRequest {
@@ -268,6 +743,22 @@ pub mod av_transport {
         pub enqueued_uri_meta_data: DecodeXmlString<crate::TrackMetaData>,
     }
 
+    impl CreateSavedQueueRequest {
+        /// Constructs a request with `instance_id` set to 0
+        pub fn new(
+            title: String,
+            enqueued_uri: String,
+            enqueued_uri_meta_data: DecodeXmlString<crate::TrackMetaData>,
+        ) -> Self {
+            Self {
+                instance_id: 0,
+                title,
+                enqueued_uri,
+                enqueued_uri_meta_data,
+            }
+        }
+    }
+
     #[derive(FromXml, Debug, Clone, PartialEq)]
     #[xml(rename = "CreateSavedQueueResponse", ns(SERVICE_TYPE))]
     pub struct CreateSavedQueueResponse {
@@ -283,7 +774,14 @@ pub mod av_transport {
 
     impl crate::DecodeSoapResponse for CreateSavedQueueResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode(
+                        "av_transport::CreateSavedQueueResponse",
+                        error,
+                        xml,
+                    )
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -324,7 +822,14 @@ pub mod av_transport {
 
     impl crate::DecodeSoapResponse for GetCrossfadeModeResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode(
+                        "av_transport::GetCrossfadeModeResponse",
+                        error,
+                        xml,
+                    )
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -345,7 +850,14 @@ pub mod av_transport {
 
     impl crate::DecodeSoapResponse for GetCurrentTransportActionsResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode(
+                        "av_transport::GetCurrentTransportActionsResponse",
+                        error,
+                        xml,
+                    )
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -370,7 +882,14 @@ pub mod av_transport {
 
     impl crate::DecodeSoapResponse for GetDeviceCapabilitiesResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode(
+                        "av_transport::GetDeviceCapabilitiesResponse",
+                        error,
+                        xml,
+                    )
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -407,7 +926,10 @@ pub mod av_transport {
 
     impl crate::DecodeSoapResponse for GetMediaInfoResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode("av_transport::GetMediaInfoResponse", error, xml)
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -442,7 +964,14 @@ pub mod av_transport {
 
     impl crate::DecodeSoapResponse for GetPositionInfoResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode(
+                        "av_transport::GetPositionInfoResponse",
+                        error,
+                        xml,
+                    )
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -465,7 +994,14 @@ pub mod av_transport {
 
     impl crate::DecodeSoapResponse for GetRemainingSleepTimerDurationResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode(
+                        "av_transport::GetRemainingSleepTimerDurationResponse",
+                        error,
+                        xml,
+                    )
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -490,7 +1026,14 @@ pub mod av_transport {
 
     impl crate::DecodeSoapResponse for GetRunningAlarmPropertiesResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode(
+                        "av_transport::GetRunningAlarmPropertiesResponse",
+                        error,
+                        xml,
+                    )
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -515,7 +1058,14 @@ pub mod av_transport {
 
     impl crate::DecodeSoapResponse for GetTransportInfoResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode(
+                        "av_transport::GetTransportInfoResponse",
+                        error,
+                        xml,
+                    )
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -538,7 +1088,14 @@ pub mod av_transport {
 
     impl crate::DecodeSoapResponse for GetTransportSettingsResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode(
+                        "av_transport::GetTransportSettingsResponse",
+                        error,
+                        xml,
+                    )
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -616,6 +1173,18 @@ pub mod av_transport {
         pub number_of_tracks: u32,
     }
 
+    impl RemoveTrackRangeFromQueueRequest {
+        /// Constructs a request with `instance_id` set to 0
+        pub fn new(update_id: u32, starting_index: u32, number_of_tracks: u32) -> Self {
+            Self {
+                instance_id: 0,
+                update_id,
+                starting_index,
+                number_of_tracks,
+            }
+        }
+    }
+
     #[derive(FromXml, Debug, Clone, PartialEq)]
     #[xml(rename = "RemoveTrackRangeFromQueueResponse", ns(SERVICE_TYPE))]
     pub struct RemoveTrackRangeFromQueueResponse {
@@ -625,7 +1194,14 @@ pub mod av_transport {
 
     impl crate::DecodeSoapResponse for RemoveTrackRangeFromQueueResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode(
+                        "av_transport::RemoveTrackRangeFromQueueResponse",
+                        error,
+                        xml,
+                    )
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -645,6 +1221,24 @@ pub mod av_transport {
         pub update_id: u32,
     }
 
+    impl ReorderTracksInQueueRequest {
+        /// Constructs a request with `instance_id` set to 0
+        pub fn new(
+            starting_index: u32,
+            number_of_tracks: u32,
+            insert_before: u32,
+            update_id: u32,
+        ) -> Self {
+            Self {
+                instance_id: 0,
+                starting_index,
+                number_of_tracks,
+                insert_before,
+                update_id,
+            }
+        }
+    }
+
     #[derive(ToXml, Debug, Clone, PartialEq, Default)]
     #[xml(rename = "ReorderTracksInSavedQueue", ns(SERVICE_TYPE))]
     pub struct ReorderTracksInSavedQueueRequest {
@@ -660,6 +1254,24 @@ pub mod av_transport {
         pub new_position_list: String,
     }
 
+    impl ReorderTracksInSavedQueueRequest {
+        /// Constructs a request with `instance_id` set to 0
+        pub fn new(
+            object_id: String,
+            update_id: u32,
+            track_list: String,
+            new_position_list: String,
+        ) -> Self {
+            Self {
+                instance_id: 0,
+                object_id,
+                update_id,
+                track_list,
+                new_position_list,
+            }
+        }
+    }
+
     #[derive(FromXml, Debug, Clone, PartialEq)]
     #[xml(rename = "ReorderTracksInSavedQueueResponse", ns(SERVICE_TYPE))]
     pub struct ReorderTracksInSavedQueueResponse {
@@ -673,7 +1285,14 @@ pub mod av_transport {
 
     impl crate::DecodeSoapResponse for ReorderTracksInSavedQueueResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode(
+                        "av_transport::ReorderTracksInSavedQueueResponse",
+                        error,
+                        xml,
+                    )
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -701,6 +1320,33 @@ pub mod av_transport {
         pub include_linked_zones: bool,
     }
 
+    impl RunAlarmRequest {
+        /// Constructs a request with `instance_id` set to 0
+        #[allow(clippy::too_many_arguments)]
+        pub fn new(
+            alarm_id: u32,
+            logged_start_time: String,
+            duration: String,
+            program_uri: String,
+            program_meta_data: DecodeXmlString<crate::TrackMetaData>,
+            play_mode: super::CurrentPlayMode,
+            volume: u16,
+            include_linked_zones: bool,
+        ) -> Self {
+            Self {
+                instance_id: 0,
+                alarm_id,
+                logged_start_time,
+                duration,
+                program_uri,
+                program_meta_data,
+                play_mode,
+                volume,
+                include_linked_zones,
+            }
+        }
+    }
+
     #[derive(ToXml, Debug, Clone, PartialEq, Default)]
     #[xml(rename = "SaveQueue", ns(SERVICE_TYPE))]
     pub struct SaveQueueRequest {
@@ -723,7 +1369,10 @@ pub mod av_transport {
 
     impl crate::DecodeSoapResponse for SaveQueueResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode("av_transport::SaveQueueResponse", error, xml)
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -811,6 +1460,26 @@ pub mod av_transport {
         pub reset_volume_after: bool,
     }
 
+    impl StartAutoplayRequest {
+        /// Constructs a request with `instance_id` set to 0
+        pub fn new(
+            program_uri: String,
+            program_meta_data: DecodeXmlString<crate::TrackMetaData>,
+            volume: u16,
+            include_linked_zones: bool,
+            reset_volume_after: bool,
+        ) -> Self {
+            Self {
+                instance_id: 0,
+                program_uri,
+                program_meta_data,
+                volume,
+                include_linked_zones,
+                reset_volume_after,
+            }
+        }
+    }
+
     #[derive(ToXml, Debug, Clone, PartialEq, Default)]
     #[xml(rename = "Stop", ns(SERVICE_TYPE))]
     pub struct StopRequest {
@@ -857,7 +1526,7 @@ pub mod av_transport {
         pub async fn subscribe_av_transport(
             &self,
         ) -> crate::Result<crate::upnp::EventStream<AVTransportEvent>> {
-            self.subscribe_helper(&SERVICE_TYPE).await
+            self.subscribe_helper(SERVICE_TYPE).await
         }
     }
 
@@ -912,7 +1581,7 @@ pub mod av_transport {
         pub current_track_meta_data: Option<DecodeXmlString<crate::TrackMetaData>>,
         pub current_track_uri: Option<String>,
         pub current_transport_actions: Option<String>,
-        pub current_valid_play_modes: Option<String>,
+        pub current_valid_play_modes: Option<DecodeXmlString<crate::ValidPlayModes>>,
         pub direct_control_account_id: Option<String>,
         pub direct_control_client_id: Option<String>,
         pub direct_control_is_suspended: Option<bool>,
@@ -1348,7 +2017,7 @@ pub mod av_transport {
     #[allow(non_camel_case_types)]
     struct AVTransportLastChangeCurrentValidPlayModes {
         #[xml(attribute)]
-        val: Option<String>,
+        val: Option<DecodeXmlString<crate::ValidPlayModes>>,
     }
 
     #[derive(FromXml)]
@@ -1728,184 +2397,185 @@ pub mod av_transport {
             let mut map = std::collections::BTreeMap::new();
 
             for item in last_change.instance {
-                let mut result = AVTransportLastChange::default();
-
-                result.av_transport_uri = item.av_transport_uri.and_then(|v| v.val);
+                let result = AVTransportLastChange {
+                    av_transport_uri: item.av_transport_uri.and_then(|v| v.val),
 
-                result.av_transport_uri_meta_data =
-                    item.av_transport_uri_meta_data.and_then(|v| v.val);
+                    av_transport_uri_meta_data: item.av_transport_uri_meta_data.and_then(|v| v.val),
 
-                result.alarm_include_linked_zones =
-                    item.alarm_include_linked_zones.and_then(|v| v.val);
+                    alarm_include_linked_zones: item.alarm_include_linked_zones.and_then(|v| v.val),
 
-                result.alarm_state = item.alarm_state.and_then(|v| v.val);
+                    alarm_state: item.alarm_state.and_then(|v| v.val),
 
-                result.alarm_volume = item.alarm_volume.and_then(|v| v.val);
+                    alarm_volume: item.alarm_volume.and_then(|v| v.val),
 
-                result.current_av_transport_uri = item.current_av_transport_uri.and_then(|v| v.val);
+                    current_av_transport_uri: item.current_av_transport_uri.and_then(|v| v.val),
 
-                result.enqueue_as_next = item.enqueue_as_next.and_then(|v| v.val);
+                    enqueue_as_next: item.enqueue_as_next.and_then(|v| v.val),
 
-                result.group_id = item.group_id.and_then(|v| v.val);
+                    group_id: item.group_id.and_then(|v| v.val),
 
-                result.iso8601_time = item.iso8601_time.and_then(|v| v.val);
+                    iso8601_time: item.iso8601_time.and_then(|v| v.val),
 
-                result.instance_id = item.instance_id.and_then(|v| v.val);
+                    instance_id: item.instance_id.and_then(|v| v.val),
 
-                result.list_uri = item.list_uri.and_then(|v| v.val);
+                    list_uri: item.list_uri.and_then(|v| v.val),
 
-                result.list_uri_meta_data = item.list_uri_meta_data.and_then(|v| v.val);
+                    list_uri_meta_data: item.list_uri_meta_data.and_then(|v| v.val),
 
-                result.member_id = item.member_id.and_then(|v| v.val);
+                    member_id: item.member_id.and_then(|v| v.val),
 
-                result.member_list = item.member_list.and_then(|v| v.val);
+                    member_list: item.member_list.and_then(|v| v.val),
 
-                result.num_tracks = item.num_tracks.and_then(|v| v.val);
+                    num_tracks: item.num_tracks.and_then(|v| v.val),
 
-                result.num_tracks_change = item.num_tracks_change.and_then(|v| v.val);
+                    num_tracks_change: item.num_tracks_change.and_then(|v| v.val),
 
-                result.object_id = item.object_id.and_then(|v| v.val);
+                    object_id: item.object_id.and_then(|v| v.val),
 
-                result.player_id = item.player_id.and_then(|v| v.val);
+                    player_id: item.player_id.and_then(|v| v.val),
 
-                result.queue = item.queue.and_then(|v| v.val);
+                    queue: item.queue.and_then(|v| v.val),
 
-                result.rejoin_group = item.rejoin_group.and_then(|v| v.val);
+                    rejoin_group: item.rejoin_group.and_then(|v| v.val),
 
-                result.reset_volume_after = item.reset_volume_after.and_then(|v| v.val);
+                    reset_volume_after: item.reset_volume_after.and_then(|v| v.val),
 
-                result.resume_playback = item.resume_playback.and_then(|v| v.val);
+                    resume_playback: item.resume_playback.and_then(|v| v.val),
 
-                result.saved_queue_title = item.saved_queue_title.and_then(|v| v.val);
+                    saved_queue_title: item.saved_queue_title.and_then(|v| v.val),
 
-                result.seek_mode = item.seek_mode.and_then(|v| v.val);
+                    seek_mode: item.seek_mode.and_then(|v| v.val),
 
-                result.seek_target = item.seek_target.and_then(|v| v.val);
+                    seek_target: item.seek_target.and_then(|v| v.val),
 
-                result.sleep_timer_state = item.sleep_timer_state.and_then(|v| v.val);
+                    sleep_timer_state: item.sleep_timer_state.and_then(|v| v.val),
 
-                result.source_state = item.source_state.and_then(|v| v.val);
+                    source_state: item.source_state.and_then(|v| v.val),
 
-                result.stream_restart_state = item.stream_restart_state.and_then(|v| v.val);
+                    stream_restart_state: item.stream_restart_state.and_then(|v| v.val),
 
-                result.track_list = item.track_list.and_then(|v| v.val);
+                    track_list: item.track_list.and_then(|v| v.val),
 
-                result.track_number = item.track_number.and_then(|v| v.val);
+                    track_number: item.track_number.and_then(|v| v.val),
 
-                result.transport_settings = item.transport_settings.and_then(|v| v.val);
+                    transport_settings: item.transport_settings.and_then(|v| v.val),
 
-                result.uri = item.uri.and_then(|v| v.val);
+                    uri: item.uri.and_then(|v| v.val),
 
-                result.uri_meta_data = item.uri_meta_data.and_then(|v| v.val);
+                    uri_meta_data: item.uri_meta_data.and_then(|v| v.val),
 
-                result.vli_state = item.vli_state.and_then(|v| v.val);
+                    vli_state: item.vli_state.and_then(|v| v.val),
 
-                result.absolute_counter_position =
-                    item.absolute_counter_position.and_then(|v| v.val);
+                    absolute_counter_position: item.absolute_counter_position.and_then(|v| v.val),
 
-                result.absolute_time_position = item.absolute_time_position.and_then(|v| v.val);
+                    absolute_time_position: item.absolute_time_position.and_then(|v| v.val),
 
-                result.alarm_id_running = item.alarm_id_running.and_then(|v| v.val);
+                    alarm_id_running: item.alarm_id_running.and_then(|v| v.val),
 
-                result.alarm_logged_start_time = item.alarm_logged_start_time.and_then(|v| v.val);
+                    alarm_logged_start_time: item.alarm_logged_start_time.and_then(|v| v.val),
 
-                result.alarm_running = item.alarm_running.and_then(|v| v.val);
+                    alarm_running: item.alarm_running.and_then(|v| v.val),
 
-                result.current_crossfade_mode = item.current_crossfade_mode.and_then(|v| v.val);
+                    current_crossfade_mode: item.current_crossfade_mode.and_then(|v| v.val),
 
-                result.current_media_duration = item.current_media_duration.and_then(|v| v.val);
+                    current_media_duration: item.current_media_duration.and_then(|v| v.val),
 
-                result.current_play_mode = item.current_play_mode.and_then(|v| v.val);
+                    current_play_mode: item.current_play_mode.and_then(|v| v.val),
 
-                result.current_record_quality_mode =
-                    item.current_record_quality_mode.and_then(|v| v.val);
+                    current_record_quality_mode: item
+                        .current_record_quality_mode
+                        .and_then(|v| v.val),
 
-                result.current_section = item.current_section.and_then(|v| v.val);
+                    current_section: item.current_section.and_then(|v| v.val),
 
-                result.current_track = item.current_track.and_then(|v| v.val);
+                    current_track: item.current_track.and_then(|v| v.val),
 
-                result.current_track_duration = item.current_track_duration.and_then(|v| v.val);
+                    current_track_duration: item.current_track_duration.and_then(|v| v.val),
 
-                result.current_track_meta_data = item.current_track_meta_data.and_then(|v| v.val);
+                    current_track_meta_data: item.current_track_meta_data.and_then(|v| v.val),
 
-                result.current_track_uri = item.current_track_uri.and_then(|v| v.val);
+                    current_track_uri: item.current_track_uri.and_then(|v| v.val),
 
-                result.current_transport_actions =
-                    item.current_transport_actions.and_then(|v| v.val);
+                    current_transport_actions: item.current_transport_actions.and_then(|v| v.val),
 
-                result.current_valid_play_modes = item.current_valid_play_modes.and_then(|v| v.val);
+                    current_valid_play_modes: item.current_valid_play_modes.and_then(|v| v.val),
 
-                result.direct_control_account_id =
-                    item.direct_control_account_id.and_then(|v| v.val);
+                    direct_control_account_id: item.direct_control_account_id.and_then(|v| v.val),
 
-                result.direct_control_client_id = item.direct_control_client_id.and_then(|v| v.val);
+                    direct_control_client_id: item.direct_control_client_id.and_then(|v| v.val),
 
-                result.direct_control_is_suspended =
-                    item.direct_control_is_suspended.and_then(|v| v.val);
+                    direct_control_is_suspended: item
+                        .direct_control_is_suspended
+                        .and_then(|v| v.val),
 
-                result.enqueued_transport_uri = item.enqueued_transport_uri.and_then(|v| v.val);
+                    enqueued_transport_uri: item.enqueued_transport_uri.and_then(|v| v.val),
 
-                result.enqueued_transport_uri_meta_data =
-                    item.enqueued_transport_uri_meta_data.and_then(|v| v.val);
+                    enqueued_transport_uri_meta_data: item
+                        .enqueued_transport_uri_meta_data
+                        .and_then(|v| v.val),
 
-                result.muse_sessions = item.muse_sessions.and_then(|v| v.val);
+                    muse_sessions: item.muse_sessions.and_then(|v| v.val),
 
-                result.next_av_transport_uri = item.next_av_transport_uri.and_then(|v| v.val);
+                    next_av_transport_uri: item.next_av_transport_uri.and_then(|v| v.val),
 
-                result.next_av_transport_uri_meta_data =
-                    item.next_av_transport_uri_meta_data.and_then(|v| v.val);
+                    next_av_transport_uri_meta_data: item
+                        .next_av_transport_uri_meta_data
+                        .and_then(|v| v.val),
 
-                result.next_track_meta_data = item.next_track_meta_data.and_then(|v| v.val);
+                    next_track_meta_data: item.next_track_meta_data.and_then(|v| v.val),
 
-                result.next_track_uri = item.next_track_uri.and_then(|v| v.val);
+                    next_track_uri: item.next_track_uri.and_then(|v| v.val),
 
-                result.number_of_tracks = item.number_of_tracks.and_then(|v| v.val);
+                    number_of_tracks: item.number_of_tracks.and_then(|v| v.val),
 
-                result.playback_storage_medium = item.playback_storage_medium.and_then(|v| v.val);
+                    playback_storage_medium: item.playback_storage_medium.and_then(|v| v.val),
 
-                result.possible_playback_storage_media =
-                    item.possible_playback_storage_media.and_then(|v| v.val);
+                    possible_playback_storage_media: item
+                        .possible_playback_storage_media
+                        .and_then(|v| v.val),
 
-                result.possible_record_quality_modes =
-                    item.possible_record_quality_modes.and_then(|v| v.val);
+                    possible_record_quality_modes: item
+                        .possible_record_quality_modes
+                        .and_then(|v| v.val),
 
-                result.possible_record_storage_media =
-                    item.possible_record_storage_media.and_then(|v| v.val);
+                    possible_record_storage_media: item
+                        .possible_record_storage_media
+                        .and_then(|v| v.val),
 
-                result.queue_update_id = item.queue_update_id.and_then(|v| v.val);
+                    queue_update_id: item.queue_update_id.and_then(|v| v.val),
 
-                result.record_medium_write_status =
-                    item.record_medium_write_status.and_then(|v| v.val);
+                    record_medium_write_status: item.record_medium_write_status.and_then(|v| v.val),
 
-                result.record_storage_medium = item.record_storage_medium.and_then(|v| v.val);
+                    record_storage_medium: item.record_storage_medium.and_then(|v| v.val),
 
-                result.relative_counter_position =
-                    item.relative_counter_position.and_then(|v| v.val);
+                    relative_counter_position: item.relative_counter_position.and_then(|v| v.val),
 
-                result.relative_time_position = item.relative_time_position.and_then(|v| v.val);
+                    relative_time_position: item.relative_time_position.and_then(|v| v.val),
 
-                result.restart_pending = item.restart_pending.and_then(|v| v.val);
+                    restart_pending: item.restart_pending.and_then(|v| v.val),
 
-                result.sleep_timer_generation = item.sleep_timer_generation.and_then(|v| v.val);
+                    sleep_timer_generation: item.sleep_timer_generation.and_then(|v| v.val),
 
-                result.snooze_running = item.snooze_running.and_then(|v| v.val);
+                    snooze_running: item.snooze_running.and_then(|v| v.val),
 
-                result.transport_error_description =
-                    item.transport_error_description.and_then(|v| v.val);
+                    transport_error_description: item
+                        .transport_error_description
+                        .and_then(|v| v.val),
 
-                result.transport_error_http_code =
-                    item.transport_error_http_code.and_then(|v| v.val);
+                    transport_error_http_code: item.transport_error_http_code.and_then(|v| v.val),
 
-                result.transport_error_http_headers =
-                    item.transport_error_http_headers.and_then(|v| v.val);
+                    transport_error_http_headers: item
+                        .transport_error_http_headers
+                        .and_then(|v| v.val),
 
-                result.transport_error_uri = item.transport_error_uri.and_then(|v| v.val);
+                    transport_error_uri: item.transport_error_uri.and_then(|v| v.val),
 
-                result.transport_play_speed = item.transport_play_speed.and_then(|v| v.val);
+                    transport_play_speed: item.transport_play_speed.and_then(|v| v.val),
 
-                result.transport_state = item.transport_state.and_then(|v| v.val);
+                    transport_state: item.transport_state.and_then(|v| v.val),
 
-                result.transport_status = item.transport_status.and_then(|v| v.val);
+                    transport_status: item.transport_status.and_then(|v| v.val),
+                };
 
                 map.insert(item.object_instance_id_, result);
             }
@@ -1928,13 +2598,13 @@ pub enum SeekMode {
     Unspecified(String),
 }
 
-impl ToString for SeekMode {
-    fn to_string(&self) -> String {
+impl std::fmt::Display for SeekMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
-            SeekMode::TrackNr => "TRACK_NR".to_string(),
-            SeekMode::RelTime => "REL_TIME".to_string(),
-            SeekMode::TimeDelta => "TIME_DELTA".to_string(),
-            SeekMode::Unspecified(s) => s.to_string(),
+            SeekMode::TrackNr => f.write_str("TRACK_NR"),
+            SeekMode::RelTime => f.write_str("REL_TIME"),
+            SeekMode::TimeDelta => f.write_str("TIME_DELTA"),
+            SeekMode::Unspecified(s) => f.write_str(s),
         }
     }
 }
@@ -2017,16 +2687,16 @@ pub enum CurrentPlayMode {
     Unspecified(String),
 }
 
-impl ToString for CurrentPlayMode {
-    fn to_string(&self) -> String {
+impl std::fmt::Display for CurrentPlayMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
-            CurrentPlayMode::Normal => "NORMAL".to_string(),
-            CurrentPlayMode::RepeatAll => "REPEAT_ALL".to_string(),
-            CurrentPlayMode::RepeatOne => "REPEAT_ONE".to_string(),
-            CurrentPlayMode::ShuffleNorepeat => "SHUFFLE_NOREPEAT".to_string(),
-            CurrentPlayMode::Shuffle => "SHUFFLE".to_string(),
-            CurrentPlayMode::ShuffleRepeatOne => "SHUFFLE_REPEAT_ONE".to_string(),
-            CurrentPlayMode::Unspecified(s) => s.to_string(),
+            CurrentPlayMode::Normal => f.write_str("NORMAL"),
+            CurrentPlayMode::RepeatAll => f.write_str("REPEAT_ALL"),
+            CurrentPlayMode::RepeatOne => f.write_str("REPEAT_ONE"),
+            CurrentPlayMode::ShuffleNorepeat => f.write_str("SHUFFLE_NOREPEAT"),
+            CurrentPlayMode::Shuffle => f.write_str("SHUFFLE"),
+            CurrentPlayMode::ShuffleRepeatOne => f.write_str("SHUFFLE_REPEAT_ONE"),
+            CurrentPlayMode::Unspecified(s) => f.write_str(s),
         }
     }
 }
@@ -2108,12 +2778,12 @@ pub enum PlaybackStorageMedium {
     Unspecified(String),
 }
 
-impl ToString for PlaybackStorageMedium {
-    fn to_string(&self) -> String {
+impl std::fmt::Display for PlaybackStorageMedium {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
-            PlaybackStorageMedium::None => "NONE".to_string(),
-            PlaybackStorageMedium::Network => "NETWORK".to_string(),
-            PlaybackStorageMedium::Unspecified(s) => s.to_string(),
+            PlaybackStorageMedium::None => f.write_str("NONE"),
+            PlaybackStorageMedium::Network => f.write_str("NETWORK"),
+            PlaybackStorageMedium::Unspecified(s) => f.write_str(s),
         }
     }
 }
@@ -2193,14 +2863,14 @@ pub enum TransportState {
     Unspecified(String),
 }
 
-impl ToString for TransportState {
-    fn to_string(&self) -> String {
+impl std::fmt::Display for TransportState {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
-            TransportState::Stopped => "STOPPED".to_string(),
-            TransportState::Playing => "PLAYING".to_string(),
-            TransportState::PausedPlayback => "PAUSED_PLAYBACK".to_string(),
-            TransportState::Transitioning => "TRANSITIONING".to_string(),
-            TransportState::Unspecified(s) => s.to_string(),
+            TransportState::Stopped => f.write_str("STOPPED"),
+            TransportState::Playing => f.write_str("PLAYING"),
+            TransportState::PausedPlayback => f.write_str("PAUSED_PLAYBACK"),
+            TransportState::Transitioning => f.write_str("TRANSITIONING"),
+            TransportState::Unspecified(s) => f.write_str(s),
         }
     }
 }
@@ -2276,6 +2946,131 @@ pub mod alarm_clock {
     /// `urn:schemas-upnp-org:service:AlarmClock:1`
     pub const SERVICE_TYPE: &str = "urn:schemas-upnp-org:service:AlarmClock:1";
 
+    /// The device models that are known to support each action
+    /// of this service, sorted by action name.
+    /// The models are identified by their `modelNumber`.
+    pub const ACTION_MODELS: &[(&str, &[&str])] = &[
+        (
+            "CreateAlarm",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "DestroyAlarm",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "GetDailyIndexRefreshTime",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "GetFormat",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "GetHouseholdTimeAtStamp",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "GetTimeNow",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "GetTimeServer",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "GetTimeZone",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "GetTimeZoneAndRule",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "GetTimeZoneRule",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "ListAlarms",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "SetDailyIndexRefreshTime",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "SetFormat",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "SetTimeNow",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "SetTimeServer",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "SetTimeZone",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "UpdateAlarm",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+    ];
+
     #[derive(ToXml, Debug, Clone, PartialEq, Default)]
     #[xml(rename = "CreateAlarm", ns(SERVICE_TYPE))]
     pub struct CreateAlarmRequest {
@@ -2311,6 +3106,36 @@ pub mod alarm_clock {
         pub include_linked_zones: bool,
     }
 
+    impl CreateAlarmRequest {
+        /// Constructs a request from its fields
+        #[allow(clippy::too_many_arguments)]
+        pub fn new(
+            start_local_time: String,
+            duration: String,
+            recurrence: super::Recurrence,
+            enabled: bool,
+            room_uuid: String,
+            program_uri: String,
+            program_meta_data: String,
+            play_mode: super::AlarmPlayMode,
+            volume: u16,
+            include_linked_zones: bool,
+        ) -> Self {
+            Self {
+                start_local_time,
+                duration,
+                recurrence,
+                enabled,
+                room_uuid,
+                program_uri,
+                program_meta_data,
+                play_mode,
+                volume,
+                include_linked_zones,
+            }
+        }
+    }
+
     #[derive(FromXml, Debug, Clone, PartialEq)]
     #[xml(rename = "CreateAlarmResponse", ns(SERVICE_TYPE))]
     pub struct CreateAlarmResponse {
@@ -2320,7 +3145,10 @@ pub mod alarm_clock {
 
     impl crate::DecodeSoapResponse for CreateAlarmResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode("alarm_clock::CreateAlarmResponse", error, xml)
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -2342,7 +3170,14 @@ pub mod alarm_clock {
 
     impl crate::DecodeSoapResponse for GetDailyIndexRefreshTimeResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode(
+                        "alarm_clock::GetDailyIndexRefreshTimeResponse",
+                        error,
+                        xml,
+                    )
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -2358,7 +3193,10 @@ pub mod alarm_clock {
 
     impl crate::DecodeSoapResponse for GetFormatResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode("alarm_clock::GetFormatResponse", error, xml)
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -2379,7 +3217,14 @@ pub mod alarm_clock {
 
     impl crate::DecodeSoapResponse for GetHouseholdTimeAtStampResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode(
+                        "alarm_clock::GetHouseholdTimeAtStampResponse",
+                        error,
+                        xml,
+                    )
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -2399,7 +3244,10 @@ pub mod alarm_clock {
 
     impl crate::DecodeSoapResponse for GetTimeNowResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode("alarm_clock::GetTimeNowResponse", error, xml)
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -2413,7 +3261,10 @@ pub mod alarm_clock {
 
     impl crate::DecodeSoapResponse for GetTimeServerResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode("alarm_clock::GetTimeServerResponse", error, xml)
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -2429,7 +3280,10 @@ pub mod alarm_clock {
 
     impl crate::DecodeSoapResponse for GetTimeZoneResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode("alarm_clock::GetTimeZoneResponse", error, xml)
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -2447,7 +3301,14 @@ pub mod alarm_clock {
 
     impl crate::DecodeSoapResponse for GetTimeZoneAndRuleResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode(
+                        "alarm_clock::GetTimeZoneAndRuleResponse",
+                        error,
+                        xml,
+                    )
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -2468,7 +3329,14 @@ pub mod alarm_clock {
 
     impl crate::DecodeSoapResponse for GetTimeZoneRuleResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode(
+                        "alarm_clock::GetTimeZoneRuleResponse",
+                        error,
+                        xml,
+                    )
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -2484,7 +3352,10 @@ pub mod alarm_clock {
 
     impl crate::DecodeSoapResponse for ListAlarmsResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode("alarm_clock::ListAlarmsResponse", error, xml)
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -2568,6 +3439,38 @@ pub mod alarm_clock {
         pub include_linked_zones: bool,
     }
 
+    impl UpdateAlarmRequest {
+        /// Constructs a request from its fields
+        #[allow(clippy::too_many_arguments)]
+        pub fn new(
+            id: u32,
+            start_local_time: String,
+            duration: String,
+            recurrence: super::Recurrence,
+            enabled: bool,
+            room_uuid: String,
+            program_uri: String,
+            program_meta_data: String,
+            play_mode: super::AlarmPlayMode,
+            volume: u16,
+            include_linked_zones: bool,
+        ) -> Self {
+            Self {
+                id,
+                start_local_time,
+                duration,
+                recurrence,
+                enabled,
+                room_uuid,
+                program_uri,
+                program_meta_data,
+                play_mode,
+                volume,
+                include_linked_zones,
+            }
+        }
+    }
+
     /// A parsed event produced by the `AlarmClock` service.
     /// Use `SonosDevice::subscribe_alarm_clock()` to obtain an event
     /// stream that produces these.
@@ -2649,7 +3552,7 @@ pub mod alarm_clock {
         pub async fn subscribe_alarm_clock(
             &self,
         ) -> crate::Result<crate::upnp::EventStream<AlarmClockEvent>> {
-            self.subscribe_helper(&SERVICE_TYPE).await
+            self.subscribe_helper(SERVICE_TYPE).await
         }
     }
 }
@@ -2668,14 +3571,14 @@ pub enum AlarmPlayMode {
     Unspecified(String),
 }
 
-impl ToString for AlarmPlayMode {
-    fn to_string(&self) -> String {
+impl std::fmt::Display for AlarmPlayMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
-            AlarmPlayMode::Normal => "NORMAL".to_string(),
-            AlarmPlayMode::RepeatAll => "REPEAT_ALL".to_string(),
-            AlarmPlayMode::ShuffleNorepeat => "SHUFFLE_NOREPEAT".to_string(),
-            AlarmPlayMode::Shuffle => "SHUFFLE".to_string(),
-            AlarmPlayMode::Unspecified(s) => s.to_string(),
+            AlarmPlayMode::Normal => f.write_str("NORMAL"),
+            AlarmPlayMode::RepeatAll => f.write_str("REPEAT_ALL"),
+            AlarmPlayMode::ShuffleNorepeat => f.write_str("SHUFFLE_NOREPEAT"),
+            AlarmPlayMode::Shuffle => f.write_str("SHUFFLE"),
+            AlarmPlayMode::Unspecified(s) => f.write_str(s),
         }
     }
 }
@@ -2757,14 +3660,14 @@ pub enum Recurrence {
     Unspecified(String),
 }
 
-impl ToString for Recurrence {
-    fn to_string(&self) -> String {
+impl std::fmt::Display for Recurrence {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
-            Recurrence::Once => "ONCE".to_string(),
-            Recurrence::Weekdays => "WEEKDAYS".to_string(),
-            Recurrence::Weekends => "WEEKENDS".to_string(),
-            Recurrence::Daily => "DAILY".to_string(),
-            Recurrence::Unspecified(s) => s.to_string(),
+            Recurrence::Once => f.write_str("ONCE"),
+            Recurrence::Weekdays => f.write_str("WEEKDAYS"),
+            Recurrence::Weekends => f.write_str("WEEKENDS"),
+            Recurrence::Daily => f.write_str("DAILY"),
+            Recurrence::Unspecified(s) => f.write_str(s),
         }
     }
 }
@@ -2840,6 +3743,19 @@ pub mod audio_in {
     /// `urn:schemas-upnp-org:service:AudioIn:1`
     pub const SERVICE_TYPE: &str = "urn:schemas-upnp-org:service:AudioIn:1";
 
+    /// The device models that are known to support each action
+    /// of this service, sorted by action name.
+    /// The models are identified by their `modelNumber`.
+    pub const ACTION_MODELS: &[(&str, &[&str])] = &[
+        ("GetAudioInputAttributes", &["S5", "S6"]),
+        ("GetLineInLevel", &["S5", "S6"]),
+        ("SelectAudio", &["S5", "S6"]),
+        ("SetAudioInputAttributes", &["S5", "S6"]),
+        ("SetLineInLevel", &["S5", "S6"]),
+        ("StartTransmissionToGroup", &["S5", "S6"]),
+        ("StopTransmissionToGroup", &["S5", "S6"]),
+    ];
+
     #[derive(FromXml, Debug, Clone, PartialEq)]
     #[xml(rename = "GetAudioInputAttributesResponse", ns(SERVICE_TYPE))]
     pub struct GetAudioInputAttributesResponse {
@@ -2851,7 +3767,14 @@ pub mod audio_in {
 
     impl crate::DecodeSoapResponse for GetAudioInputAttributesResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode(
+                        "audio_in::GetAudioInputAttributesResponse",
+                        error,
+                        xml,
+                    )
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -2867,7 +3790,10 @@ pub mod audio_in {
 
     impl crate::DecodeSoapResponse for GetLineInLevelResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode("audio_in::GetLineInLevelResponse", error, xml)
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -2913,7 +3839,14 @@ pub mod audio_in {
 
     impl crate::DecodeSoapResponse for StartTransmissionToGroupResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode(
+                        "audio_in::StartTransmissionToGroupResponse",
+                        error,
+                        xml,
+                    )
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -2999,7 +3932,7 @@ pub mod audio_in {
         pub async fn subscribe_audio_in(
             &self,
         ) -> crate::Result<crate::upnp::EventStream<AudioInEvent>> {
-            self.subscribe_helper(&SERVICE_TYPE).await
+            self.subscribe_helper(SERVICE_TYPE).await
         }
     }
 }
@@ -3012,6 +3945,33 @@ pub mod connection_manager {
     /// `urn:schemas-upnp-org:service:ConnectionManager:1`
     pub const SERVICE_TYPE: &str = "urn:schemas-upnp-org:service:ConnectionManager:1";
 
+    /// The device models that are known to support each action
+    /// of this service, sorted by action name.
+    /// The models are identified by their `modelNumber`.
+    pub const ACTION_MODELS: &[(&str, &[&str])] = &[
+        (
+            "GetCurrentConnectionIDs",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "GetCurrentConnectionInfo",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "GetProtocolInfo",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+    ];
+
     #[derive(FromXml, Debug, Clone, PartialEq)]
     #[xml(rename = "GetCurrentConnectionIDsResponse", ns(SERVICE_TYPE))]
     pub struct GetCurrentConnectionIdsResponse {
@@ -3021,7 +3981,14 @@ pub mod connection_manager {
 
     impl crate::DecodeSoapResponse for GetCurrentConnectionIdsResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode(
+                        "connection_manager::GetCurrentConnectionIdsResponse",
+                        error,
+                        xml,
+                    )
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -3054,7 +4021,14 @@ pub mod connection_manager {
 
     impl crate::DecodeSoapResponse for GetCurrentConnectionInfoResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode(
+                        "connection_manager::GetCurrentConnectionInfoResponse",
+                        error,
+                        xml,
+                    )
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -3070,7 +4044,14 @@ pub mod connection_manager {
 
     impl crate::DecodeSoapResponse for GetProtocolInfoResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode(
+                        "connection_manager::GetProtocolInfoResponse",
+                        error,
+                        xml,
+                    )
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -3128,7 +4109,7 @@ pub mod connection_manager {
         pub async fn subscribe_connection_manager(
             &self,
         ) -> crate::Result<crate::upnp::EventStream<ConnectionManagerEvent>> {
-            self.subscribe_helper(&SERVICE_TYPE).await
+            self.subscribe_helper(SERVICE_TYPE).await
         }
     }
 }
@@ -3148,15 +4129,15 @@ pub enum ConnectionStatus {
     Unspecified(String),
 }
 
-impl ToString for ConnectionStatus {
-    fn to_string(&self) -> String {
+impl std::fmt::Display for ConnectionStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
-            ConnectionStatus::Ok => "OK".to_string(),
-            ConnectionStatus::ContentFormatMismatch => "ContentFormatMismatch".to_string(),
-            ConnectionStatus::InsufficientBandwidth => "InsufficientBandwidth".to_string(),
-            ConnectionStatus::UnreliableChannel => "UnreliableChannel".to_string(),
-            ConnectionStatus::Unknown => "Unknown".to_string(),
-            ConnectionStatus::Unspecified(s) => s.to_string(),
+            ConnectionStatus::Ok => f.write_str("OK"),
+            ConnectionStatus::ContentFormatMismatch => f.write_str("ContentFormatMismatch"),
+            ConnectionStatus::InsufficientBandwidth => f.write_str("InsufficientBandwidth"),
+            ConnectionStatus::UnreliableChannel => f.write_str("UnreliableChannel"),
+            ConnectionStatus::Unknown => f.write_str("Unknown"),
+            ConnectionStatus::Unspecified(s) => f.write_str(s),
         }
     }
 }
@@ -3237,12 +4218,12 @@ pub enum Direction {
     Unspecified(String),
 }
 
-impl ToString for Direction {
-    fn to_string(&self) -> String {
+impl std::fmt::Display for Direction {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
-            Direction::Input => "Input".to_string(),
-            Direction::Output => "Output".to_string(),
-            Direction::Unspecified(s) => s.to_string(),
+            Direction::Input => f.write_str("Input"),
+            Direction::Output => f.write_str("Output"),
+            Direction::Unspecified(s) => f.write_str(s),
         }
     }
 }
@@ -3316,6 +4297,124 @@ pub mod content_directory {
     /// `urn:schemas-upnp-org:service:ContentDirectory:1`
     pub const SERVICE_TYPE: &str = "urn:schemas-upnp-org:service:ContentDirectory:1";
 
+    /// The device models that are known to support each action
+    /// of this service, sorted by action name.
+    /// The models are identified by their `modelNumber`.
+    pub const ACTION_MODELS: &[(&str, &[&str])] = &[
+        (
+            "Browse",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "CreateObject",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "DestroyObject",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "FindPrefix",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "GetAlbumArtistDisplayOption",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "GetAllPrefixLocations",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "GetBrowseable",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "GetLastIndexChange",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "GetSearchCapabilities",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "GetShareIndexInProgress",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "GetSortCapabilities",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "GetSystemUpdateID",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "RefreshShareIndex",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "RequestResort",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "SetBrowseable",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "UpdateObject",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+    ];
+
     #[derive(ToXml, Debug, Clone, PartialEq, Default)]
     #[xml(rename = "Browse", ns(SERVICE_TYPE))]
     pub struct BrowseRequest {
@@ -3339,6 +4438,27 @@ pub mod content_directory {
         pub sort_criteria: String,
     }
 
+    impl BrowseRequest {
+        /// Constructs a request from its fields
+        pub fn new(
+            object_id: String,
+            browse_flag: super::BrowseFlag,
+            filter: String,
+            starting_index: u32,
+            requested_count: u32,
+            sort_criteria: String,
+        ) -> Self {
+            Self {
+                object_id,
+                browse_flag,
+                filter,
+                starting_index,
+                requested_count,
+                sort_criteria,
+            }
+        }
+    }
+
     #[derive(FromXml, Debug, Clone, PartialEq)]
     #[xml(rename = "BrowseResponse", ns(SERVICE_TYPE))]
     pub struct BrowseResponse {
@@ -3354,7 +4474,10 @@ pub mod content_directory {
 
     impl crate::DecodeSoapResponse for BrowseResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode("content_directory::BrowseResponse", error, xml)
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -3379,7 +4502,14 @@ pub mod content_directory {
 
     impl crate::DecodeSoapResponse for CreateObjectResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode(
+                        "content_directory::CreateObjectResponse",
+                        error,
+                        xml,
+                    )
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -3411,7 +4541,14 @@ pub mod content_directory {
 
     impl crate::DecodeSoapResponse for FindPrefixResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode(
+                        "content_directory::FindPrefixResponse",
+                        error,
+                        xml,
+                    )
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -3425,7 +4562,14 @@ pub mod content_directory {
 
     impl crate::DecodeSoapResponse for GetAlbumArtistDisplayOptionResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode(
+                        "content_directory::GetAlbumArtistDisplayOptionResponse",
+                        error,
+                        xml,
+                    )
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -3450,7 +4594,14 @@ pub mod content_directory {
 
     impl crate::DecodeSoapResponse for GetAllPrefixLocationsResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode(
+                        "content_directory::GetAllPrefixLocationsResponse",
+                        error,
+                        xml,
+                    )
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -3464,7 +4615,14 @@ pub mod content_directory {
 
     impl crate::DecodeSoapResponse for GetBrowseableResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode(
+                        "content_directory::GetBrowseableResponse",
+                        error,
+                        xml,
+                    )
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -3478,7 +4636,14 @@ pub mod content_directory {
 
     impl crate::DecodeSoapResponse for GetLastIndexChangeResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode(
+                        "content_directory::GetLastIndexChangeResponse",
+                        error,
+                        xml,
+                    )
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -3492,7 +4657,14 @@ pub mod content_directory {
 
     impl crate::DecodeSoapResponse for GetSearchCapabilitiesResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode(
+                        "content_directory::GetSearchCapabilitiesResponse",
+                        error,
+                        xml,
+                    )
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -3506,7 +4678,14 @@ pub mod content_directory {
 
     impl crate::DecodeSoapResponse for GetShareIndexInProgressResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode(
+                        "content_directory::GetShareIndexInProgressResponse",
+                        error,
+                        xml,
+                    )
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -3520,7 +4699,14 @@ pub mod content_directory {
 
     impl crate::DecodeSoapResponse for GetSortCapabilitiesResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode(
+                        "content_directory::GetSortCapabilitiesResponse",
+                        error,
+                        xml,
+                    )
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -3534,7 +4720,14 @@ pub mod content_directory {
 
     impl crate::DecodeSoapResponse for GetSystemUpdateIdResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode(
+                        "content_directory::GetSystemUpdateIdResponse",
+                        error,
+                        xml,
+                    )
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -3695,7 +4888,7 @@ pub mod content_directory {
         pub async fn subscribe_content_directory(
             &self,
         ) -> crate::Result<crate::upnp::EventStream<ContentDirectoryEvent>> {
-            self.subscribe_helper(&SERVICE_TYPE).await
+            self.subscribe_helper(SERVICE_TYPE).await
         }
     }
 }
@@ -3712,12 +4905,12 @@ pub enum BrowseFlag {
     Unspecified(String),
 }
 
-impl ToString for BrowseFlag {
-    fn to_string(&self) -> String {
+impl std::fmt::Display for BrowseFlag {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
-            BrowseFlag::BrowseMetadata => "BrowseMetadata".to_string(),
-            BrowseFlag::BrowseDirectChildren => "BrowseDirectChildren".to_string(),
-            BrowseFlag::Unspecified(s) => s.to_string(),
+            BrowseFlag::BrowseMetadata => f.write_str("BrowseMetadata"),
+            BrowseFlag::BrowseDirectChildren => f.write_str("BrowseDirectChildren"),
+            BrowseFlag::Unspecified(s) => f.write_str(s),
         }
     }
 }
@@ -3791,6 +4984,202 @@ pub mod device_properties {
     /// `urn:schemas-upnp-org:service:DeviceProperties:1`
     pub const SERVICE_TYPE: &str = "urn:schemas-upnp-org:service:DeviceProperties:1";
 
+    /// The device models that are known to support each action
+    /// of this service, sorted by action name.
+    /// The models are identified by their `modelNumber`.
+    pub const ACTION_MODELS: &[(&str, &[&str])] = &[
+        (
+            "AddBondedZones",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "AddHTSatellite",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "CreateStereoPair",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "EnterConfigMode",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "ExitConfigMode",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "GetAutoplayLinkedZones",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "GetAutoplayRoomUUID",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "GetAutoplayVolume",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "GetButtonLockState",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "GetButtonState",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        ("GetHTForwardState", &["S19", "S38"]),
+        (
+            "GetHouseholdID",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "GetLEDState",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "GetUseAutoplayVolume",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "GetZoneAttributes",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "GetZoneInfo",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "RemoveBondedZones",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "RemoveHTSatellite",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "RoomDetectionStartChirping",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S6", "S9",
+                "Sub",
+            ],
+        ),
+        (
+            "RoomDetectionStopChirping",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S6", "S9",
+                "Sub",
+            ],
+        ),
+        (
+            "SeparateStereoPair",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "SetAutoplayLinkedZones",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "SetAutoplayRoomUUID",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "SetAutoplayVolume",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "SetButtonLockState",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "SetLEDState",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "SetUseAutoplayVolume",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "SetZoneAttributes",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+    ];
+
     #[derive(ToXml, Debug, Clone, PartialEq, Default)]
     #[xml(rename = "AddBondedZones", ns(SERVICE_TYPE))]
     pub struct AddBondedZonesRequest {
@@ -3832,7 +5221,14 @@ pub mod device_properties {
 
     impl crate::DecodeSoapResponse for EnterConfigModeResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode(
+                        "device_properties::EnterConfigModeResponse",
+                        error,
+                        xml,
+                    )
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -3860,7 +5256,14 @@ pub mod device_properties {
 
     impl crate::DecodeSoapResponse for GetAutoplayLinkedZonesResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode(
+                        "device_properties::GetAutoplayLinkedZonesResponse",
+                        error,
+                        xml,
+                    )
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -3881,7 +5284,14 @@ pub mod device_properties {
 
     impl crate::DecodeSoapResponse for GetAutoplayRoomUuidResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode(
+                        "device_properties::GetAutoplayRoomUuidResponse",
+                        error,
+                        xml,
+                    )
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -3902,7 +5312,14 @@ pub mod device_properties {
 
     impl crate::DecodeSoapResponse for GetAutoplayVolumeResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode(
+                        "device_properties::GetAutoplayVolumeResponse",
+                        error,
+                        xml,
+                    )
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -3916,7 +5333,14 @@ pub mod device_properties {
 
     impl crate::DecodeSoapResponse for GetButtonLockStateResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode(
+                        "device_properties::GetButtonLockStateResponse",
+                        error,
+                        xml,
+                    )
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -3930,7 +5354,14 @@ pub mod device_properties {
 
     impl crate::DecodeSoapResponse for GetButtonStateResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode(
+                        "device_properties::GetButtonStateResponse",
+                        error,
+                        xml,
+                    )
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -3944,7 +5375,14 @@ pub mod device_properties {
 
     impl crate::DecodeSoapResponse for GetHtForwardStateResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode(
+                        "device_properties::GetHtForwardStateResponse",
+                        error,
+                        xml,
+                    )
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -3958,7 +5396,14 @@ pub mod device_properties {
 
     impl crate::DecodeSoapResponse for GetHouseholdIdResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode(
+                        "device_properties::GetHouseholdIdResponse",
+                        error,
+                        xml,
+                    )
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -3972,7 +5417,14 @@ pub mod device_properties {
 
     impl crate::DecodeSoapResponse for GetLedStateResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode(
+                        "device_properties::GetLedStateResponse",
+                        error,
+                        xml,
+                    )
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -3993,7 +5445,14 @@ pub mod device_properties {
 
     impl crate::DecodeSoapResponse for GetUseAutoplayVolumeResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode(
+                        "device_properties::GetUseAutoplayVolumeResponse",
+                        error,
+                        xml,
+                    )
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -4013,7 +5472,14 @@ pub mod device_properties {
 
     impl crate::DecodeSoapResponse for GetZoneAttributesResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode(
+                        "device_properties::GetZoneAttributesResponse",
+                        error,
+                        xml,
+                    )
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -4045,7 +5511,14 @@ pub mod device_properties {
 
     impl crate::DecodeSoapResponse for GetZoneInfoResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode(
+                        "device_properties::GetZoneInfoResponse",
+                        error,
+                        xml,
+                    )
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -4089,7 +5562,14 @@ pub mod device_properties {
 
     impl crate::DecodeSoapResponse for RoomDetectionStartChirpingResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode(
+                        "device_properties::RoomDetectionStartChirpingResponse",
+                        error,
+                        xml,
+                    )
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -4172,6 +5652,23 @@ pub mod device_properties {
         pub desired_target_room_name: String,
     }
 
+    impl SetZoneAttributesRequest {
+        /// Constructs a request from its fields
+        pub fn new(
+            desired_zone_name: String,
+            desired_icon: String,
+            desired_configuration: String,
+            desired_target_room_name: String,
+        ) -> Self {
+            Self {
+                desired_zone_name,
+                desired_icon,
+                desired_configuration,
+                desired_target_room_name,
+            }
+        }
+    }
+
     /// A parsed event produced by the `DeviceProperties` service.
     /// Use `SonosDevice::subscribe_device_properties()` to obtain an event
     /// stream that produces these.
@@ -4428,7 +5925,7 @@ pub mod device_properties {
         pub async fn subscribe_device_properties(
             &self,
         ) -> crate::Result<crate::upnp::EventStream<DevicePropertiesEvent>> {
-            self.subscribe_helper(&SERVICE_TYPE).await
+            self.subscribe_helper(SERVICE_TYPE).await
         }
     }
 }
@@ -4445,12 +5942,12 @@ pub enum ButtonLockState {
     Unspecified(String),
 }
 
-impl ToString for ButtonLockState {
-    fn to_string(&self) -> String {
+impl std::fmt::Display for ButtonLockState {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
-            ButtonLockState::On => "On".to_string(),
-            ButtonLockState::Off => "Off".to_string(),
-            ButtonLockState::Unspecified(s) => s.to_string(),
+            ButtonLockState::On => f.write_str("On"),
+            ButtonLockState::Off => f.write_str("Off"),
+            ButtonLockState::Unspecified(s) => f.write_str(s),
         }
     }
 }
@@ -4528,12 +6025,12 @@ pub enum LEDState {
     Unspecified(String),
 }
 
-impl ToString for LEDState {
-    fn to_string(&self) -> String {
+impl std::fmt::Display for LEDState {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
-            LEDState::On => "On".to_string(),
-            LEDState::Off => "Off".to_string(),
-            LEDState::Unspecified(s) => s.to_string(),
+            LEDState::On => f.write_str("On"),
+            LEDState::Off => f.write_str("Off"),
+            LEDState::Unspecified(s) => f.write_str(s),
         }
     }
 }
@@ -4607,6 +6104,40 @@ pub mod group_management {
     /// `urn:schemas-upnp-org:service:GroupManagement:1`
     pub const SERVICE_TYPE: &str = "urn:schemas-upnp-org:service:GroupManagement:1";
 
+    /// The device models that are known to support each action
+    /// of this service, sorted by action name.
+    /// The models are identified by their `modelNumber`.
+    pub const ACTION_MODELS: &[(&str, &[&str])] = &[
+        (
+            "AddMember",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "RemoveMember",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "ReportTrackBufferingResult",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "SetSourceAreaIds",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+    ];
+
     #[derive(ToXml, Debug, Clone, PartialEq, Default)]
     #[xml(rename = "AddMember", ns(SERVICE_TYPE))]
     pub struct AddMemberRequest {
@@ -4633,7 +6164,10 @@ pub mod group_management {
 
     impl crate::DecodeSoapResponse for AddMemberResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode("group_management::AddMemberResponse", error, xml)
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -4728,7 +6262,7 @@ pub mod group_management {
         pub async fn subscribe_group_management(
             &self,
         ) -> crate::Result<crate::upnp::EventStream<GroupManagementEvent>> {
-            self.subscribe_helper(&SERVICE_TYPE).await
+            self.subscribe_helper(SERVICE_TYPE).await
         }
     }
 }
@@ -4741,6 +6275,54 @@ pub mod group_rendering_control {
     /// `urn:schemas-upnp-org:service:GroupRenderingControl:1`
     pub const SERVICE_TYPE: &str = "urn:schemas-upnp-org:service:GroupRenderingControl:1";
 
+    /// The device models that are known to support each action
+    /// of this service, sorted by action name.
+    /// The models are identified by their `modelNumber`.
+    pub const ACTION_MODELS: &[(&str, &[&str])] = &[
+        (
+            "GetGroupMute",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "GetGroupVolume",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "SetGroupMute",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "SetGroupVolume",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "SetRelativeGroupVolume",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "SnapshotGroupVolume",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+    ];
+
     #[derive(ToXml, Debug, Clone, PartialEq, Default)]
     #[xml(rename = "GetGroupMute", ns(SERVICE_TYPE))]
     pub struct GetGroupMuteRequest {
@@ -4757,7 +6339,14 @@ pub mod group_rendering_control {
 
     impl crate::DecodeSoapResponse for GetGroupMuteResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode(
+                        "group_rendering_control::GetGroupMuteResponse",
+                        error,
+                        xml,
+                    )
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -4778,7 +6367,14 @@ pub mod group_rendering_control {
 
     impl crate::DecodeSoapResponse for GetGroupVolumeResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode(
+                        "group_rendering_control::GetGroupVolumeResponse",
+                        error,
+                        xml,
+                    )
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -4821,7 +6417,14 @@ pub mod group_rendering_control {
 
     impl crate::DecodeSoapResponse for SetRelativeGroupVolumeResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode(
+                        "group_rendering_control::SetRelativeGroupVolumeResponse",
+                        error,
+                        xml,
+                    )
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -4886,7 +6489,7 @@ pub mod group_rendering_control {
         pub async fn subscribe_group_rendering_control(
             &self,
         ) -> crate::Result<crate::upnp::EventStream<GroupRenderingControlEvent>> {
-            self.subscribe_helper(&SERVICE_TYPE).await
+            self.subscribe_helper(SERVICE_TYPE).await
         }
     }
 }
@@ -4899,6 +6502,20 @@ pub mod ht_control {
     /// `urn:schemas-upnp-org:service:HTControl:1`
     pub const SERVICE_TYPE: &str = "urn:schemas-upnp-org:service:HTControl:1";
 
+    /// The device models that are known to support each action
+    /// of this service, sorted by action name.
+    /// The models are identified by their `modelNumber`.
+    pub const ACTION_MODELS: &[(&str, &[&str])] = &[
+        ("CommitLearnedIRCodes", &["S14", "S19", "S9"]),
+        ("GetIRRepeaterState", &["S14", "S19", "S9"]),
+        ("GetLEDFeedbackState", &["S14", "S19", "S9"]),
+        ("IdentifyIRRemote", &["S14", "S19", "S9"]),
+        ("IsRemoteConfigured", &["S14", "S19", "S9"]),
+        ("LearnIRCode", &["S14", "S19", "S9"]),
+        ("SetIRRepeaterState", &["S14", "S19", "S9"]),
+        ("SetLEDFeedbackState", &["S14", "S19", "S9"]),
+    ];
+
     #[derive(ToXml, Debug, Clone, PartialEq, Default)]
     #[xml(rename = "CommitLearnedIRCodes", ns(SERVICE_TYPE))]
     pub struct CommitLearnedIrCodesRequest {
@@ -4915,7 +6532,14 @@ pub mod ht_control {
 
     impl crate::DecodeSoapResponse for GetIrRepeaterStateResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode(
+                        "ht_control::GetIrRepeaterStateResponse",
+                        error,
+                        xml,
+                    )
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -4929,7 +6553,14 @@ pub mod ht_control {
 
     impl crate::DecodeSoapResponse for GetLedFeedbackStateResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode(
+                        "ht_control::GetLedFeedbackStateResponse",
+                        error,
+                        xml,
+                    )
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -4950,7 +6581,14 @@ pub mod ht_control {
 
     impl crate::DecodeSoapResponse for IsRemoteConfiguredResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode(
+                        "ht_control::IsRemoteConfiguredResponse",
+                        error,
+                        xml,
+                    )
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -5024,7 +6662,7 @@ pub mod ht_control {
         pub async fn subscribe_ht_control(
             &self,
         ) -> crate::Result<crate::upnp::EventStream<HTControlEvent>> {
-            self.subscribe_helper(&SERVICE_TYPE).await
+            self.subscribe_helper(SERVICE_TYPE).await
         }
     }
 }
@@ -5042,13 +6680,13 @@ pub enum IRRepeaterState {
     Unspecified(String),
 }
 
-impl ToString for IRRepeaterState {
-    fn to_string(&self) -> String {
+impl std::fmt::Display for IRRepeaterState {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
-            IRRepeaterState::On => "On".to_string(),
-            IRRepeaterState::Off => "Off".to_string(),
-            IRRepeaterState::Disabled => "Disabled".to_string(),
-            IRRepeaterState::Unspecified(s) => s.to_string(),
+            IRRepeaterState::On => f.write_str("On"),
+            IRRepeaterState::Off => f.write_str("Off"),
+            IRRepeaterState::Disabled => f.write_str("Disabled"),
+            IRRepeaterState::Unspecified(s) => f.write_str(s),
         }
     }
 }
@@ -5127,12 +6765,12 @@ pub enum LEDFeedbackState {
     Unspecified(String),
 }
 
-impl ToString for LEDFeedbackState {
-    fn to_string(&self) -> String {
+impl std::fmt::Display for LEDFeedbackState {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
-            LEDFeedbackState::On => "On".to_string(),
-            LEDFeedbackState::Off => "Off".to_string(),
-            LEDFeedbackState::Unspecified(s) => s.to_string(),
+            LEDFeedbackState::On => f.write_str("On"),
+            LEDFeedbackState::Off => f.write_str("Off"),
+            LEDFeedbackState::Unspecified(s) => f.write_str(s),
         }
     }
 }
@@ -5206,6 +6844,33 @@ pub mod music_services {
     /// `urn:schemas-upnp-org:service:MusicServices:1`
     pub const SERVICE_TYPE: &str = "urn:schemas-upnp-org:service:MusicServices:1";
 
+    /// The device models that are known to support each action
+    /// of this service, sorted by action name.
+    /// The models are identified by their `modelNumber`.
+    pub const ACTION_MODELS: &[(&str, &[&str])] = &[
+        (
+            "GetSessionId",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "ListAvailableServices",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "UpdateAvailableServices",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+    ];
+
     #[derive(ToXml, Debug, Clone, PartialEq, Default)]
     #[xml(rename = "GetSessionId", ns(SERVICE_TYPE))]
     pub struct GetSessionIdRequest {
@@ -5224,7 +6889,14 @@ pub mod music_services {
 
     impl crate::DecodeSoapResponse for GetSessionIdResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode(
+                        "music_services::GetSessionIdResponse",
+                        error,
+                        xml,
+                    )
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -5242,7 +6914,14 @@ pub mod music_services {
 
     impl crate::DecodeSoapResponse for ListAvailableServicesResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode(
+                        "music_services::ListAvailableServicesResponse",
+                        error,
+                        xml,
+                    )
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -5286,7 +6965,7 @@ pub mod music_services {
         pub async fn subscribe_music_services(
             &self,
         ) -> crate::Result<crate::upnp::EventStream<MusicServicesEvent>> {
-            self.subscribe_helper(&SERVICE_TYPE).await
+            self.subscribe_helper(SERVICE_TYPE).await
         }
     }
 }
@@ -5299,6 +6978,17 @@ pub mod q_play {
     /// `urn:schemas-tencent-com:service:QPlay:1`
     pub const SERVICE_TYPE: &str = "urn:schemas-tencent-com:service:QPlay:1";
 
+    /// The device models that are known to support each action
+    /// of this service, sorted by action name.
+    /// The models are identified by their `modelNumber`.
+    pub const ACTION_MODELS: &[(&str, &[&str])] = &[(
+        "QPlayAuth",
+        &[
+            "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6", "S9",
+            "Sub",
+        ],
+    )];
+
     #[derive(ToXml, Debug, Clone, PartialEq, Default)]
     #[xml(rename = "QPlayAuth", ns(SERVICE_TYPE))]
     pub struct QPlayAuthRequest {
@@ -5319,7 +7009,10 @@ pub mod q_play {
 
     impl crate::DecodeSoapResponse for QPlayAuthResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode("q_play::QPlayAuthResponse", error, xml)
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -5333,6 +7026,89 @@ pub mod queue {
     /// `urn:schemas-sonos-com:service:Queue:1`
     pub const SERVICE_TYPE: &str = "urn:schemas-sonos-com:service:Queue:1";
 
+    /// The device models that are known to support each action
+    /// of this service, sorted by action name.
+    /// The models are identified by their `modelNumber`.
+    pub const ACTION_MODELS: &[(&str, &[&str])] = &[
+        (
+            "AddMultipleURIs",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "AddURI",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "AttachQueue",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "Backup",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "Browse",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "CreateQueue",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "RemoveAllTracks",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "RemoveTrackRange",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "ReorderTracks",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "ReplaceAllTracks",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "SaveAsSonosPlaylist",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+    ];
+
     #[derive(ToXml, Debug, Clone, PartialEq, Default)]
     #[xml(rename = "AddMultipleURIs", ns(SERVICE_TYPE))]
     pub struct AddMultipleUrisRequest {
@@ -5354,6 +7130,32 @@ pub mod queue {
         pub enqueued_uris_and_meta_data: String,
     }
 
+    impl AddMultipleUrisRequest {
+        /// Constructs a request from its fields
+        #[allow(clippy::too_many_arguments)]
+        pub fn new(
+            queue_id: u32,
+            update_id: u32,
+            container_uri: String,
+            container_meta_data: String,
+            desired_first_track_number_enqueued: u32,
+            enqueue_as_next: bool,
+            number_of_uris: u32,
+            enqueued_uris_and_meta_data: String,
+        ) -> Self {
+            Self {
+                queue_id,
+                update_id,
+                container_uri,
+                container_meta_data,
+                desired_first_track_number_enqueued,
+                enqueue_as_next,
+                number_of_uris,
+                enqueued_uris_and_meta_data,
+            }
+        }
+    }
+
     #[derive(FromXml, Debug, Clone, PartialEq)]
     #[xml(rename = "AddMultipleURIsResponse", ns(SERVICE_TYPE))]
     pub struct AddMultipleUrisResponse {
@@ -5369,7 +7171,10 @@ pub mod queue {
 
     impl crate::DecodeSoapResponse for AddMultipleUrisResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode("queue::AddMultipleUrisResponse", error, xml)
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -5391,6 +7196,27 @@ pub mod queue {
         pub enqueue_as_next: bool,
     }
 
+    impl AddUriRequest {
+        /// Constructs a request from its fields
+        pub fn new(
+            queue_id: u32,
+            update_id: u32,
+            enqueued_uri: String,
+            enqueued_uri_meta_data: DecodeXmlString<crate::TrackMetaData>,
+            desired_first_track_number_enqueued: u32,
+            enqueue_as_next: bool,
+        ) -> Self {
+            Self {
+                queue_id,
+                update_id,
+                enqueued_uri,
+                enqueued_uri_meta_data,
+                desired_first_track_number_enqueued,
+                enqueue_as_next,
+            }
+        }
+    }
+
     #[derive(FromXml, Debug, Clone, PartialEq)]
     #[xml(rename = "AddURIResponse", ns(SERVICE_TYPE))]
     pub struct AddUriResponse {
@@ -5406,7 +7232,10 @@ pub mod queue {
 
     impl crate::DecodeSoapResponse for AddUriResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode("queue::AddUriResponse", error, xml)
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -5429,7 +7258,10 @@ pub mod queue {
 
     impl crate::DecodeSoapResponse for AttachQueueResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode("queue::AttachQueueResponse", error, xml)
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -5460,7 +7292,10 @@ pub mod queue {
 
     impl crate::DecodeSoapResponse for BrowseResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode("queue::BrowseResponse", error, xml)
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -5485,7 +7320,10 @@ pub mod queue {
 
     impl crate::DecodeSoapResponse for CreateQueueResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode("queue::CreateQueueResponse", error, xml)
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -5508,7 +7346,10 @@ pub mod queue {
 
     impl crate::DecodeSoapResponse for RemoveAllTracksResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode("queue::RemoveAllTracksResponse", error, xml)
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -5526,6 +7367,23 @@ pub mod queue {
         pub number_of_tracks: u32,
     }
 
+    impl RemoveTrackRangeRequest {
+        /// Constructs a request from its fields
+        pub fn new(
+            queue_id: u32,
+            update_id: u32,
+            starting_index: u32,
+            number_of_tracks: u32,
+        ) -> Self {
+            Self {
+                queue_id,
+                update_id,
+                starting_index,
+                number_of_tracks,
+            }
+        }
+    }
+
     #[derive(FromXml, Debug, Clone, PartialEq)]
     #[xml(rename = "RemoveTrackRangeResponse", ns(SERVICE_TYPE))]
     pub struct RemoveTrackRangeResponse {
@@ -5535,7 +7393,10 @@ pub mod queue {
 
     impl crate::DecodeSoapResponse for RemoveTrackRangeResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode("queue::RemoveTrackRangeResponse", error, xml)
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -5555,6 +7416,25 @@ pub mod queue {
         pub update_id: u32,
     }
 
+    impl ReorderTracksRequest {
+        /// Constructs a request from its fields
+        pub fn new(
+            queue_id: u32,
+            starting_index: u32,
+            number_of_tracks: u32,
+            insert_before: u32,
+            update_id: u32,
+        ) -> Self {
+            Self {
+                queue_id,
+                starting_index,
+                number_of_tracks,
+                insert_before,
+                update_id,
+            }
+        }
+    }
+
     #[derive(FromXml, Debug, Clone, PartialEq)]
     #[xml(rename = "ReorderTracksResponse", ns(SERVICE_TYPE))]
     pub struct ReorderTracksResponse {
@@ -5564,7 +7444,10 @@ pub mod queue {
 
     impl crate::DecodeSoapResponse for ReorderTracksResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode("queue::ReorderTracksResponse", error, xml)
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -5590,6 +7473,32 @@ pub mod queue {
         pub enqueued_uris_and_meta_data: String,
     }
 
+    impl ReplaceAllTracksRequest {
+        /// Constructs a request from its fields
+        #[allow(clippy::too_many_arguments)]
+        pub fn new(
+            queue_id: u32,
+            update_id: u32,
+            container_uri: String,
+            container_meta_data: String,
+            current_track_index: u32,
+            new_current_track_indices: String,
+            number_of_uris: u32,
+            enqueued_uris_and_meta_data: String,
+        ) -> Self {
+            Self {
+                queue_id,
+                update_id,
+                container_uri,
+                container_meta_data,
+                current_track_index,
+                new_current_track_indices,
+                number_of_uris,
+                enqueued_uris_and_meta_data,
+            }
+        }
+    }
+
     #[derive(FromXml, Debug, Clone, PartialEq)]
     #[xml(rename = "ReplaceAllTracksResponse", ns(SERVICE_TYPE))]
     pub struct ReplaceAllTracksResponse {
@@ -5601,7 +7510,10 @@ pub mod queue {
 
     impl crate::DecodeSoapResponse for ReplaceAllTracksResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode("queue::ReplaceAllTracksResponse", error, xml)
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -5626,7 +7538,10 @@ pub mod queue {
 
     impl crate::DecodeSoapResponse for SaveAsSonosPlaylistResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode("queue::SaveAsSonosPlaylistResponse", error, xml)
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -5668,7 +7583,7 @@ pub mod queue {
     impl crate::SonosDevice {
         /// Subscribe to events from the `Queue` service on this device
         pub async fn subscribe_queue(&self) -> crate::Result<crate::upnp::EventStream<QueueEvent>> {
-            self.subscribe_helper(&SERVICE_TYPE).await
+            self.subscribe_helper(SERVICE_TYPE).await
         }
     }
 
@@ -5893,45 +7808,45 @@ pub mod queue {
             let mut map = std::collections::BTreeMap::new();
 
             for item in last_change.instance {
-                let mut result = QueueLastChange::default();
+                let result = QueueLastChange {
+                    count: item.count.and_then(|v| v.val),
 
-                result.count = item.count.and_then(|v| v.val);
+                    enqueue_as_next: item.enqueue_as_next.and_then(|v| v.val),
 
-                result.enqueue_as_next = item.enqueue_as_next.and_then(|v| v.val);
+                    index: item.index.and_then(|v| v.val),
 
-                result.index = item.index.and_then(|v| v.val);
+                    list_uri: item.list_uri.and_then(|v| v.val),
 
-                result.list_uri = item.list_uri.and_then(|v| v.val);
+                    list_uri_and_metadata: item.list_uri_and_metadata.and_then(|v| v.val),
 
-                result.list_uri_and_metadata = item.list_uri_and_metadata.and_then(|v| v.val);
+                    num_tracks: item.num_tracks.and_then(|v| v.val),
 
-                result.num_tracks = item.num_tracks.and_then(|v| v.val);
+                    object_id: item.object_id.and_then(|v| v.val),
 
-                result.object_id = item.object_id.and_then(|v| v.val);
+                    queue_id: item.queue_id.and_then(|v| v.val),
 
-                result.queue_id = item.queue_id.and_then(|v| v.val);
+                    queue_owner_context: item.queue_owner_context.and_then(|v| v.val),
 
-                result.queue_owner_context = item.queue_owner_context.and_then(|v| v.val);
+                    queue_owner_id: item.queue_owner_id.and_then(|v| v.val),
 
-                result.queue_owner_id = item.queue_owner_id.and_then(|v| v.val);
+                    queue_policy: item.queue_policy.and_then(|v| v.val),
 
-                result.queue_policy = item.queue_policy.and_then(|v| v.val);
+                    result: item.result.and_then(|v| v.val),
 
-                result.result = item.result.and_then(|v| v.val);
+                    saved_queue_title: item.saved_queue_title.and_then(|v| v.val),
 
-                result.saved_queue_title = item.saved_queue_title.and_then(|v| v.val);
+                    track_number: item.track_number.and_then(|v| v.val),
 
-                result.track_number = item.track_number.and_then(|v| v.val);
+                    track_numbers_csv: item.track_numbers_csv.and_then(|v| v.val),
 
-                result.track_numbers_csv = item.track_numbers_csv.and_then(|v| v.val);
+                    uri: item.uri.and_then(|v| v.val),
 
-                result.uri = item.uri.and_then(|v| v.val);
+                    uri_meta_data: item.uri_meta_data.and_then(|v| v.val),
 
-                result.uri_meta_data = item.uri_meta_data.and_then(|v| v.val);
+                    update_id: item.update_id.and_then(|v| v.val),
 
-                result.update_id = item.update_id.and_then(|v| v.val);
-
-                result.curated = item.curated.and_then(|v| v.val);
+                    curated: item.curated.and_then(|v| v.val),
+                };
 
                 map.insert(item.object_instance_id_, result);
             }
@@ -5949,6 +7864,205 @@ pub mod rendering_control {
     /// `urn:schemas-upnp-org:service:RenderingControl:1`
     pub const SERVICE_TYPE: &str = "urn:schemas-upnp-org:service:RenderingControl:1";
 
+    /// The device models that are known to support each action
+    /// of this service, sorted by action name.
+    /// The models are identified by their `modelNumber`.
+    pub const ACTION_MODELS: &[(&str, &[&str])] = &[
+        (
+            "GetBass",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "GetEQ",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "GetHeadphoneConnected",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "GetLoudness",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "GetMute",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "GetOutputFixed",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "GetRoomCalibrationStatus",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "GetSupportsOutputFixed",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "GetTreble",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "GetVolume",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "GetVolumeDB",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "GetVolumeDBRange",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "RampToVolume",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "ResetBasicEQ",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "ResetExtEQ",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "RestoreVolumePriorToRamp",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "SetBass",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "SetChannelMap",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "SetEQ",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "SetLoudness",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "SetMute",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "SetOutputFixed",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "SetRelativeVolume",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "SetRoomCalibrationStatus",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "SetRoomCalibrationX",
+            &["S13", "S14", "S21", "S27", "S3", "S5", "S6", "Sub"],
+        ),
+        (
+            "SetTreble",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "SetVolume",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "SetVolumeDB",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+    ];
+
     #[derive(ToXml, Debug, Clone, PartialEq, Default)]
     #[xml(rename = "GetBass", ns(SERVICE_TYPE))]
     pub struct GetBassRequest {
@@ -5965,7 +8079,10 @@ pub mod rendering_control {
 
     impl crate::DecodeSoapResponse for GetBassResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode("rendering_control::GetBassResponse", error, xml)
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -5989,7 +8106,10 @@ pub mod rendering_control {
 
     impl crate::DecodeSoapResponse for GetEqResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode("rendering_control::GetEqResponse", error, xml)
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -6010,7 +8130,14 @@ pub mod rendering_control {
 
     impl crate::DecodeSoapResponse for GetHeadphoneConnectedResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode(
+                        "rendering_control::GetHeadphoneConnectedResponse",
+                        error,
+                        xml,
+                    )
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -6033,7 +8160,14 @@ pub mod rendering_control {
 
     impl crate::DecodeSoapResponse for GetLoudnessResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode(
+                        "rendering_control::GetLoudnessResponse",
+                        error,
+                        xml,
+                    )
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -6056,7 +8190,10 @@ pub mod rendering_control {
 
     impl crate::DecodeSoapResponse for GetMuteResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode("rendering_control::GetMuteResponse", error, xml)
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -6077,7 +8214,14 @@ pub mod rendering_control {
 
     impl crate::DecodeSoapResponse for GetOutputFixedResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode(
+                        "rendering_control::GetOutputFixedResponse",
+                        error,
+                        xml,
+                    )
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -6100,7 +8244,14 @@ pub mod rendering_control {
 
     impl crate::DecodeSoapResponse for GetRoomCalibrationStatusResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode(
+                        "rendering_control::GetRoomCalibrationStatusResponse",
+                        error,
+                        xml,
+                    )
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -6121,7 +8272,14 @@ pub mod rendering_control {
 
     impl crate::DecodeSoapResponse for GetSupportsOutputFixedResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode(
+                        "rendering_control::GetSupportsOutputFixedResponse",
+                        error,
+                        xml,
+                    )
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -6142,7 +8300,14 @@ pub mod rendering_control {
 
     impl crate::DecodeSoapResponse for GetTrebleResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode(
+                        "rendering_control::GetTrebleResponse",
+                        error,
+                        xml,
+                    )
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -6165,7 +8330,14 @@ pub mod rendering_control {
 
     impl crate::DecodeSoapResponse for GetVolumeResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode(
+                        "rendering_control::GetVolumeResponse",
+                        error,
+                        xml,
+                    )
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -6188,7 +8360,14 @@ pub mod rendering_control {
 
     impl crate::DecodeSoapResponse for GetVolumeDbResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode(
+                        "rendering_control::GetVolumeDbResponse",
+                        error,
+                        xml,
+                    )
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -6213,7 +8392,14 @@ pub mod rendering_control {
 
     impl crate::DecodeSoapResponse for GetVolumeDbRangeResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode(
+                        "rendering_control::GetVolumeDbRangeResponse",
+                        error,
+                        xml,
+                    )
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -6235,6 +8421,26 @@ pub mod rendering_control {
         pub program_uri: String,
     }
 
+    impl RampToVolumeRequest {
+        /// Constructs a request with `instance_id` set to 0
+        pub fn new(
+            channel: super::Channel,
+            ramp_type: super::RampType,
+            desired_volume: u16,
+            reset_volume_after: bool,
+            program_uri: String,
+        ) -> Self {
+            Self {
+                instance_id: 0,
+                channel,
+                ramp_type,
+                desired_volume,
+                reset_volume_after,
+                program_uri,
+            }
+        }
+    }
+
     #[derive(FromXml, Debug, Clone, PartialEq)]
     #[xml(rename = "RampToVolumeResponse", ns(SERVICE_TYPE))]
     pub struct RampToVolumeResponse {
@@ -6244,7 +8450,14 @@ pub mod rendering_control {
 
     impl crate::DecodeSoapResponse for RampToVolumeResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode(
+                        "rendering_control::RampToVolumeResponse",
+                        error,
+                        xml,
+                    )
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -6273,7 +8486,14 @@ pub mod rendering_control {
 
     impl crate::DecodeSoapResponse for ResetBasicEqResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode(
+                        "rendering_control::ResetBasicEqResponse",
+                        error,
+                        xml,
+                    )
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -6378,7 +8598,14 @@ pub mod rendering_control {
 
     impl crate::DecodeSoapResponse for SetRelativeVolumeResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode(
+                        "rendering_control::SetRelativeVolumeResponse",
+                        error,
+                        xml,
+                    )
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -6405,6 +8632,18 @@ pub mod rendering_control {
         pub calibration_mode: String,
     }
 
+    impl SetRoomCalibrationXRequest {
+        /// Constructs a request with `instance_id` set to 0
+        pub fn new(calibration_id: String, coefficients: String, calibration_mode: String) -> Self {
+            Self {
+                instance_id: 0,
+                calibration_id,
+                coefficients,
+                calibration_mode,
+            }
+        }
+    }
+
     #[derive(ToXml, Debug, Clone, PartialEq, Default)]
     #[xml(rename = "SetTreble", ns(SERVICE_TYPE))]
     pub struct SetTrebleRequest {
@@ -6476,7 +8715,7 @@ pub mod rendering_control {
         pub async fn subscribe_rendering_control(
             &self,
         ) -> crate::Result<crate::upnp::EventStream<RenderingControlEvent>> {
-            self.subscribe_helper(&SERVICE_TYPE).await
+            self.subscribe_helper(SERVICE_TYPE).await
         }
     }
 
@@ -6933,94 +9172,95 @@ pub mod rendering_control {
             let mut map = std::collections::BTreeMap::new();
 
             for item in last_change.instance {
-                let mut result = RenderingControlLastChange::default();
-
-                result.channel = item.channel.and_then(|v| v.val);
+                let result = RenderingControlLastChange {
+                    channel: item.channel.and_then(|v| v.val),
 
-                result.channel_map = item.channel_map.and_then(|v| v.val);
+                    channel_map: item.channel_map.and_then(|v| v.val),
 
-                result.eq_type = item.eq_type.and_then(|v| v.val);
+                    eq_type: item.eq_type.and_then(|v| v.val),
 
-                result.instance_id = item.instance_id.and_then(|v| v.val);
+                    instance_id: item.instance_id.and_then(|v| v.val),
 
-                result.left_volume = item.left_volume.and_then(|v| v.val);
+                    left_volume: item.left_volume.and_then(|v| v.val),
 
-                result.mute_channel = item.mute_channel.and_then(|v| v.val);
+                    mute_channel: item.mute_channel.and_then(|v| v.val),
 
-                result.program_uri = item.program_uri.and_then(|v| v.val);
+                    program_uri: item.program_uri.and_then(|v| v.val),
 
-                result.ramp_time_seconds = item.ramp_time_seconds.and_then(|v| v.val);
+                    ramp_time_seconds: item.ramp_time_seconds.and_then(|v| v.val),
 
-                result.ramp_type = item.ramp_type.and_then(|v| v.val);
+                    ramp_type: item.ramp_type.and_then(|v| v.val),
 
-                result.reset_volume_after = item.reset_volume_after.and_then(|v| v.val);
+                    reset_volume_after: item.reset_volume_after.and_then(|v| v.val),
 
-                result.right_volume = item.right_volume.and_then(|v| v.val);
+                    right_volume: item.right_volume.and_then(|v| v.val),
 
-                result.volume_adjustment = item.volume_adjustment.and_then(|v| v.val);
+                    volume_adjustment: item.volume_adjustment.and_then(|v| v.val),
 
-                result.audio_delay = item.audio_delay.and_then(|v| v.val);
+                    audio_delay: item.audio_delay.and_then(|v| v.val),
 
-                result.audio_delay_left_rear = item.audio_delay_left_rear.and_then(|v| v.val);
+                    audio_delay_left_rear: item.audio_delay_left_rear.and_then(|v| v.val),
 
-                result.audio_delay_right_rear = item.audio_delay_right_rear.and_then(|v| v.val);
+                    audio_delay_right_rear: item.audio_delay_right_rear.and_then(|v| v.val),
 
-                result.bass = item.bass.and_then(|v| v.val);
+                    bass: item.bass.and_then(|v| v.val),
 
-                result.dialog_level = item.dialog_level.and_then(|v| v.val);
+                    dialog_level: item.dialog_level.and_then(|v| v.val),
 
-                result.eq_value = item.eq_value.and_then(|v| v.val);
+                    eq_value: item.eq_value.and_then(|v| v.val),
 
-                result.headphone_connected = item.headphone_connected.and_then(|v| v.val);
+                    headphone_connected: item.headphone_connected.and_then(|v| v.val),
 
-                result.loudness = item.loudness.and_then(|v| v.val);
+                    loudness: item.loudness.and_then(|v| v.val),
 
-                result.music_surround_level = item.music_surround_level.and_then(|v| v.val);
+                    music_surround_level: item.music_surround_level.and_then(|v| v.val),
 
-                result.mute = item.mute.and_then(|v| v.val);
+                    mute: item.mute.and_then(|v| v.val),
 
-                result.night_mode = item.night_mode.and_then(|v| v.val);
+                    night_mode: item.night_mode.and_then(|v| v.val),
 
-                result.output_fixed = item.output_fixed.and_then(|v| v.val);
+                    output_fixed: item.output_fixed.and_then(|v| v.val),
 
-                result.preset_name_list = item.preset_name_list.and_then(|v| v.val);
+                    preset_name_list: item.preset_name_list.and_then(|v| v.val),
 
-                result.room_calibration_available =
-                    item.room_calibration_available.and_then(|v| v.val);
+                    room_calibration_available: item.room_calibration_available.and_then(|v| v.val),
 
-                result.room_calibration_calibration_mode =
-                    item.room_calibration_calibration_mode.and_then(|v| v.val);
+                    room_calibration_calibration_mode: item
+                        .room_calibration_calibration_mode
+                        .and_then(|v| v.val),
 
-                result.room_calibration_coefficients =
-                    item.room_calibration_coefficients.and_then(|v| v.val);
+                    room_calibration_coefficients: item
+                        .room_calibration_coefficients
+                        .and_then(|v| v.val),
 
-                result.room_calibration_enabled = item.room_calibration_enabled.and_then(|v| v.val);
+                    room_calibration_enabled: item.room_calibration_enabled.and_then(|v| v.val),
 
-                result.room_calibration_id = item.room_calibration_id.and_then(|v| v.val);
+                    room_calibration_id: item.room_calibration_id.and_then(|v| v.val),
 
-                result.speaker_size = item.speaker_size.and_then(|v| v.val);
+                    speaker_size: item.speaker_size.and_then(|v| v.val),
 
-                result.sub_crossover = item.sub_crossover.and_then(|v| v.val);
+                    sub_crossover: item.sub_crossover.and_then(|v| v.val),
 
-                result.sub_enabled = item.sub_enabled.and_then(|v| v.val);
+                    sub_enabled: item.sub_enabled.and_then(|v| v.val),
 
-                result.sub_gain = item.sub_gain.and_then(|v| v.val);
+                    sub_gain: item.sub_gain.and_then(|v| v.val),
 
-                result.sub_polarity = item.sub_polarity.and_then(|v| v.val);
+                    sub_polarity: item.sub_polarity.and_then(|v| v.val),
 
-                result.supports_output_fixed = item.supports_output_fixed.and_then(|v| v.val);
+                    supports_output_fixed: item.supports_output_fixed.and_then(|v| v.val),
 
-                result.surround_enabled = item.surround_enabled.and_then(|v| v.val);
+                    surround_enabled: item.surround_enabled.and_then(|v| v.val),
 
-                result.surround_level = item.surround_level.and_then(|v| v.val);
+                    surround_level: item.surround_level.and_then(|v| v.val),
 
-                result.surround_mode = item.surround_mode.and_then(|v| v.val);
+                    surround_mode: item.surround_mode.and_then(|v| v.val),
 
-                result.treble = item.treble.and_then(|v| v.val);
+                    treble: item.treble.and_then(|v| v.val),
 
-                result.volume = item.volume.and_then(|v| v.val);
+                    volume: item.volume.and_then(|v| v.val),
 
-                result.volume_db = item.volume_db.and_then(|v| v.val);
+                    volume_db: item.volume_db.and_then(|v| v.val),
+                };
 
                 map.insert(item.object_instance_id_, result);
             }
@@ -7043,13 +9283,13 @@ pub enum Channel {
     Unspecified(String),
 }
 
-impl ToString for Channel {
-    fn to_string(&self) -> String {
+impl std::fmt::Display for Channel {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
-            Channel::Master => "Master".to_string(),
-            Channel::Lf => "LF".to_string(),
-            Channel::Rf => "RF".to_string(),
-            Channel::Unspecified(s) => s.to_string(),
+            Channel::Master => f.write_str("Master"),
+            Channel::Lf => f.write_str("LF"),
+            Channel::Rf => f.write_str("RF"),
+            Channel::Unspecified(s) => f.write_str(s),
         }
     }
 }
@@ -7130,14 +9370,14 @@ pub enum MuteChannel {
     Unspecified(String),
 }
 
-impl ToString for MuteChannel {
-    fn to_string(&self) -> String {
+impl std::fmt::Display for MuteChannel {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
-            MuteChannel::Master => "Master".to_string(),
-            MuteChannel::Lf => "LF".to_string(),
-            MuteChannel::Rf => "RF".to_string(),
-            MuteChannel::SpeakerOnly => "SpeakerOnly".to_string(),
-            MuteChannel::Unspecified(s) => s.to_string(),
+            MuteChannel::Master => f.write_str("Master"),
+            MuteChannel::Lf => f.write_str("LF"),
+            MuteChannel::Rf => f.write_str("RF"),
+            MuteChannel::SpeakerOnly => f.write_str("SpeakerOnly"),
+            MuteChannel::Unspecified(s) => f.write_str(s),
         }
     }
 }
@@ -7218,13 +9458,13 @@ pub enum RampType {
     Unspecified(String),
 }
 
-impl ToString for RampType {
-    fn to_string(&self) -> String {
+impl std::fmt::Display for RampType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
-            RampType::SleepTimerRampType => "SLEEP_TIMER_RAMP_TYPE".to_string(),
-            RampType::AlarmRampType => "ALARM_RAMP_TYPE".to_string(),
-            RampType::AutoplayRampType => "AUTOPLAY_RAMP_TYPE".to_string(),
-            RampType::Unspecified(s) => s.to_string(),
+            RampType::SleepTimerRampType => f.write_str("SLEEP_TIMER_RAMP_TYPE"),
+            RampType::AlarmRampType => f.write_str("ALARM_RAMP_TYPE"),
+            RampType::AutoplayRampType => f.write_str("AUTOPLAY_RAMP_TYPE"),
+            RampType::Unspecified(s) => f.write_str(s),
         }
     }
 }
@@ -7299,6 +9539,131 @@ pub mod system_properties {
     /// `urn:schemas-upnp-org:service:SystemProperties:1`
     pub const SERVICE_TYPE: &str = "urn:schemas-upnp-org:service:SystemProperties:1";
 
+    /// The device models that are known to support each action
+    /// of this service, sorted by action name.
+    /// The models are identified by their `modelNumber`.
+    pub const ACTION_MODELS: &[(&str, &[&str])] = &[
+        (
+            "AddAccountX",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "AddOAuthAccountX",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "DoPostUpdateTasks",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "EditAccountMd",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "EditAccountPasswordX",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "EnableRDM",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "GetRDM",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "GetString",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "GetWebCode",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "ProvisionCredentialedTrialAccountX",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "RefreshAccountCredentialsX",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "Remove",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "RemoveAccount",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "ReplaceAccountX",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "ResetThirdPartyCredentials",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "SetAccountNicknameX",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "SetString",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+    ];
+
     #[derive(ToXml, Debug, Clone, PartialEq, Default)]
     #[xml(rename = "AddAccountX", ns(SERVICE_TYPE))]
     pub struct AddAccountXRequest {
@@ -7319,7 +9684,14 @@ pub mod system_properties {
 
     impl crate::DecodeSoapResponse for AddAccountXResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode(
+                        "system_properties::AddAccountXResponse",
+                        error,
+                        xml,
+                    )
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -7345,6 +9717,32 @@ pub mod system_properties {
         pub account_tier: u32,
     }
 
+    impl AddOAuthAccountXRequest {
+        /// Constructs a request from its fields
+        #[allow(clippy::too_many_arguments)]
+        pub fn new(
+            account_type: u32,
+            account_token: String,
+            account_key: String,
+            o_auth_device_id: String,
+            authorization_code: String,
+            redirect_uri: String,
+            user_id_hash_code: String,
+            account_tier: u32,
+        ) -> Self {
+            Self {
+                account_type,
+                account_token,
+                account_key,
+                o_auth_device_id,
+                authorization_code,
+                redirect_uri,
+                user_id_hash_code,
+                account_tier,
+            }
+        }
+    }
+
     #[derive(FromXml, Debug, Clone, PartialEq)]
     #[xml(rename = "AddOAuthAccountXResponse", ns(SERVICE_TYPE))]
     pub struct AddOAuthAccountXResponse {
@@ -7356,7 +9754,14 @@ pub mod system_properties {
 
     impl crate::DecodeSoapResponse for AddOAuthAccountXResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode(
+                        "system_properties::AddOAuthAccountXResponse",
+                        error,
+                        xml,
+                    )
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -7399,7 +9804,10 @@ pub mod system_properties {
 
     impl crate::DecodeSoapResponse for GetRdmResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode("system_properties::GetRdmResponse", error, xml)
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -7421,7 +9829,14 @@ pub mod system_properties {
 
     impl crate::DecodeSoapResponse for GetStringResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode(
+                        "system_properties::GetStringResponse",
+                        error,
+                        xml,
+                    )
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -7442,7 +9857,14 @@ pub mod system_properties {
 
     impl crate::DecodeSoapResponse for GetWebCodeResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode(
+                        "system_properties::GetWebCodeResponse",
+                        error,
+                        xml,
+                    )
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -7472,7 +9894,14 @@ pub mod system_properties {
 
     impl crate::DecodeSoapResponse for ProvisionCredentialedTrialAccountXResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode(
+                        "system_properties::ProvisionCredentialedTrialAccountXResponse",
+                        error,
+                        xml,
+                    )
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -7490,6 +9919,23 @@ pub mod system_properties {
         pub account_key: String,
     }
 
+    impl RefreshAccountCredentialsXRequest {
+        /// Constructs a request from its fields
+        pub fn new(
+            account_type: u32,
+            account_uid: u32,
+            account_token: String,
+            account_key: String,
+        ) -> Self {
+            Self {
+                account_type,
+                account_uid,
+                account_token,
+                account_key,
+            }
+        }
+    }
+
     #[derive(ToXml, Debug, Clone, PartialEq, Default)]
     #[xml(rename = "Remove", ns(SERVICE_TYPE))]
     pub struct RemoveRequest {
@@ -7524,6 +9970,27 @@ pub mod system_properties {
         pub o_auth_device_id: String,
     }
 
+    impl ReplaceAccountXRequest {
+        /// Constructs a request from its fields
+        pub fn new(
+            account_udn: String,
+            new_account_id: String,
+            new_account_password: String,
+            account_token: String,
+            account_key: String,
+            o_auth_device_id: String,
+        ) -> Self {
+            Self {
+                account_udn,
+                new_account_id,
+                new_account_password,
+                account_token,
+                account_key,
+                o_auth_device_id,
+            }
+        }
+    }
+
     #[derive(FromXml, Debug, Clone, PartialEq)]
     #[xml(rename = "ReplaceAccountXResponse", ns(SERVICE_TYPE))]
     pub struct ReplaceAccountXResponse {
@@ -7533,7 +10000,14 @@ pub mod system_properties {
 
     impl crate::DecodeSoapResponse for ReplaceAccountXResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode(
+                        "system_properties::ReplaceAccountXResponse",
+                        error,
+                        xml,
+                    )
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -7624,7 +10098,7 @@ pub mod system_properties {
         pub async fn subscribe_system_properties(
             &self,
         ) -> crate::Result<crate::upnp::EventStream<SystemPropertiesEvent>> {
-            self.subscribe_helper(&SERVICE_TYPE).await
+            self.subscribe_helper(SERVICE_TYPE).await
         }
     }
 }
@@ -7637,6 +10111,68 @@ pub mod virtual_line_in {
     /// `urn:schemas-upnp-org:service:VirtualLineIn:1`
     pub const SERVICE_TYPE: &str = "urn:schemas-upnp-org:service:VirtualLineIn:1";
 
+    /// The device models that are known to support each action
+    /// of this service, sorted by action name.
+    /// The models are identified by their `modelNumber`.
+    pub const ACTION_MODELS: &[(&str, &[&str])] = &[
+        (
+            "Next",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "Pause",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "Play",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "Previous",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "SetVolume",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "StartTransmission",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "Stop",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "StopTransmission",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+    ];
+
     #[derive(ToXml, Debug, Clone, PartialEq, Default)]
     #[xml(rename = "Next", ns(SERVICE_TYPE))]
     pub struct NextRequest {
@@ -7694,7 +10230,14 @@ pub mod virtual_line_in {
 
     impl crate::DecodeSoapResponse for StartTransmissionResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode(
+                        "virtual_line_in::StartTransmissionResponse",
+                        error,
+                        xml,
+                    )
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -7761,7 +10304,7 @@ pub mod virtual_line_in {
         pub async fn subscribe_virtual_line_in(
             &self,
         ) -> crate::Result<crate::upnp::EventStream<VirtualLineInEvent>> {
-            self.subscribe_helper(&SERVICE_TYPE).await
+            self.subscribe_helper(SERVICE_TYPE).await
         }
     }
 }
@@ -7774,6 +10317,68 @@ pub mod zone_group_topology {
     /// `urn:schemas-upnp-org:service:ZoneGroupTopology:1`
     pub const SERVICE_TYPE: &str = "urn:schemas-upnp-org:service:ZoneGroupTopology:1";
 
+    /// The device models that are known to support each action
+    /// of this service, sorted by action name.
+    /// The models are identified by their `modelNumber`.
+    pub const ACTION_MODELS: &[(&str, &[&str])] = &[
+        (
+            "BeginSoftwareUpdate",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "CheckForUpdate",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "GetZoneGroupAttributes",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "GetZoneGroupState",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "RegisterMobileDevice",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "ReportAlarmStartedRunning",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "ReportUnresponsiveDevice",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+        (
+            "SubmitDiagnostics",
+            &[
+                "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6",
+                "S9", "Sub",
+            ],
+        ),
+    ];
+
     #[derive(ToXml, Debug, Clone, PartialEq, Default)]
     #[xml(rename = "BeginSoftwareUpdate", ns(SERVICE_TYPE))]
     pub struct BeginSoftwareUpdateRequest {
@@ -7805,7 +10410,14 @@ pub mod zone_group_topology {
 
     impl crate::DecodeSoapResponse for CheckForUpdateResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode(
+                        "zone_group_topology::CheckForUpdateResponse",
+                        error,
+                        xml,
+                    )
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -7825,7 +10437,14 @@ pub mod zone_group_topology {
 
     impl crate::DecodeSoapResponse for GetZoneGroupAttributesResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode(
+                        "zone_group_topology::GetZoneGroupAttributesResponse",
+                        error,
+                        xml,
+                    )
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -7839,7 +10458,14 @@ pub mod zone_group_topology {
 
     impl crate::DecodeSoapResponse for GetZoneGroupStateResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode(
+                        "zone_group_topology::GetZoneGroupStateResponse",
+                        error,
+                        xml,
+                    )
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -7882,7 +10508,14 @@ pub mod zone_group_topology {
 
     impl crate::DecodeSoapResponse for SubmitDiagnosticsResponse {
         fn decode_soap_xml(xml: &str) -> crate::Result<Self> {
-            let envelope: crate::soap_resp::Envelope<Self> = instant_xml::from_str(xml)?;
+            let envelope: crate::soap_resp::Envelope<Self> =
+                instant_xml::from_str(xml).map_err(|error| {
+                    crate::Error::response_decode(
+                        "zone_group_topology::SubmitDiagnosticsResponse",
+                        error,
+                        xml,
+                    )
+                })?;
             Ok(envelope.body.payload)
         }
     }
@@ -7996,7 +10629,7 @@ pub mod zone_group_topology {
         pub async fn subscribe_zone_group_topology(
             &self,
         ) -> crate::Result<crate::upnp::EventStream<ZoneGroupTopologyEvent>> {
-            self.subscribe_helper(&SERVICE_TYPE).await
+            self.subscribe_helper(SERVICE_TYPE).await
         }
     }
 }
@@ -8014,17 +10647,17 @@ pub enum UnresponsiveDeviceActionType {
     Unspecified(String),
 }
 
-impl ToString for UnresponsiveDeviceActionType {
-    fn to_string(&self) -> String {
+impl std::fmt::Display for UnresponsiveDeviceActionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
-            UnresponsiveDeviceActionType::Remove => "Remove".to_string(),
+            UnresponsiveDeviceActionType::Remove => f.write_str("Remove"),
             UnresponsiveDeviceActionType::TopologyMonitorProbe => {
-                "TopologyMonitorProbe".to_string()
+                f.write_str("TopologyMonitorProbe")
             }
             UnresponsiveDeviceActionType::VerifyThenRemoveSystemwide => {
-                "VerifyThenRemoveSystemwide".to_string()
+                f.write_str("VerifyThenRemoveSystemwide")
             }
-            UnresponsiveDeviceActionType::Unspecified(s) => s.to_string(),
+            UnresponsiveDeviceActionType::Unspecified(s) => f.write_str(s),
         }
     }
 }
@@ -8105,12 +10738,12 @@ pub enum UpdateType {
     Unspecified(String),
 }
 
-impl ToString for UpdateType {
-    fn to_string(&self) -> String {
+impl std::fmt::Display for UpdateType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
-            UpdateType::All => "All".to_string(),
-            UpdateType::Software => "Software".to_string(),
-            UpdateType::Unspecified(s) => s.to_string(),
+            UpdateType::All => f.write_str("All"),
+            UpdateType::Software => f.write_str("Software"),
+            UpdateType::Unspecified(s) => f.write_str(s),
         }
     }
 }
@@ -8910,7 +11543,7 @@ impl AVTransport for SonosDevice {
         request: av_transport::AddMultipleUrisToQueueRequest,
     ) -> Result<av_transport::AddMultipleUrisToQueueResponse> {
         self.action(
-            &av_transport::SERVICE_TYPE,
+            av_transport::SERVICE_TYPE,
             "AddMultipleURIsToQueue",
             request,
         )
@@ -8921,7 +11554,7 @@ impl AVTransport for SonosDevice {
         &self,
         request: av_transport::AddUriToQueueRequest,
     ) -> Result<av_transport::AddUriToQueueResponse> {
-        self.action(&av_transport::SERVICE_TYPE, "AddURIToQueue", request)
+        self.action(av_transport::SERVICE_TYPE, "AddURIToQueue", request)
             .await
     }
 
@@ -8929,12 +11562,12 @@ impl AVTransport for SonosDevice {
         &self,
         request: av_transport::AddUriToSavedQueueRequest,
     ) -> Result<av_transport::AddUriToSavedQueueResponse> {
-        self.action(&av_transport::SERVICE_TYPE, "AddURIToSavedQueue", request)
+        self.action(av_transport::SERVICE_TYPE, "AddURIToSavedQueue", request)
             .await
     }
 
     async fn backup_queue(&self, request: av_transport::BackupQueueRequest) -> Result<()> {
-        self.action(&av_transport::SERVICE_TYPE, "BackupQueue", request)
+        self.action(av_transport::SERVICE_TYPE, "BackupQueue", request)
             .await
     }
 
@@ -8943,7 +11576,7 @@ impl AVTransport for SonosDevice {
         request: av_transport::BecomeCoordinatorOfStandaloneGroupRequest,
     ) -> Result<av_transport::BecomeCoordinatorOfStandaloneGroupResponse> {
         self.action(
-            &av_transport::SERVICE_TYPE,
+            av_transport::SERVICE_TYPE,
             "BecomeCoordinatorOfStandaloneGroup",
             request,
         )
@@ -8955,7 +11588,7 @@ impl AVTransport for SonosDevice {
         request: av_transport::BecomeGroupCoordinatorRequest,
     ) -> Result<()> {
         self.action(
-            &av_transport::SERVICE_TYPE,
+            av_transport::SERVICE_TYPE,
             "BecomeGroupCoordinator",
             request,
         )
@@ -8967,7 +11600,7 @@ impl AVTransport for SonosDevice {
         request: av_transport::BecomeGroupCoordinatorAndSourceRequest,
     ) -> Result<()> {
         self.action(
-            &av_transport::SERVICE_TYPE,
+            av_transport::SERVICE_TYPE,
             "BecomeGroupCoordinatorAndSource",
             request,
         )
@@ -8978,7 +11611,7 @@ impl AVTransport for SonosDevice {
         &self,
         request: av_transport::ChangeCoordinatorRequest,
     ) -> Result<()> {
-        self.action(&av_transport::SERVICE_TYPE, "ChangeCoordinator", request)
+        self.action(av_transport::SERVICE_TYPE, "ChangeCoordinator", request)
             .await
     }
 
@@ -8987,7 +11620,7 @@ impl AVTransport for SonosDevice {
         request: av_transport::ChangeTransportSettingsRequest,
     ) -> Result<()> {
         self.action(
-            &av_transport::SERVICE_TYPE,
+            av_transport::SERVICE_TYPE,
             "ChangeTransportSettings",
             request,
         )
@@ -8998,7 +11631,7 @@ impl AVTransport for SonosDevice {
         &self,
         request: av_transport::ConfigureSleepTimerRequest,
     ) -> Result<()> {
-        self.action(&av_transport::SERVICE_TYPE, "ConfigureSleepTimer", request)
+        self.action(av_transport::SERVICE_TYPE, "ConfigureSleepTimer", request)
             .await
     }
 
@@ -9006,7 +11639,7 @@ impl AVTransport for SonosDevice {
         &self,
         request: av_transport::CreateSavedQueueRequest,
     ) -> Result<av_transport::CreateSavedQueueResponse> {
-        self.action(&av_transport::SERVICE_TYPE, "CreateSavedQueue", request)
+        self.action(av_transport::SERVICE_TYPE, "CreateSavedQueue", request)
             .await
     }
 
@@ -9015,7 +11648,7 @@ impl AVTransport for SonosDevice {
         request: av_transport::DelegateGroupCoordinationToRequest,
     ) -> Result<()> {
         self.action(
-            &av_transport::SERVICE_TYPE,
+            av_transport::SERVICE_TYPE,
             "DelegateGroupCoordinationTo",
             request,
         )
@@ -9027,7 +11660,7 @@ impl AVTransport for SonosDevice {
         request: av_transport::EndDirectControlSessionRequest,
     ) -> Result<()> {
         self.action(
-            &av_transport::SERVICE_TYPE,
+            av_transport::SERVICE_TYPE,
             "EndDirectControlSession",
             request,
         )
@@ -9038,7 +11671,7 @@ impl AVTransport for SonosDevice {
         &self,
         request: av_transport::GetCrossfadeModeRequest,
     ) -> Result<av_transport::GetCrossfadeModeResponse> {
-        self.action(&av_transport::SERVICE_TYPE, "GetCrossfadeMode", request)
+        self.action(av_transport::SERVICE_TYPE, "GetCrossfadeMode", request)
             .await
     }
 
@@ -9047,7 +11680,7 @@ impl AVTransport for SonosDevice {
         request: av_transport::GetCurrentTransportActionsRequest,
     ) -> Result<av_transport::GetCurrentTransportActionsResponse> {
         self.action(
-            &av_transport::SERVICE_TYPE,
+            av_transport::SERVICE_TYPE,
             "GetCurrentTransportActions",
             request,
         )
@@ -9058,19 +11691,15 @@ impl AVTransport for SonosDevice {
         &self,
         request: av_transport::GetDeviceCapabilitiesRequest,
     ) -> Result<av_transport::GetDeviceCapabilitiesResponse> {
-        self.action(
-            &av_transport::SERVICE_TYPE,
-            "GetDeviceCapabilities",
-            request,
-        )
-        .await
+        self.action(av_transport::SERVICE_TYPE, "GetDeviceCapabilities", request)
+            .await
     }
 
     async fn get_media_info(
         &self,
         request: av_transport::GetMediaInfoRequest,
     ) -> Result<av_transport::GetMediaInfoResponse> {
-        self.action(&av_transport::SERVICE_TYPE, "GetMediaInfo", request)
+        self.action(av_transport::SERVICE_TYPE, "GetMediaInfo", request)
             .await
     }
 
@@ -9078,7 +11707,7 @@ impl AVTransport for SonosDevice {
         &self,
         request: av_transport::GetPositionInfoRequest,
     ) -> Result<av_transport::GetPositionInfoResponse> {
-        self.action(&av_transport::SERVICE_TYPE, "GetPositionInfo", request)
+        self.action(av_transport::SERVICE_TYPE, "GetPositionInfo", request)
             .await
     }
 
@@ -9087,7 +11716,7 @@ impl AVTransport for SonosDevice {
         request: av_transport::GetRemainingSleepTimerDurationRequest,
     ) -> Result<av_transport::GetRemainingSleepTimerDurationResponse> {
         self.action(
-            &av_transport::SERVICE_TYPE,
+            av_transport::SERVICE_TYPE,
             "GetRemainingSleepTimerDuration",
             request,
         )
@@ -9099,7 +11728,7 @@ impl AVTransport for SonosDevice {
         request: av_transport::GetRunningAlarmPropertiesRequest,
     ) -> Result<av_transport::GetRunningAlarmPropertiesResponse> {
         self.action(
-            &av_transport::SERVICE_TYPE,
+            av_transport::SERVICE_TYPE,
             "GetRunningAlarmProperties",
             request,
         )
@@ -9110,7 +11739,7 @@ impl AVTransport for SonosDevice {
         &self,
         request: av_transport::GetTransportInfoRequest,
     ) -> Result<av_transport::GetTransportInfoResponse> {
-        self.action(&av_transport::SERVICE_TYPE, "GetTransportInfo", request)
+        self.action(av_transport::SERVICE_TYPE, "GetTransportInfo", request)
             .await
     }
 
@@ -9118,12 +11747,12 @@ impl AVTransport for SonosDevice {
         &self,
         request: av_transport::GetTransportSettingsRequest,
     ) -> Result<av_transport::GetTransportSettingsResponse> {
-        self.action(&av_transport::SERVICE_TYPE, "GetTransportSettings", request)
+        self.action(av_transport::SERVICE_TYPE, "GetTransportSettings", request)
             .await
     }
 
     async fn next(&self, request: av_transport::NextRequest) -> Result<()> {
-        self.action(&av_transport::SERVICE_TYPE, "Next", request)
+        self.action(av_transport::SERVICE_TYPE, "Next", request)
             .await
     }
 
@@ -9131,22 +11760,22 @@ impl AVTransport for SonosDevice {
         &self,
         request: av_transport::NotifyDeletedUriRequest,
     ) -> Result<()> {
-        self.action(&av_transport::SERVICE_TYPE, "NotifyDeletedURI", request)
+        self.action(av_transport::SERVICE_TYPE, "NotifyDeletedURI", request)
             .await
     }
 
     async fn pause(&self, request: av_transport::PauseRequest) -> Result<()> {
-        self.action(&av_transport::SERVICE_TYPE, "Pause", request)
+        self.action(av_transport::SERVICE_TYPE, "Pause", request)
             .await
     }
 
     async fn play(&self, request: av_transport::PlayRequest) -> Result<()> {
-        self.action(&av_transport::SERVICE_TYPE, "Play", request)
+        self.action(av_transport::SERVICE_TYPE, "Play", request)
             .await
     }
 
     async fn previous(&self, request: av_transport::PreviousRequest) -> Result<()> {
-        self.action(&av_transport::SERVICE_TYPE, "Previous", request)
+        self.action(av_transport::SERVICE_TYPE, "Previous", request)
             .await
     }
 
@@ -9155,7 +11784,7 @@ impl AVTransport for SonosDevice {
         request: av_transport::RemoveAllTracksFromQueueRequest,
     ) -> Result<()> {
         self.action(
-            &av_transport::SERVICE_TYPE,
+            av_transport::SERVICE_TYPE,
             "RemoveAllTracksFromQueue",
             request,
         )
@@ -9166,7 +11795,7 @@ impl AVTransport for SonosDevice {
         &self,
         request: av_transport::RemoveTrackFromQueueRequest,
     ) -> Result<()> {
-        self.action(&av_transport::SERVICE_TYPE, "RemoveTrackFromQueue", request)
+        self.action(av_transport::SERVICE_TYPE, "RemoveTrackFromQueue", request)
             .await
     }
 
@@ -9175,7 +11804,7 @@ impl AVTransport for SonosDevice {
         request: av_transport::RemoveTrackRangeFromQueueRequest,
     ) -> Result<av_transport::RemoveTrackRangeFromQueueResponse> {
         self.action(
-            &av_transport::SERVICE_TYPE,
+            av_transport::SERVICE_TYPE,
             "RemoveTrackRangeFromQueue",
             request,
         )
@@ -9186,7 +11815,7 @@ impl AVTransport for SonosDevice {
         &self,
         request: av_transport::ReorderTracksInQueueRequest,
     ) -> Result<()> {
-        self.action(&av_transport::SERVICE_TYPE, "ReorderTracksInQueue", request)
+        self.action(av_transport::SERVICE_TYPE, "ReorderTracksInQueue", request)
             .await
     }
 
@@ -9195,7 +11824,7 @@ impl AVTransport for SonosDevice {
         request: av_transport::ReorderTracksInSavedQueueRequest,
     ) -> Result<av_transport::ReorderTracksInSavedQueueResponse> {
         self.action(
-            &av_transport::SERVICE_TYPE,
+            av_transport::SERVICE_TYPE,
             "ReorderTracksInSavedQueue",
             request,
         )
@@ -9203,7 +11832,7 @@ impl AVTransport for SonosDevice {
     }
 
     async fn run_alarm(&self, request: av_transport::RunAlarmRequest) -> Result<()> {
-        self.action(&av_transport::SERVICE_TYPE, "RunAlarm", request)
+        self.action(av_transport::SERVICE_TYPE, "RunAlarm", request)
             .await
     }
 
@@ -9211,12 +11840,12 @@ impl AVTransport for SonosDevice {
         &self,
         request: av_transport::SaveQueueRequest,
     ) -> Result<av_transport::SaveQueueResponse> {
-        self.action(&av_transport::SERVICE_TYPE, "SaveQueue", request)
+        self.action(av_transport::SERVICE_TYPE, "SaveQueue", request)
             .await
     }
 
     async fn seek(&self, request: av_transport::SeekRequest) -> Result<()> {
-        self.action(&av_transport::SERVICE_TYPE, "Seek", request)
+        self.action(av_transport::SERVICE_TYPE, "Seek", request)
             .await
     }
 
@@ -9224,7 +11853,7 @@ impl AVTransport for SonosDevice {
         &self,
         request: av_transport::SetAvTransportUriRequest,
     ) -> Result<()> {
-        self.action(&av_transport::SERVICE_TYPE, "SetAVTransportURI", request)
+        self.action(av_transport::SERVICE_TYPE, "SetAVTransportURI", request)
             .await
     }
 
@@ -9232,7 +11861,7 @@ impl AVTransport for SonosDevice {
         &self,
         request: av_transport::SetCrossfadeModeRequest,
     ) -> Result<()> {
-        self.action(&av_transport::SERVICE_TYPE, "SetCrossfadeMode", request)
+        self.action(av_transport::SERVICE_TYPE, "SetCrossfadeMode", request)
             .await
     }
 
@@ -9240,31 +11869,27 @@ impl AVTransport for SonosDevice {
         &self,
         request: av_transport::SetNextAvTransportUriRequest,
     ) -> Result<()> {
-        self.action(
-            &av_transport::SERVICE_TYPE,
-            "SetNextAVTransportURI",
-            request,
-        )
-        .await
+        self.action(av_transport::SERVICE_TYPE, "SetNextAVTransportURI", request)
+            .await
     }
 
     async fn set_play_mode(&self, request: av_transport::SetPlayModeRequest) -> Result<()> {
-        self.action(&av_transport::SERVICE_TYPE, "SetPlayMode", request)
+        self.action(av_transport::SERVICE_TYPE, "SetPlayMode", request)
             .await
     }
 
     async fn snooze_alarm(&self, request: av_transport::SnoozeAlarmRequest) -> Result<()> {
-        self.action(&av_transport::SERVICE_TYPE, "SnoozeAlarm", request)
+        self.action(av_transport::SERVICE_TYPE, "SnoozeAlarm", request)
             .await
     }
 
     async fn start_autoplay(&self, request: av_transport::StartAutoplayRequest) -> Result<()> {
-        self.action(&av_transport::SERVICE_TYPE, "StartAutoplay", request)
+        self.action(av_transport::SERVICE_TYPE, "StartAutoplay", request)
             .await
     }
 
     async fn stop(&self, request: av_transport::StopRequest) -> Result<()> {
-        self.action(&av_transport::SERVICE_TYPE, "Stop", request)
+        self.action(av_transport::SERVICE_TYPE, "Stop", request)
             .await
     }
 }
@@ -9274,12 +11899,12 @@ impl AlarmClock for SonosDevice {
         &self,
         request: alarm_clock::CreateAlarmRequest,
     ) -> Result<alarm_clock::CreateAlarmResponse> {
-        self.action(&alarm_clock::SERVICE_TYPE, "CreateAlarm", request)
+        self.action(alarm_clock::SERVICE_TYPE, "CreateAlarm", request)
             .await
     }
 
     async fn destroy_alarm(&self, request: alarm_clock::DestroyAlarmRequest) -> Result<()> {
-        self.action(&alarm_clock::SERVICE_TYPE, "DestroyAlarm", request)
+        self.action(alarm_clock::SERVICE_TYPE, "DestroyAlarm", request)
             .await
     }
 
@@ -9287,7 +11912,7 @@ impl AlarmClock for SonosDevice {
         &self,
     ) -> Result<alarm_clock::GetDailyIndexRefreshTimeResponse> {
         self.action(
-            &alarm_clock::SERVICE_TYPE,
+            alarm_clock::SERVICE_TYPE,
             "GetDailyIndexRefreshTime",
             crate::soap::Unit {},
         )
@@ -9295,12 +11920,8 @@ impl AlarmClock for SonosDevice {
     }
 
     async fn get_format(&self) -> Result<alarm_clock::GetFormatResponse> {
-        self.action(
-            &alarm_clock::SERVICE_TYPE,
-            "GetFormat",
-            crate::soap::Unit {},
-        )
-        .await
+        self.action(alarm_clock::SERVICE_TYPE, "GetFormat", crate::soap::Unit {})
+            .await
     }
 
     async fn get_household_time_at_stamp(
@@ -9308,7 +11929,7 @@ impl AlarmClock for SonosDevice {
         request: alarm_clock::GetHouseholdTimeAtStampRequest,
     ) -> Result<alarm_clock::GetHouseholdTimeAtStampResponse> {
         self.action(
-            &alarm_clock::SERVICE_TYPE,
+            alarm_clock::SERVICE_TYPE,
             "GetHouseholdTimeAtStamp",
             request,
         )
@@ -9317,7 +11938,7 @@ impl AlarmClock for SonosDevice {
 
     async fn get_time_now(&self) -> Result<alarm_clock::GetTimeNowResponse> {
         self.action(
-            &alarm_clock::SERVICE_TYPE,
+            alarm_clock::SERVICE_TYPE,
             "GetTimeNow",
             crate::soap::Unit {},
         )
@@ -9326,7 +11947,7 @@ impl AlarmClock for SonosDevice {
 
     async fn get_time_server(&self) -> Result<alarm_clock::GetTimeServerResponse> {
         self.action(
-            &alarm_clock::SERVICE_TYPE,
+            alarm_clock::SERVICE_TYPE,
             "GetTimeServer",
             crate::soap::Unit {},
         )
@@ -9335,7 +11956,7 @@ impl AlarmClock for SonosDevice {
 
     async fn get_time_zone(&self) -> Result<alarm_clock::GetTimeZoneResponse> {
         self.action(
-            &alarm_clock::SERVICE_TYPE,
+            alarm_clock::SERVICE_TYPE,
             "GetTimeZone",
             crate::soap::Unit {},
         )
@@ -9344,7 +11965,7 @@ impl AlarmClock for SonosDevice {
 
     async fn get_time_zone_and_rule(&self) -> Result<alarm_clock::GetTimeZoneAndRuleResponse> {
         self.action(
-            &alarm_clock::SERVICE_TYPE,
+            alarm_clock::SERVICE_TYPE,
             "GetTimeZoneAndRule",
             crate::soap::Unit {},
         )
@@ -9355,13 +11976,13 @@ impl AlarmClock for SonosDevice {
         &self,
         request: alarm_clock::GetTimeZoneRuleRequest,
     ) -> Result<alarm_clock::GetTimeZoneRuleResponse> {
-        self.action(&alarm_clock::SERVICE_TYPE, "GetTimeZoneRule", request)
+        self.action(alarm_clock::SERVICE_TYPE, "GetTimeZoneRule", request)
             .await
     }
 
     async fn list_alarms(&self) -> Result<alarm_clock::ListAlarmsResponse> {
         self.action(
-            &alarm_clock::SERVICE_TYPE,
+            alarm_clock::SERVICE_TYPE,
             "ListAlarms",
             crate::soap::Unit {},
         )
@@ -9373,7 +11994,7 @@ impl AlarmClock for SonosDevice {
         request: alarm_clock::SetDailyIndexRefreshTimeRequest,
     ) -> Result<()> {
         self.action(
-            &alarm_clock::SERVICE_TYPE,
+            alarm_clock::SERVICE_TYPE,
             "SetDailyIndexRefreshTime",
             request,
         )
@@ -9381,27 +12002,27 @@ impl AlarmClock for SonosDevice {
     }
 
     async fn set_format(&self, request: alarm_clock::SetFormatRequest) -> Result<()> {
-        self.action(&alarm_clock::SERVICE_TYPE, "SetFormat", request)
+        self.action(alarm_clock::SERVICE_TYPE, "SetFormat", request)
             .await
     }
 
     async fn set_time_now(&self, request: alarm_clock::SetTimeNowRequest) -> Result<()> {
-        self.action(&alarm_clock::SERVICE_TYPE, "SetTimeNow", request)
+        self.action(alarm_clock::SERVICE_TYPE, "SetTimeNow", request)
             .await
     }
 
     async fn set_time_server(&self, request: alarm_clock::SetTimeServerRequest) -> Result<()> {
-        self.action(&alarm_clock::SERVICE_TYPE, "SetTimeServer", request)
+        self.action(alarm_clock::SERVICE_TYPE, "SetTimeServer", request)
             .await
     }
 
     async fn set_time_zone(&self, request: alarm_clock::SetTimeZoneRequest) -> Result<()> {
-        self.action(&alarm_clock::SERVICE_TYPE, "SetTimeZone", request)
+        self.action(alarm_clock::SERVICE_TYPE, "SetTimeZone", request)
             .await
     }
 
     async fn update_alarm(&self, request: alarm_clock::UpdateAlarmRequest) -> Result<()> {
-        self.action(&alarm_clock::SERVICE_TYPE, "UpdateAlarm", request)
+        self.action(alarm_clock::SERVICE_TYPE, "UpdateAlarm", request)
             .await
     }
 }
@@ -9411,7 +12032,7 @@ impl AudioIn for SonosDevice {
         &self,
     ) -> Result<audio_in::GetAudioInputAttributesResponse> {
         self.action(
-            &audio_in::SERVICE_TYPE,
+            audio_in::SERVICE_TYPE,
             "GetAudioInputAttributes",
             crate::soap::Unit {},
         )
@@ -9420,7 +12041,7 @@ impl AudioIn for SonosDevice {
 
     async fn get_line_in_level(&self) -> Result<audio_in::GetLineInLevelResponse> {
         self.action(
-            &audio_in::SERVICE_TYPE,
+            audio_in::SERVICE_TYPE,
             "GetLineInLevel",
             crate::soap::Unit {},
         )
@@ -9428,7 +12049,7 @@ impl AudioIn for SonosDevice {
     }
 
     async fn select_audio(&self, request: audio_in::SelectAudioRequest) -> Result<()> {
-        self.action(&audio_in::SERVICE_TYPE, "SelectAudio", request)
+        self.action(audio_in::SERVICE_TYPE, "SelectAudio", request)
             .await
     }
 
@@ -9436,12 +12057,12 @@ impl AudioIn for SonosDevice {
         &self,
         request: audio_in::SetAudioInputAttributesRequest,
     ) -> Result<()> {
-        self.action(&audio_in::SERVICE_TYPE, "SetAudioInputAttributes", request)
+        self.action(audio_in::SERVICE_TYPE, "SetAudioInputAttributes", request)
             .await
     }
 
     async fn set_line_in_level(&self, request: audio_in::SetLineInLevelRequest) -> Result<()> {
-        self.action(&audio_in::SERVICE_TYPE, "SetLineInLevel", request)
+        self.action(audio_in::SERVICE_TYPE, "SetLineInLevel", request)
             .await
     }
 
@@ -9449,7 +12070,7 @@ impl AudioIn for SonosDevice {
         &self,
         request: audio_in::StartTransmissionToGroupRequest,
     ) -> Result<audio_in::StartTransmissionToGroupResponse> {
-        self.action(&audio_in::SERVICE_TYPE, "StartTransmissionToGroup", request)
+        self.action(audio_in::SERVICE_TYPE, "StartTransmissionToGroup", request)
             .await
     }
 
@@ -9457,7 +12078,7 @@ impl AudioIn for SonosDevice {
         &self,
         request: audio_in::StopTransmissionToGroupRequest,
     ) -> Result<()> {
-        self.action(&audio_in::SERVICE_TYPE, "StopTransmissionToGroup", request)
+        self.action(audio_in::SERVICE_TYPE, "StopTransmissionToGroup", request)
             .await
     }
 }
@@ -9467,7 +12088,7 @@ impl ConnectionManager for SonosDevice {
         &self,
     ) -> Result<connection_manager::GetCurrentConnectionIdsResponse> {
         self.action(
-            &connection_manager::SERVICE_TYPE,
+            connection_manager::SERVICE_TYPE,
             "GetCurrentConnectionIDs",
             crate::soap::Unit {},
         )
@@ -9479,7 +12100,7 @@ impl ConnectionManager for SonosDevice {
         request: connection_manager::GetCurrentConnectionInfoRequest,
     ) -> Result<connection_manager::GetCurrentConnectionInfoResponse> {
         self.action(
-            &connection_manager::SERVICE_TYPE,
+            connection_manager::SERVICE_TYPE,
             "GetCurrentConnectionInfo",
             request,
         )
@@ -9488,7 +12109,7 @@ impl ConnectionManager for SonosDevice {
 
     async fn get_protocol_info(&self) -> Result<connection_manager::GetProtocolInfoResponse> {
         self.action(
-            &connection_manager::SERVICE_TYPE,
+            connection_manager::SERVICE_TYPE,
             "GetProtocolInfo",
             crate::soap::Unit {},
         )
@@ -9501,7 +12122,7 @@ impl ContentDirectory for SonosDevice {
         &self,
         request: content_directory::BrowseRequest,
     ) -> Result<content_directory::BrowseResponse> {
-        self.action(&content_directory::SERVICE_TYPE, "Browse", request)
+        self.action(content_directory::SERVICE_TYPE, "Browse", request)
             .await
     }
 
@@ -9509,12 +12130,12 @@ impl ContentDirectory for SonosDevice {
         &self,
         request: content_directory::CreateObjectRequest,
     ) -> Result<content_directory::CreateObjectResponse> {
-        self.action(&content_directory::SERVICE_TYPE, "CreateObject", request)
+        self.action(content_directory::SERVICE_TYPE, "CreateObject", request)
             .await
     }
 
     async fn destroy_object(&self, request: content_directory::DestroyObjectRequest) -> Result<()> {
-        self.action(&content_directory::SERVICE_TYPE, "DestroyObject", request)
+        self.action(content_directory::SERVICE_TYPE, "DestroyObject", request)
             .await
     }
 
@@ -9522,7 +12143,7 @@ impl ContentDirectory for SonosDevice {
         &self,
         request: content_directory::FindPrefixRequest,
     ) -> Result<content_directory::FindPrefixResponse> {
-        self.action(&content_directory::SERVICE_TYPE, "FindPrefix", request)
+        self.action(content_directory::SERVICE_TYPE, "FindPrefix", request)
             .await
     }
 
@@ -9530,7 +12151,7 @@ impl ContentDirectory for SonosDevice {
         &self,
     ) -> Result<content_directory::GetAlbumArtistDisplayOptionResponse> {
         self.action(
-            &content_directory::SERVICE_TYPE,
+            content_directory::SERVICE_TYPE,
             "GetAlbumArtistDisplayOption",
             crate::soap::Unit {},
         )
@@ -9542,7 +12163,7 @@ impl ContentDirectory for SonosDevice {
         request: content_directory::GetAllPrefixLocationsRequest,
     ) -> Result<content_directory::GetAllPrefixLocationsResponse> {
         self.action(
-            &content_directory::SERVICE_TYPE,
+            content_directory::SERVICE_TYPE,
             "GetAllPrefixLocations",
             request,
         )
@@ -9551,7 +12172,7 @@ impl ContentDirectory for SonosDevice {
 
     async fn get_browseable(&self) -> Result<content_directory::GetBrowseableResponse> {
         self.action(
-            &content_directory::SERVICE_TYPE,
+            content_directory::SERVICE_TYPE,
             "GetBrowseable",
             crate::soap::Unit {},
         )
@@ -9560,7 +12181,7 @@ impl ContentDirectory for SonosDevice {
 
     async fn get_last_index_change(&self) -> Result<content_directory::GetLastIndexChangeResponse> {
         self.action(
-            &content_directory::SERVICE_TYPE,
+            content_directory::SERVICE_TYPE,
             "GetLastIndexChange",
             crate::soap::Unit {},
         )
@@ -9571,7 +12192,7 @@ impl ContentDirectory for SonosDevice {
         &self,
     ) -> Result<content_directory::GetSearchCapabilitiesResponse> {
         self.action(
-            &content_directory::SERVICE_TYPE,
+            content_directory::SERVICE_TYPE,
             "GetSearchCapabilities",
             crate::soap::Unit {},
         )
@@ -9582,7 +12203,7 @@ impl ContentDirectory for SonosDevice {
         &self,
     ) -> Result<content_directory::GetShareIndexInProgressResponse> {
         self.action(
-            &content_directory::SERVICE_TYPE,
+            content_directory::SERVICE_TYPE,
             "GetShareIndexInProgress",
             crate::soap::Unit {},
         )
@@ -9593,7 +12214,7 @@ impl ContentDirectory for SonosDevice {
         &self,
     ) -> Result<content_directory::GetSortCapabilitiesResponse> {
         self.action(
-            &content_directory::SERVICE_TYPE,
+            content_directory::SERVICE_TYPE,
             "GetSortCapabilities",
             crate::soap::Unit {},
         )
@@ -9602,7 +12223,7 @@ impl ContentDirectory for SonosDevice {
 
     async fn get_system_update_id(&self) -> Result<content_directory::GetSystemUpdateIdResponse> {
         self.action(
-            &content_directory::SERVICE_TYPE,
+            content_directory::SERVICE_TYPE,
             "GetSystemUpdateID",
             crate::soap::Unit {},
         )
@@ -9614,7 +12235,7 @@ impl ContentDirectory for SonosDevice {
         request: content_directory::RefreshShareIndexRequest,
     ) -> Result<()> {
         self.action(
-            &content_directory::SERVICE_TYPE,
+            content_directory::SERVICE_TYPE,
             "RefreshShareIndex",
             request,
         )
@@ -9622,17 +12243,17 @@ impl ContentDirectory for SonosDevice {
     }
 
     async fn request_resort(&self, request: content_directory::RequestResortRequest) -> Result<()> {
-        self.action(&content_directory::SERVICE_TYPE, "RequestResort", request)
+        self.action(content_directory::SERVICE_TYPE, "RequestResort", request)
             .await
     }
 
     async fn set_browseable(&self, request: content_directory::SetBrowseableRequest) -> Result<()> {
-        self.action(&content_directory::SERVICE_TYPE, "SetBrowseable", request)
+        self.action(content_directory::SERVICE_TYPE, "SetBrowseable", request)
             .await
     }
 
     async fn update_object(&self, request: content_directory::UpdateObjectRequest) -> Result<()> {
-        self.action(&content_directory::SERVICE_TYPE, "UpdateObject", request)
+        self.action(content_directory::SERVICE_TYPE, "UpdateObject", request)
             .await
     }
 }
@@ -9642,7 +12263,7 @@ impl DeviceProperties for SonosDevice {
         &self,
         request: device_properties::AddBondedZonesRequest,
     ) -> Result<()> {
-        self.action(&device_properties::SERVICE_TYPE, "AddBondedZones", request)
+        self.action(device_properties::SERVICE_TYPE, "AddBondedZones", request)
             .await
     }
 
@@ -9650,7 +12271,7 @@ impl DeviceProperties for SonosDevice {
         &self,
         request: device_properties::AddHtSatelliteRequest,
     ) -> Result<()> {
-        self.action(&device_properties::SERVICE_TYPE, "AddHTSatellite", request)
+        self.action(device_properties::SERVICE_TYPE, "AddHTSatellite", request)
             .await
     }
 
@@ -9658,19 +12279,15 @@ impl DeviceProperties for SonosDevice {
         &self,
         request: device_properties::CreateStereoPairRequest,
     ) -> Result<()> {
-        self.action(
-            &device_properties::SERVICE_TYPE,
-            "CreateStereoPair",
-            request,
-        )
-        .await
+        self.action(device_properties::SERVICE_TYPE, "CreateStereoPair", request)
+            .await
     }
 
     async fn enter_config_mode(
         &self,
         request: device_properties::EnterConfigModeRequest,
     ) -> Result<device_properties::EnterConfigModeResponse> {
-        self.action(&device_properties::SERVICE_TYPE, "EnterConfigMode", request)
+        self.action(device_properties::SERVICE_TYPE, "EnterConfigMode", request)
             .await
     }
 
@@ -9678,7 +12295,7 @@ impl DeviceProperties for SonosDevice {
         &self,
         request: device_properties::ExitConfigModeRequest,
     ) -> Result<()> {
-        self.action(&device_properties::SERVICE_TYPE, "ExitConfigMode", request)
+        self.action(device_properties::SERVICE_TYPE, "ExitConfigMode", request)
             .await
     }
 
@@ -9687,7 +12304,7 @@ impl DeviceProperties for SonosDevice {
         request: device_properties::GetAutoplayLinkedZonesRequest,
     ) -> Result<device_properties::GetAutoplayLinkedZonesResponse> {
         self.action(
-            &device_properties::SERVICE_TYPE,
+            device_properties::SERVICE_TYPE,
             "GetAutoplayLinkedZones",
             request,
         )
@@ -9699,7 +12316,7 @@ impl DeviceProperties for SonosDevice {
         request: device_properties::GetAutoplayRoomUuidRequest,
     ) -> Result<device_properties::GetAutoplayRoomUuidResponse> {
         self.action(
-            &device_properties::SERVICE_TYPE,
+            device_properties::SERVICE_TYPE,
             "GetAutoplayRoomUUID",
             request,
         )
@@ -9711,7 +12328,7 @@ impl DeviceProperties for SonosDevice {
         request: device_properties::GetAutoplayVolumeRequest,
     ) -> Result<device_properties::GetAutoplayVolumeResponse> {
         self.action(
-            &device_properties::SERVICE_TYPE,
+            device_properties::SERVICE_TYPE,
             "GetAutoplayVolume",
             request,
         )
@@ -9720,7 +12337,7 @@ impl DeviceProperties for SonosDevice {
 
     async fn get_button_lock_state(&self) -> Result<device_properties::GetButtonLockStateResponse> {
         self.action(
-            &device_properties::SERVICE_TYPE,
+            device_properties::SERVICE_TYPE,
             "GetButtonLockState",
             crate::soap::Unit {},
         )
@@ -9729,7 +12346,7 @@ impl DeviceProperties for SonosDevice {
 
     async fn get_button_state(&self) -> Result<device_properties::GetButtonStateResponse> {
         self.action(
-            &device_properties::SERVICE_TYPE,
+            device_properties::SERVICE_TYPE,
             "GetButtonState",
             crate::soap::Unit {},
         )
@@ -9738,7 +12355,7 @@ impl DeviceProperties for SonosDevice {
 
     async fn get_ht_forward_state(&self) -> Result<device_properties::GetHtForwardStateResponse> {
         self.action(
-            &device_properties::SERVICE_TYPE,
+            device_properties::SERVICE_TYPE,
             "GetHTForwardState",
             crate::soap::Unit {},
         )
@@ -9747,7 +12364,7 @@ impl DeviceProperties for SonosDevice {
 
     async fn get_household_id(&self) -> Result<device_properties::GetHouseholdIdResponse> {
         self.action(
-            &device_properties::SERVICE_TYPE,
+            device_properties::SERVICE_TYPE,
             "GetHouseholdID",
             crate::soap::Unit {},
         )
@@ -9756,7 +12373,7 @@ impl DeviceProperties for SonosDevice {
 
     async fn get_led_state(&self) -> Result<device_properties::GetLedStateResponse> {
         self.action(
-            &device_properties::SERVICE_TYPE,
+            device_properties::SERVICE_TYPE,
             "GetLEDState",
             crate::soap::Unit {},
         )
@@ -9768,7 +12385,7 @@ impl DeviceProperties for SonosDevice {
         request: device_properties::GetUseAutoplayVolumeRequest,
     ) -> Result<device_properties::GetUseAutoplayVolumeResponse> {
         self.action(
-            &device_properties::SERVICE_TYPE,
+            device_properties::SERVICE_TYPE,
             "GetUseAutoplayVolume",
             request,
         )
@@ -9777,7 +12394,7 @@ impl DeviceProperties for SonosDevice {
 
     async fn get_zone_attributes(&self) -> Result<device_properties::GetZoneAttributesResponse> {
         self.action(
-            &device_properties::SERVICE_TYPE,
+            device_properties::SERVICE_TYPE,
             "GetZoneAttributes",
             crate::soap::Unit {},
         )
@@ -9786,7 +12403,7 @@ impl DeviceProperties for SonosDevice {
 
     async fn get_zone_info(&self) -> Result<device_properties::GetZoneInfoResponse> {
         self.action(
-            &device_properties::SERVICE_TYPE,
+            device_properties::SERVICE_TYPE,
             "GetZoneInfo",
             crate::soap::Unit {},
         )
@@ -9798,7 +12415,7 @@ impl DeviceProperties for SonosDevice {
         request: device_properties::RemoveBondedZonesRequest,
     ) -> Result<()> {
         self.action(
-            &device_properties::SERVICE_TYPE,
+            device_properties::SERVICE_TYPE,
             "RemoveBondedZones",
             request,
         )
@@ -9810,7 +12427,7 @@ impl DeviceProperties for SonosDevice {
         request: device_properties::RemoveHtSatelliteRequest,
     ) -> Result<()> {
         self.action(
-            &device_properties::SERVICE_TYPE,
+            device_properties::SERVICE_TYPE,
             "RemoveHTSatellite",
             request,
         )
@@ -9822,7 +12439,7 @@ impl DeviceProperties for SonosDevice {
         request: device_properties::RoomDetectionStartChirpingRequest,
     ) -> Result<device_properties::RoomDetectionStartChirpingResponse> {
         self.action(
-            &device_properties::SERVICE_TYPE,
+            device_properties::SERVICE_TYPE,
             "RoomDetectionStartChirping",
             request,
         )
@@ -9834,7 +12451,7 @@ impl DeviceProperties for SonosDevice {
         request: device_properties::RoomDetectionStopChirpingRequest,
     ) -> Result<()> {
         self.action(
-            &device_properties::SERVICE_TYPE,
+            device_properties::SERVICE_TYPE,
             "RoomDetectionStopChirping",
             request,
         )
@@ -9846,7 +12463,7 @@ impl DeviceProperties for SonosDevice {
         request: device_properties::SeparateStereoPairRequest,
     ) -> Result<()> {
         self.action(
-            &device_properties::SERVICE_TYPE,
+            device_properties::SERVICE_TYPE,
             "SeparateStereoPair",
             request,
         )
@@ -9858,7 +12475,7 @@ impl DeviceProperties for SonosDevice {
         request: device_properties::SetAutoplayLinkedZonesRequest,
     ) -> Result<()> {
         self.action(
-            &device_properties::SERVICE_TYPE,
+            device_properties::SERVICE_TYPE,
             "SetAutoplayLinkedZones",
             request,
         )
@@ -9870,7 +12487,7 @@ impl DeviceProperties for SonosDevice {
         request: device_properties::SetAutoplayRoomUuidRequest,
     ) -> Result<()> {
         self.action(
-            &device_properties::SERVICE_TYPE,
+            device_properties::SERVICE_TYPE,
             "SetAutoplayRoomUUID",
             request,
         )
@@ -9882,7 +12499,7 @@ impl DeviceProperties for SonosDevice {
         request: device_properties::SetAutoplayVolumeRequest,
     ) -> Result<()> {
         self.action(
-            &device_properties::SERVICE_TYPE,
+            device_properties::SERVICE_TYPE,
             "SetAutoplayVolume",
             request,
         )
@@ -9894,7 +12511,7 @@ impl DeviceProperties for SonosDevice {
         request: device_properties::SetButtonLockStateRequest,
     ) -> Result<()> {
         self.action(
-            &device_properties::SERVICE_TYPE,
+            device_properties::SERVICE_TYPE,
             "SetButtonLockState",
             request,
         )
@@ -9902,7 +12519,7 @@ impl DeviceProperties for SonosDevice {
     }
 
     async fn set_led_state(&self, request: device_properties::SetLedStateRequest) -> Result<()> {
-        self.action(&device_properties::SERVICE_TYPE, "SetLEDState", request)
+        self.action(device_properties::SERVICE_TYPE, "SetLEDState", request)
             .await
     }
 
@@ -9911,7 +12528,7 @@ impl DeviceProperties for SonosDevice {
         request: device_properties::SetUseAutoplayVolumeRequest,
     ) -> Result<()> {
         self.action(
-            &device_properties::SERVICE_TYPE,
+            device_properties::SERVICE_TYPE,
             "SetUseAutoplayVolume",
             request,
         )
@@ -9923,7 +12540,7 @@ impl DeviceProperties for SonosDevice {
         request: device_properties::SetZoneAttributesRequest,
     ) -> Result<()> {
         self.action(
-            &device_properties::SERVICE_TYPE,
+            device_properties::SERVICE_TYPE,
             "SetZoneAttributes",
             request,
         )
@@ -9936,12 +12553,12 @@ impl GroupManagement for SonosDevice {
         &self,
         request: group_management::AddMemberRequest,
     ) -> Result<group_management::AddMemberResponse> {
-        self.action(&group_management::SERVICE_TYPE, "AddMember", request)
+        self.action(group_management::SERVICE_TYPE, "AddMember", request)
             .await
     }
 
     async fn remove_member(&self, request: group_management::RemoveMemberRequest) -> Result<()> {
-        self.action(&group_management::SERVICE_TYPE, "RemoveMember", request)
+        self.action(group_management::SERVICE_TYPE, "RemoveMember", request)
             .await
     }
 
@@ -9950,7 +12567,7 @@ impl GroupManagement for SonosDevice {
         request: group_management::ReportTrackBufferingResultRequest,
     ) -> Result<()> {
         self.action(
-            &group_management::SERVICE_TYPE,
+            group_management::SERVICE_TYPE,
             "ReportTrackBufferingResult",
             request,
         )
@@ -9961,7 +12578,7 @@ impl GroupManagement for SonosDevice {
         &self,
         request: group_management::SetSourceAreaIdsRequest,
     ) -> Result<()> {
-        self.action(&group_management::SERVICE_TYPE, "SetSourceAreaIds", request)
+        self.action(group_management::SERVICE_TYPE, "SetSourceAreaIds", request)
             .await
     }
 }
@@ -9972,7 +12589,7 @@ impl GroupRenderingControl for SonosDevice {
         request: group_rendering_control::GetGroupMuteRequest,
     ) -> Result<group_rendering_control::GetGroupMuteResponse> {
         self.action(
-            &group_rendering_control::SERVICE_TYPE,
+            group_rendering_control::SERVICE_TYPE,
             "GetGroupMute",
             request,
         )
@@ -9984,7 +12601,7 @@ impl GroupRenderingControl for SonosDevice {
         request: group_rendering_control::GetGroupVolumeRequest,
     ) -> Result<group_rendering_control::GetGroupVolumeResponse> {
         self.action(
-            &group_rendering_control::SERVICE_TYPE,
+            group_rendering_control::SERVICE_TYPE,
             "GetGroupVolume",
             request,
         )
@@ -9996,7 +12613,7 @@ impl GroupRenderingControl for SonosDevice {
         request: group_rendering_control::SetGroupMuteRequest,
     ) -> Result<()> {
         self.action(
-            &group_rendering_control::SERVICE_TYPE,
+            group_rendering_control::SERVICE_TYPE,
             "SetGroupMute",
             request,
         )
@@ -10008,7 +12625,7 @@ impl GroupRenderingControl for SonosDevice {
         request: group_rendering_control::SetGroupVolumeRequest,
     ) -> Result<()> {
         self.action(
-            &group_rendering_control::SERVICE_TYPE,
+            group_rendering_control::SERVICE_TYPE,
             "SetGroupVolume",
             request,
         )
@@ -10020,7 +12637,7 @@ impl GroupRenderingControl for SonosDevice {
         request: group_rendering_control::SetRelativeGroupVolumeRequest,
     ) -> Result<group_rendering_control::SetRelativeGroupVolumeResponse> {
         self.action(
-            &group_rendering_control::SERVICE_TYPE,
+            group_rendering_control::SERVICE_TYPE,
             "SetRelativeGroupVolume",
             request,
         )
@@ -10032,7 +12649,7 @@ impl GroupRenderingControl for SonosDevice {
         request: group_rendering_control::SnapshotGroupVolumeRequest,
     ) -> Result<()> {
         self.action(
-            &group_rendering_control::SERVICE_TYPE,
+            group_rendering_control::SERVICE_TYPE,
             "SnapshotGroupVolume",
             request,
         )
@@ -10045,13 +12662,13 @@ impl HTControl for SonosDevice {
         &self,
         request: ht_control::CommitLearnedIrCodesRequest,
     ) -> Result<()> {
-        self.action(&ht_control::SERVICE_TYPE, "CommitLearnedIRCodes", request)
+        self.action(ht_control::SERVICE_TYPE, "CommitLearnedIRCodes", request)
             .await
     }
 
     async fn get_ir_repeater_state(&self) -> Result<ht_control::GetIrRepeaterStateResponse> {
         self.action(
-            &ht_control::SERVICE_TYPE,
+            ht_control::SERVICE_TYPE,
             "GetIRRepeaterState",
             crate::soap::Unit {},
         )
@@ -10060,7 +12677,7 @@ impl HTControl for SonosDevice {
 
     async fn get_led_feedback_state(&self) -> Result<ht_control::GetLedFeedbackStateResponse> {
         self.action(
-            &ht_control::SERVICE_TYPE,
+            ht_control::SERVICE_TYPE,
             "GetLEDFeedbackState",
             crate::soap::Unit {},
         )
@@ -10068,13 +12685,13 @@ impl HTControl for SonosDevice {
     }
 
     async fn identify_ir_remote(&self, request: ht_control::IdentifyIrRemoteRequest) -> Result<()> {
-        self.action(&ht_control::SERVICE_TYPE, "IdentifyIRRemote", request)
+        self.action(ht_control::SERVICE_TYPE, "IdentifyIRRemote", request)
             .await
     }
 
     async fn is_remote_configured(&self) -> Result<ht_control::IsRemoteConfiguredResponse> {
         self.action(
-            &ht_control::SERVICE_TYPE,
+            ht_control::SERVICE_TYPE,
             "IsRemoteConfigured",
             crate::soap::Unit {},
         )
@@ -10082,7 +12699,7 @@ impl HTControl for SonosDevice {
     }
 
     async fn learn_ir_code(&self, request: ht_control::LearnIrCodeRequest) -> Result<()> {
-        self.action(&ht_control::SERVICE_TYPE, "LearnIRCode", request)
+        self.action(ht_control::SERVICE_TYPE, "LearnIRCode", request)
             .await
     }
 
@@ -10090,7 +12707,7 @@ impl HTControl for SonosDevice {
         &self,
         request: ht_control::SetIrRepeaterStateRequest,
     ) -> Result<()> {
-        self.action(&ht_control::SERVICE_TYPE, "SetIRRepeaterState", request)
+        self.action(ht_control::SERVICE_TYPE, "SetIRRepeaterState", request)
             .await
     }
 
@@ -10098,7 +12715,7 @@ impl HTControl for SonosDevice {
         &self,
         request: ht_control::SetLedFeedbackStateRequest,
     ) -> Result<()> {
-        self.action(&ht_control::SERVICE_TYPE, "SetLEDFeedbackState", request)
+        self.action(ht_control::SERVICE_TYPE, "SetLEDFeedbackState", request)
             .await
     }
 }
@@ -10108,7 +12725,7 @@ impl MusicServices for SonosDevice {
         &self,
         request: music_services::GetSessionIdRequest,
     ) -> Result<music_services::GetSessionIdResponse> {
-        self.action(&music_services::SERVICE_TYPE, "GetSessionId", request)
+        self.action(music_services::SERVICE_TYPE, "GetSessionId", request)
             .await
     }
 
@@ -10116,7 +12733,7 @@ impl MusicServices for SonosDevice {
         &self,
     ) -> Result<music_services::ListAvailableServicesResponse> {
         self.action(
-            &music_services::SERVICE_TYPE,
+            music_services::SERVICE_TYPE,
             "ListAvailableServices",
             crate::soap::Unit {},
         )
@@ -10125,7 +12742,7 @@ impl MusicServices for SonosDevice {
 
     async fn update_available_services(&self) -> Result<()> {
         self.action(
-            &music_services::SERVICE_TYPE,
+            music_services::SERVICE_TYPE,
             "UpdateAvailableServices",
             crate::soap::Unit {},
         )
@@ -10138,7 +12755,7 @@ impl QPlay for SonosDevice {
         &self,
         request: q_play::QPlayAuthRequest,
     ) -> Result<q_play::QPlayAuthResponse> {
-        self.action(&q_play::SERVICE_TYPE, "QPlayAuth", request)
+        self.action(q_play::SERVICE_TYPE, "QPlayAuth", request)
             .await
     }
 }
@@ -10148,36 +12765,36 @@ impl Queue for SonosDevice {
         &self,
         request: queue::AddMultipleUrisRequest,
     ) -> Result<queue::AddMultipleUrisResponse> {
-        self.action(&queue::SERVICE_TYPE, "AddMultipleURIs", request)
+        self.action(queue::SERVICE_TYPE, "AddMultipleURIs", request)
             .await
     }
 
     async fn add_uri(&self, request: queue::AddUriRequest) -> Result<queue::AddUriResponse> {
-        self.action(&queue::SERVICE_TYPE, "AddURI", request).await
+        self.action(queue::SERVICE_TYPE, "AddURI", request).await
     }
 
     async fn attach_queue(
         &self,
         request: queue::AttachQueueRequest,
     ) -> Result<queue::AttachQueueResponse> {
-        self.action(&queue::SERVICE_TYPE, "AttachQueue", request)
+        self.action(queue::SERVICE_TYPE, "AttachQueue", request)
             .await
     }
 
     async fn backup(&self) -> Result<()> {
-        self.action(&queue::SERVICE_TYPE, "Backup", crate::soap::Unit {})
+        self.action(queue::SERVICE_TYPE, "Backup", crate::soap::Unit {})
             .await
     }
 
     async fn browse(&self, request: queue::BrowseRequest) -> Result<queue::BrowseResponse> {
-        self.action(&queue::SERVICE_TYPE, "Browse", request).await
+        self.action(queue::SERVICE_TYPE, "Browse", request).await
     }
 
     async fn create_queue(
         &self,
         request: queue::CreateQueueRequest,
     ) -> Result<queue::CreateQueueResponse> {
-        self.action(&queue::SERVICE_TYPE, "CreateQueue", request)
+        self.action(queue::SERVICE_TYPE, "CreateQueue", request)
             .await
     }
 
@@ -10185,7 +12802,7 @@ impl Queue for SonosDevice {
         &self,
         request: queue::RemoveAllTracksRequest,
     ) -> Result<queue::RemoveAllTracksResponse> {
-        self.action(&queue::SERVICE_TYPE, "RemoveAllTracks", request)
+        self.action(queue::SERVICE_TYPE, "RemoveAllTracks", request)
             .await
     }
 
@@ -10193,7 +12810,7 @@ impl Queue for SonosDevice {
         &self,
         request: queue::RemoveTrackRangeRequest,
     ) -> Result<queue::RemoveTrackRangeResponse> {
-        self.action(&queue::SERVICE_TYPE, "RemoveTrackRange", request)
+        self.action(queue::SERVICE_TYPE, "RemoveTrackRange", request)
             .await
     }
 
@@ -10201,7 +12818,7 @@ impl Queue for SonosDevice {
         &self,
         request: queue::ReorderTracksRequest,
     ) -> Result<queue::ReorderTracksResponse> {
-        self.action(&queue::SERVICE_TYPE, "ReorderTracks", request)
+        self.action(queue::SERVICE_TYPE, "ReorderTracks", request)
             .await
     }
 
@@ -10209,7 +12826,7 @@ impl Queue for SonosDevice {
         &self,
         request: queue::ReplaceAllTracksRequest,
     ) -> Result<queue::ReplaceAllTracksResponse> {
-        self.action(&queue::SERVICE_TYPE, "ReplaceAllTracks", request)
+        self.action(queue::SERVICE_TYPE, "ReplaceAllTracks", request)
             .await
     }
 
@@ -10217,7 +12834,7 @@ impl Queue for SonosDevice {
         &self,
         request: queue::SaveAsSonosPlaylistRequest,
     ) -> Result<queue::SaveAsSonosPlaylistResponse> {
-        self.action(&queue::SERVICE_TYPE, "SaveAsSonosPlaylist", request)
+        self.action(queue::SERVICE_TYPE, "SaveAsSonosPlaylist", request)
             .await
     }
 }
@@ -10227,7 +12844,7 @@ impl RenderingControl for SonosDevice {
         &self,
         request: rendering_control::GetBassRequest,
     ) -> Result<rendering_control::GetBassResponse> {
-        self.action(&rendering_control::SERVICE_TYPE, "GetBass", request)
+        self.action(rendering_control::SERVICE_TYPE, "GetBass", request)
             .await
     }
 
@@ -10235,7 +12852,7 @@ impl RenderingControl for SonosDevice {
         &self,
         request: rendering_control::GetEqRequest,
     ) -> Result<rendering_control::GetEqResponse> {
-        self.action(&rendering_control::SERVICE_TYPE, "GetEQ", request)
+        self.action(rendering_control::SERVICE_TYPE, "GetEQ", request)
             .await
     }
 
@@ -10244,7 +12861,7 @@ impl RenderingControl for SonosDevice {
         request: rendering_control::GetHeadphoneConnectedRequest,
     ) -> Result<rendering_control::GetHeadphoneConnectedResponse> {
         self.action(
-            &rendering_control::SERVICE_TYPE,
+            rendering_control::SERVICE_TYPE,
             "GetHeadphoneConnected",
             request,
         )
@@ -10255,7 +12872,7 @@ impl RenderingControl for SonosDevice {
         &self,
         request: rendering_control::GetLoudnessRequest,
     ) -> Result<rendering_control::GetLoudnessResponse> {
-        self.action(&rendering_control::SERVICE_TYPE, "GetLoudness", request)
+        self.action(rendering_control::SERVICE_TYPE, "GetLoudness", request)
             .await
     }
 
@@ -10263,7 +12880,7 @@ impl RenderingControl for SonosDevice {
         &self,
         request: rendering_control::GetMuteRequest,
     ) -> Result<rendering_control::GetMuteResponse> {
-        self.action(&rendering_control::SERVICE_TYPE, "GetMute", request)
+        self.action(rendering_control::SERVICE_TYPE, "GetMute", request)
             .await
     }
 
@@ -10271,7 +12888,7 @@ impl RenderingControl for SonosDevice {
         &self,
         request: rendering_control::GetOutputFixedRequest,
     ) -> Result<rendering_control::GetOutputFixedResponse> {
-        self.action(&rendering_control::SERVICE_TYPE, "GetOutputFixed", request)
+        self.action(rendering_control::SERVICE_TYPE, "GetOutputFixed", request)
             .await
     }
 
@@ -10280,7 +12897,7 @@ impl RenderingControl for SonosDevice {
         request: rendering_control::GetRoomCalibrationStatusRequest,
     ) -> Result<rendering_control::GetRoomCalibrationStatusResponse> {
         self.action(
-            &rendering_control::SERVICE_TYPE,
+            rendering_control::SERVICE_TYPE,
             "GetRoomCalibrationStatus",
             request,
         )
@@ -10292,7 +12909,7 @@ impl RenderingControl for SonosDevice {
         request: rendering_control::GetSupportsOutputFixedRequest,
     ) -> Result<rendering_control::GetSupportsOutputFixedResponse> {
         self.action(
-            &rendering_control::SERVICE_TYPE,
+            rendering_control::SERVICE_TYPE,
             "GetSupportsOutputFixed",
             request,
         )
@@ -10303,7 +12920,7 @@ impl RenderingControl for SonosDevice {
         &self,
         request: rendering_control::GetTrebleRequest,
     ) -> Result<rendering_control::GetTrebleResponse> {
-        self.action(&rendering_control::SERVICE_TYPE, "GetTreble", request)
+        self.action(rendering_control::SERVICE_TYPE, "GetTreble", request)
             .await
     }
 
@@ -10311,7 +12928,7 @@ impl RenderingControl for SonosDevice {
         &self,
         request: rendering_control::GetVolumeRequest,
     ) -> Result<rendering_control::GetVolumeResponse> {
-        self.action(&rendering_control::SERVICE_TYPE, "GetVolume", request)
+        self.action(rendering_control::SERVICE_TYPE, "GetVolume", request)
             .await
     }
 
@@ -10319,7 +12936,7 @@ impl RenderingControl for SonosDevice {
         &self,
         request: rendering_control::GetVolumeDbRequest,
     ) -> Result<rendering_control::GetVolumeDbResponse> {
-        self.action(&rendering_control::SERVICE_TYPE, "GetVolumeDB", request)
+        self.action(rendering_control::SERVICE_TYPE, "GetVolumeDB", request)
             .await
     }
 
@@ -10327,19 +12944,15 @@ impl RenderingControl for SonosDevice {
         &self,
         request: rendering_control::GetVolumeDbRangeRequest,
     ) -> Result<rendering_control::GetVolumeDbRangeResponse> {
-        self.action(
-            &rendering_control::SERVICE_TYPE,
-            "GetVolumeDBRange",
-            request,
-        )
-        .await
+        self.action(rendering_control::SERVICE_TYPE, "GetVolumeDBRange", request)
+            .await
     }
 
     async fn ramp_to_volume(
         &self,
         request: rendering_control::RampToVolumeRequest,
     ) -> Result<rendering_control::RampToVolumeResponse> {
-        self.action(&rendering_control::SERVICE_TYPE, "RampToVolume", request)
+        self.action(rendering_control::SERVICE_TYPE, "RampToVolume", request)
             .await
     }
 
@@ -10347,12 +12960,12 @@ impl RenderingControl for SonosDevice {
         &self,
         request: rendering_control::ResetBasicEqRequest,
     ) -> Result<rendering_control::ResetBasicEqResponse> {
-        self.action(&rendering_control::SERVICE_TYPE, "ResetBasicEQ", request)
+        self.action(rendering_control::SERVICE_TYPE, "ResetBasicEQ", request)
             .await
     }
 
     async fn reset_ext_eq(&self, request: rendering_control::ResetExtEqRequest) -> Result<()> {
-        self.action(&rendering_control::SERVICE_TYPE, "ResetExtEQ", request)
+        self.action(rendering_control::SERVICE_TYPE, "ResetExtEQ", request)
             .await
     }
 
@@ -10361,7 +12974,7 @@ impl RenderingControl for SonosDevice {
         request: rendering_control::RestoreVolumePriorToRampRequest,
     ) -> Result<()> {
         self.action(
-            &rendering_control::SERVICE_TYPE,
+            rendering_control::SERVICE_TYPE,
             "RestoreVolumePriorToRamp",
             request,
         )
@@ -10369,7 +12982,7 @@ impl RenderingControl for SonosDevice {
     }
 
     async fn set_bass(&self, request: rendering_control::SetBassRequest) -> Result<()> {
-        self.action(&rendering_control::SERVICE_TYPE, "SetBass", request)
+        self.action(rendering_control::SERVICE_TYPE, "SetBass", request)
             .await
     }
 
@@ -10377,22 +12990,22 @@ impl RenderingControl for SonosDevice {
         &self,
         request: rendering_control::SetChannelMapRequest,
     ) -> Result<()> {
-        self.action(&rendering_control::SERVICE_TYPE, "SetChannelMap", request)
+        self.action(rendering_control::SERVICE_TYPE, "SetChannelMap", request)
             .await
     }
 
     async fn set_eq(&self, request: rendering_control::SetEqRequest) -> Result<()> {
-        self.action(&rendering_control::SERVICE_TYPE, "SetEQ", request)
+        self.action(rendering_control::SERVICE_TYPE, "SetEQ", request)
             .await
     }
 
     async fn set_loudness(&self, request: rendering_control::SetLoudnessRequest) -> Result<()> {
-        self.action(&rendering_control::SERVICE_TYPE, "SetLoudness", request)
+        self.action(rendering_control::SERVICE_TYPE, "SetLoudness", request)
             .await
     }
 
     async fn set_mute(&self, request: rendering_control::SetMuteRequest) -> Result<()> {
-        self.action(&rendering_control::SERVICE_TYPE, "SetMute", request)
+        self.action(rendering_control::SERVICE_TYPE, "SetMute", request)
             .await
     }
 
@@ -10400,7 +13013,7 @@ impl RenderingControl for SonosDevice {
         &self,
         request: rendering_control::SetOutputFixedRequest,
     ) -> Result<()> {
-        self.action(&rendering_control::SERVICE_TYPE, "SetOutputFixed", request)
+        self.action(rendering_control::SERVICE_TYPE, "SetOutputFixed", request)
             .await
     }
 
@@ -10409,7 +13022,7 @@ impl RenderingControl for SonosDevice {
         request: rendering_control::SetRelativeVolumeRequest,
     ) -> Result<rendering_control::SetRelativeVolumeResponse> {
         self.action(
-            &rendering_control::SERVICE_TYPE,
+            rendering_control::SERVICE_TYPE,
             "SetRelativeVolume",
             request,
         )
@@ -10421,7 +13034,7 @@ impl RenderingControl for SonosDevice {
         request: rendering_control::SetRoomCalibrationStatusRequest,
     ) -> Result<()> {
         self.action(
-            &rendering_control::SERVICE_TYPE,
+            rendering_control::SERVICE_TYPE,
             "SetRoomCalibrationStatus",
             request,
         )
@@ -10433,7 +13046,7 @@ impl RenderingControl for SonosDevice {
         request: rendering_control::SetRoomCalibrationXRequest,
     ) -> Result<()> {
         self.action(
-            &rendering_control::SERVICE_TYPE,
+            rendering_control::SERVICE_TYPE,
             "SetRoomCalibrationX",
             request,
         )
@@ -10441,17 +13054,17 @@ impl RenderingControl for SonosDevice {
     }
 
     async fn set_treble(&self, request: rendering_control::SetTrebleRequest) -> Result<()> {
-        self.action(&rendering_control::SERVICE_TYPE, "SetTreble", request)
+        self.action(rendering_control::SERVICE_TYPE, "SetTreble", request)
             .await
     }
 
     async fn set_volume(&self, request: rendering_control::SetVolumeRequest) -> Result<()> {
-        self.action(&rendering_control::SERVICE_TYPE, "SetVolume", request)
+        self.action(rendering_control::SERVICE_TYPE, "SetVolume", request)
             .await
     }
 
     async fn set_volume_db(&self, request: rendering_control::SetVolumeDbRequest) -> Result<()> {
-        self.action(&rendering_control::SERVICE_TYPE, "SetVolumeDB", request)
+        self.action(rendering_control::SERVICE_TYPE, "SetVolumeDB", request)
             .await
     }
 }
@@ -10461,7 +13074,7 @@ impl SystemProperties for SonosDevice {
         &self,
         request: system_properties::AddAccountXRequest,
     ) -> Result<system_properties::AddAccountXResponse> {
-        self.action(&system_properties::SERVICE_TYPE, "AddAccountX", request)
+        self.action(system_properties::SERVICE_TYPE, "AddAccountX", request)
             .await
     }
 
@@ -10469,17 +13082,13 @@ impl SystemProperties for SonosDevice {
         &self,
         request: system_properties::AddOAuthAccountXRequest,
     ) -> Result<system_properties::AddOAuthAccountXResponse> {
-        self.action(
-            &system_properties::SERVICE_TYPE,
-            "AddOAuthAccountX",
-            request,
-        )
-        .await
+        self.action(system_properties::SERVICE_TYPE, "AddOAuthAccountX", request)
+            .await
     }
 
     async fn do_post_update_tasks(&self) -> Result<()> {
         self.action(
-            &system_properties::SERVICE_TYPE,
+            system_properties::SERVICE_TYPE,
             "DoPostUpdateTasks",
             crate::soap::Unit {},
         )
@@ -10490,7 +13099,7 @@ impl SystemProperties for SonosDevice {
         &self,
         request: system_properties::EditAccountMdRequest,
     ) -> Result<()> {
-        self.action(&system_properties::SERVICE_TYPE, "EditAccountMd", request)
+        self.action(system_properties::SERVICE_TYPE, "EditAccountMd", request)
             .await
     }
 
@@ -10499,7 +13108,7 @@ impl SystemProperties for SonosDevice {
         request: system_properties::EditAccountPasswordXRequest,
     ) -> Result<()> {
         self.action(
-            &system_properties::SERVICE_TYPE,
+            system_properties::SERVICE_TYPE,
             "EditAccountPasswordX",
             request,
         )
@@ -10507,13 +13116,13 @@ impl SystemProperties for SonosDevice {
     }
 
     async fn enable_rdm(&self, request: system_properties::EnableRdmRequest) -> Result<()> {
-        self.action(&system_properties::SERVICE_TYPE, "EnableRDM", request)
+        self.action(system_properties::SERVICE_TYPE, "EnableRDM", request)
             .await
     }
 
     async fn get_rdm(&self) -> Result<system_properties::GetRdmResponse> {
         self.action(
-            &system_properties::SERVICE_TYPE,
+            system_properties::SERVICE_TYPE,
             "GetRDM",
             crate::soap::Unit {},
         )
@@ -10524,7 +13133,7 @@ impl SystemProperties for SonosDevice {
         &self,
         request: system_properties::GetStringRequest,
     ) -> Result<system_properties::GetStringResponse> {
-        self.action(&system_properties::SERVICE_TYPE, "GetString", request)
+        self.action(system_properties::SERVICE_TYPE, "GetString", request)
             .await
     }
 
@@ -10532,7 +13141,7 @@ impl SystemProperties for SonosDevice {
         &self,
         request: system_properties::GetWebCodeRequest,
     ) -> Result<system_properties::GetWebCodeResponse> {
-        self.action(&system_properties::SERVICE_TYPE, "GetWebCode", request)
+        self.action(system_properties::SERVICE_TYPE, "GetWebCode", request)
             .await
     }
 
@@ -10541,7 +13150,7 @@ impl SystemProperties for SonosDevice {
         request: system_properties::ProvisionCredentialedTrialAccountXRequest,
     ) -> Result<system_properties::ProvisionCredentialedTrialAccountXResponse> {
         self.action(
-            &system_properties::SERVICE_TYPE,
+            system_properties::SERVICE_TYPE,
             "ProvisionCredentialedTrialAccountX",
             request,
         )
@@ -10553,7 +13162,7 @@ impl SystemProperties for SonosDevice {
         request: system_properties::RefreshAccountCredentialsXRequest,
     ) -> Result<()> {
         self.action(
-            &system_properties::SERVICE_TYPE,
+            system_properties::SERVICE_TYPE,
             "RefreshAccountCredentialsX",
             request,
         )
@@ -10561,12 +13170,12 @@ impl SystemProperties for SonosDevice {
     }
 
     async fn remove(&self, request: system_properties::RemoveRequest) -> Result<()> {
-        self.action(&system_properties::SERVICE_TYPE, "Remove", request)
+        self.action(system_properties::SERVICE_TYPE, "Remove", request)
             .await
     }
 
     async fn remove_account(&self, request: system_properties::RemoveAccountRequest) -> Result<()> {
-        self.action(&system_properties::SERVICE_TYPE, "RemoveAccount", request)
+        self.action(system_properties::SERVICE_TYPE, "RemoveAccount", request)
             .await
     }
 
@@ -10574,13 +13183,13 @@ impl SystemProperties for SonosDevice {
         &self,
         request: system_properties::ReplaceAccountXRequest,
     ) -> Result<system_properties::ReplaceAccountXResponse> {
-        self.action(&system_properties::SERVICE_TYPE, "ReplaceAccountX", request)
+        self.action(system_properties::SERVICE_TYPE, "ReplaceAccountX", request)
             .await
     }
 
     async fn reset_third_party_credentials(&self) -> Result<()> {
         self.action(
-            &system_properties::SERVICE_TYPE,
+            system_properties::SERVICE_TYPE,
             "ResetThirdPartyCredentials",
             crate::soap::Unit {},
         )
@@ -10592,7 +13201,7 @@ impl SystemProperties for SonosDevice {
         request: system_properties::SetAccountNicknameXRequest,
     ) -> Result<()> {
         self.action(
-            &system_properties::SERVICE_TYPE,
+            system_properties::SERVICE_TYPE,
             "SetAccountNicknameX",
             request,
         )
@@ -10600,34 +13209,34 @@ impl SystemProperties for SonosDevice {
     }
 
     async fn set_string(&self, request: system_properties::SetStringRequest) -> Result<()> {
-        self.action(&system_properties::SERVICE_TYPE, "SetString", request)
+        self.action(system_properties::SERVICE_TYPE, "SetString", request)
             .await
     }
 }
 
 impl VirtualLineIn for SonosDevice {
     async fn next(&self, request: virtual_line_in::NextRequest) -> Result<()> {
-        self.action(&virtual_line_in::SERVICE_TYPE, "Next", request)
+        self.action(virtual_line_in::SERVICE_TYPE, "Next", request)
             .await
     }
 
     async fn pause(&self, request: virtual_line_in::PauseRequest) -> Result<()> {
-        self.action(&virtual_line_in::SERVICE_TYPE, "Pause", request)
+        self.action(virtual_line_in::SERVICE_TYPE, "Pause", request)
             .await
     }
 
     async fn play(&self, request: virtual_line_in::PlayRequest) -> Result<()> {
-        self.action(&virtual_line_in::SERVICE_TYPE, "Play", request)
+        self.action(virtual_line_in::SERVICE_TYPE, "Play", request)
             .await
     }
 
     async fn previous(&self, request: virtual_line_in::PreviousRequest) -> Result<()> {
-        self.action(&virtual_line_in::SERVICE_TYPE, "Previous", request)
+        self.action(virtual_line_in::SERVICE_TYPE, "Previous", request)
             .await
     }
 
     async fn set_volume(&self, request: virtual_line_in::SetVolumeRequest) -> Result<()> {
-        self.action(&virtual_line_in::SERVICE_TYPE, "SetVolume", request)
+        self.action(virtual_line_in::SERVICE_TYPE, "SetVolume", request)
             .await
     }
 
@@ -10635,12 +13244,12 @@ impl VirtualLineIn for SonosDevice {
         &self,
         request: virtual_line_in::StartTransmissionRequest,
     ) -> Result<virtual_line_in::StartTransmissionResponse> {
-        self.action(&virtual_line_in::SERVICE_TYPE, "StartTransmission", request)
+        self.action(virtual_line_in::SERVICE_TYPE, "StartTransmission", request)
             .await
     }
 
     async fn stop(&self, request: virtual_line_in::StopRequest) -> Result<()> {
-        self.action(&virtual_line_in::SERVICE_TYPE, "Stop", request)
+        self.action(virtual_line_in::SERVICE_TYPE, "Stop", request)
             .await
     }
 
@@ -10648,7 +13257,7 @@ impl VirtualLineIn for SonosDevice {
         &self,
         request: virtual_line_in::StopTransmissionRequest,
     ) -> Result<()> {
-        self.action(&virtual_line_in::SERVICE_TYPE, "StopTransmission", request)
+        self.action(virtual_line_in::SERVICE_TYPE, "StopTransmission", request)
             .await
     }
 }
@@ -10659,7 +13268,7 @@ impl ZoneGroupTopology for SonosDevice {
         request: zone_group_topology::BeginSoftwareUpdateRequest,
     ) -> Result<()> {
         self.action(
-            &zone_group_topology::SERVICE_TYPE,
+            zone_group_topology::SERVICE_TYPE,
             "BeginSoftwareUpdate",
             request,
         )
@@ -10670,19 +13279,15 @@ impl ZoneGroupTopology for SonosDevice {
         &self,
         request: zone_group_topology::CheckForUpdateRequest,
     ) -> Result<zone_group_topology::CheckForUpdateResponse> {
-        self.action(
-            &zone_group_topology::SERVICE_TYPE,
-            "CheckForUpdate",
-            request,
-        )
-        .await
+        self.action(zone_group_topology::SERVICE_TYPE, "CheckForUpdate", request)
+            .await
     }
 
     async fn get_zone_group_attributes(
         &self,
     ) -> Result<zone_group_topology::GetZoneGroupAttributesResponse> {
         self.action(
-            &zone_group_topology::SERVICE_TYPE,
+            zone_group_topology::SERVICE_TYPE,
             "GetZoneGroupAttributes",
             crate::soap::Unit {},
         )
@@ -10691,7 +13296,7 @@ impl ZoneGroupTopology for SonosDevice {
 
     async fn get_zone_group_state(&self) -> Result<zone_group_topology::GetZoneGroupStateResponse> {
         self.action(
-            &zone_group_topology::SERVICE_TYPE,
+            zone_group_topology::SERVICE_TYPE,
             "GetZoneGroupState",
             crate::soap::Unit {},
         )
@@ -10703,7 +13308,7 @@ impl ZoneGroupTopology for SonosDevice {
         request: zone_group_topology::RegisterMobileDeviceRequest,
     ) -> Result<()> {
         self.action(
-            &zone_group_topology::SERVICE_TYPE,
+            zone_group_topology::SERVICE_TYPE,
             "RegisterMobileDevice",
             request,
         )
@@ -10712,7 +13317,7 @@ impl ZoneGroupTopology for SonosDevice {
 
     async fn report_alarm_started_running(&self) -> Result<()> {
         self.action(
-            &zone_group_topology::SERVICE_TYPE,
+            zone_group_topology::SERVICE_TYPE,
             "ReportAlarmStartedRunning",
             crate::soap::Unit {},
         )
@@ -10724,7 +13329,7 @@ impl ZoneGroupTopology for SonosDevice {
         request: zone_group_topology::ReportUnresponsiveDeviceRequest,
     ) -> Result<()> {
         self.action(
-            &zone_group_topology::SERVICE_TYPE,
+            zone_group_topology::SERVICE_TYPE,
             "ReportUnresponsiveDevice",
             request,
         )
@@ -10736,7 +13341,7 @@ impl ZoneGroupTopology for SonosDevice {
         request: zone_group_topology::SubmitDiagnosticsRequest,
     ) -> Result<zone_group_topology::SubmitDiagnosticsResponse> {
         self.action(
-            &zone_group_topology::SERVICE_TYPE,
+            zone_group_topology::SERVICE_TYPE,
             "SubmitDiagnostics",
             request,
         )
@@ -10744,6 +13349,135 @@ impl ZoneGroupTopology for SonosDevice {
     }
 }
 
+/// The device models for which service descriptions were available
+/// when this crate was generated, identified by their `modelNumber`.
+pub const KNOWN_MODELS: &[&str] = &[
+    "S1", "S13", "S14", "S18", "S19", "S21", "S27", "S3", "S33", "S38", "S5", "S6", "S9", "Sub",
+];
+
+/// Returns the device models that are known to support `action` on
+/// the service with the specified service type, or None if either
+/// the service or the action is not known to this crate.
+/// Models that are not in `KNOWN_MODELS` may also support the action.
+pub fn action_supported_models(
+    service_type: &str,
+    action: &str,
+) -> Option<&'static [&'static str]> {
+    let table = match service_type {
+        av_transport::SERVICE_TYPE => av_transport::ACTION_MODELS,
+        alarm_clock::SERVICE_TYPE => alarm_clock::ACTION_MODELS,
+        audio_in::SERVICE_TYPE => audio_in::ACTION_MODELS,
+        connection_manager::SERVICE_TYPE => connection_manager::ACTION_MODELS,
+        content_directory::SERVICE_TYPE => content_directory::ACTION_MODELS,
+        device_properties::SERVICE_TYPE => device_properties::ACTION_MODELS,
+        group_management::SERVICE_TYPE => group_management::ACTION_MODELS,
+        group_rendering_control::SERVICE_TYPE => group_rendering_control::ACTION_MODELS,
+        ht_control::SERVICE_TYPE => ht_control::ACTION_MODELS,
+        music_services::SERVICE_TYPE => music_services::ACTION_MODELS,
+        q_play::SERVICE_TYPE => q_play::ACTION_MODELS,
+        queue::SERVICE_TYPE => queue::ACTION_MODELS,
+        rendering_control::SERVICE_TYPE => rendering_control::ACTION_MODELS,
+        system_properties::SERVICE_TYPE => system_properties::ACTION_MODELS,
+        virtual_line_in::SERVICE_TYPE => virtual_line_in::ACTION_MODELS,
+        zone_group_topology::SERVICE_TYPE => zone_group_topology::ACTION_MODELS,
+
+        _ => return None,
+    };
+    table
+        .binary_search_by_key(&action, |(name, _)| name)
+        .ok()
+        .map(|idx| table[idx].1)
+}
+
+/// The services that are known to this crate
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum KnownService {
+    AVTransport,
+    AlarmClock,
+    AudioIn,
+    ConnectionManager,
+    ContentDirectory,
+    DeviceProperties,
+    GroupManagement,
+    GroupRenderingControl,
+    HTControl,
+    MusicServices,
+    QPlay,
+    Queue,
+    RenderingControl,
+    SystemProperties,
+    VirtualLineIn,
+    ZoneGroupTopology,
+}
+
+impl KnownService {
+    pub const ALL: &[KnownService] = &[
+        Self::AVTransport,
+        Self::AlarmClock,
+        Self::AudioIn,
+        Self::ConnectionManager,
+        Self::ContentDirectory,
+        Self::DeviceProperties,
+        Self::GroupManagement,
+        Self::GroupRenderingControl,
+        Self::HTControl,
+        Self::MusicServices,
+        Self::QPlay,
+        Self::Queue,
+        Self::RenderingControl,
+        Self::SystemProperties,
+        Self::VirtualLineIn,
+        Self::ZoneGroupTopology,
+    ];
+
+    /// Returns the service corresponding to a service type URN,
+    /// such as `urn:schemas-upnp-org:service:AVTransport:1`
+    pub fn from_service_type(service_type: &str) -> Option<Self> {
+        match service_type {
+            av_transport::SERVICE_TYPE => Some(Self::AVTransport),
+            alarm_clock::SERVICE_TYPE => Some(Self::AlarmClock),
+            audio_in::SERVICE_TYPE => Some(Self::AudioIn),
+            connection_manager::SERVICE_TYPE => Some(Self::ConnectionManager),
+            content_directory::SERVICE_TYPE => Some(Self::ContentDirectory),
+            device_properties::SERVICE_TYPE => Some(Self::DeviceProperties),
+            group_management::SERVICE_TYPE => Some(Self::GroupManagement),
+            group_rendering_control::SERVICE_TYPE => Some(Self::GroupRenderingControl),
+            ht_control::SERVICE_TYPE => Some(Self::HTControl),
+            music_services::SERVICE_TYPE => Some(Self::MusicServices),
+            q_play::SERVICE_TYPE => Some(Self::QPlay),
+            queue::SERVICE_TYPE => Some(Self::Queue),
+            rendering_control::SERVICE_TYPE => Some(Self::RenderingControl),
+            system_properties::SERVICE_TYPE => Some(Self::SystemProperties),
+            virtual_line_in::SERVICE_TYPE => Some(Self::VirtualLineIn),
+            zone_group_topology::SERVICE_TYPE => Some(Self::ZoneGroupTopology),
+
+            _ => None,
+        }
+    }
+
+    /// Returns the service type URN for this service
+    pub fn service_type(self) -> &'static str {
+        match self {
+            Self::AVTransport => av_transport::SERVICE_TYPE,
+            Self::AlarmClock => alarm_clock::SERVICE_TYPE,
+            Self::AudioIn => audio_in::SERVICE_TYPE,
+            Self::ConnectionManager => connection_manager::SERVICE_TYPE,
+            Self::ContentDirectory => content_directory::SERVICE_TYPE,
+            Self::DeviceProperties => device_properties::SERVICE_TYPE,
+            Self::GroupManagement => group_management::SERVICE_TYPE,
+            Self::GroupRenderingControl => group_rendering_control::SERVICE_TYPE,
+            Self::HTControl => ht_control::SERVICE_TYPE,
+            Self::MusicServices => music_services::SERVICE_TYPE,
+            Self::QPlay => q_play::SERVICE_TYPE,
+            Self::Queue => queue::SERVICE_TYPE,
+            Self::RenderingControl => rendering_control::SERVICE_TYPE,
+            Self::SystemProperties => system_properties::SERVICE_TYPE,
+            Self::VirtualLineIn => virtual_line_in::SERVICE_TYPE,
+            Self::ZoneGroupTopology => zone_group_topology::SERVICE_TYPE,
+        }
+    }
+}
+
 /// The prelude makes it convenient to use the methods of `SonosDevice`.
 /// Intended usage is `use sonos::prelude::*;` and then you don't have
 /// to worry about importing the individual service traits.
@@ -1,24 +1,55 @@
 use instant_xml::{FromXmlOwned, ToXml};
 use reqwest::{StatusCode, Url};
-use std::net::Ipv4Addr;
+use std::collections::BTreeMap;
+use std::net::{IpAddr, Ipv4Addr};
+use std::sync::{Arc, Mutex, OnceLock};
 use thiserror::Error;
 
+mod browse;
+mod channel_map;
+mod clock;
 mod didl;
 mod discovery;
 mod generated;
+#[cfg(feature = "mdns")]
+mod mdns;
+mod scpd;
+mod transport;
+mod units;
 mod upnp;
+mod uri;
 mod xmlutil;
 mod zone;
 
+pub use browse::*;
+pub use channel_map::*;
+pub use clock::*;
 pub use didl::*;
 pub use discovery::*;
 pub use generated::*;
+#[cfg(feature = "mdns")]
+pub use mdns::*;
+pub use scpd::*;
+pub use transport::*;
+pub use units::*;
 pub use upnp::*;
-pub use xmlutil::DecodeXmlString;
+pub use uri::*;
+pub use xmlutil::{DecodeXmlString, NOT_IMPLEMENTED};
 pub use zone::*;
 
 pub type Result<T> = std::result::Result<T, Error>;
 
+/// The maximum time that `SonosDevice::name` will wait for a response
+pub const NAME_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(5);
+
+/// The maximum time that `SonosDevice::play_notification` will wait
+/// for a notification clip to finish playing
+pub const NOTIFICATION_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(60);
+
+/// The maximum number of bytes of a response body that are retained
+/// in `Error::ResponseDecode`
+pub const RESPONSE_DECODE_BODY_LIMIT: usize = 1024;
+
 #[derive(Debug, Error)]
 pub enum Error {
     #[error("XML Error: {0}")]
@@ -28,6 +59,20 @@ pub enum Error {
         error: instant_xml::Error,
         text: String,
     },
+    #[error("DIDL Error: {error:#} while parsing {text}")]
+    DidlParse {
+        error: instant_xml::Error,
+        text: String,
+    },
+    /// A SOAP response could not be decoded into `type_name`.
+    /// `body` holds at most `RESPONSE_DECODE_BODY_LIMIT` bytes of
+    /// the response; the complete response is logged at debug level.
+    #[error("Failed to decode {type_name}: {error:#} while parsing {body}")]
+    ResponseDecode {
+        type_name: &'static str,
+        error: instant_xml::Error,
+        body: String,
+    },
     #[error("Service {0:?} is not supported by this device")]
     UnsupportedService(String),
     #[error("Invalid URI: {0:#?}")]
@@ -46,12 +91,35 @@ pub enum Error {
     Io(#[from] std::io::Error),
     #[error("Invalid enum variant value")]
     InvalidEnumVariantValue,
-    #[error("Room {0} not found")]
-    RoomNotFound(String),
+    #[error(
+        "Room {room_name} not found. Found rooms: {}{}",
+        found.join(", "),
+        if errors.is_empty() { String::new() } else { format!(". Errors: {}", describe_failures(errors)) }
+    )]
+    RoomNotFound {
+        room_name: String,
+        /// The names of the rooms that were discovered
+        found: Vec<String>,
+        /// The devices whose names could not be determined, and why
+        errors: Vec<(String, Error)>,
+    },
+    #[error("No Sonos devices were found on the network")]
+    NoDevicesFound,
+    #[error("HTTPS requires the tls feature; use the http URL on port 1400 instead of {0}")]
+    HttpsNotSupported(Url),
     #[error("Cannot find IP from device URL! {0:?}")]
     NoIpInDeviceUrl(Url),
+    #[error(
+        "Cannot subscribe to events because {0} could not be resolved to an IP address; \
+         construct the device using its IP address instead"
+    )]
+    SubscriptionRequiresIpAddress(String),
     #[error("Subscription failed because SID header is missing")]
     SubscriptionFailedNoSid,
+    #[error("Device {0} is not part of a stereo pair")]
+    NotStereoPair(String),
+    #[error("Failed to renew subscription {sid}: {error:#}")]
+    SubscriptionRenewalFailed { sid: String, error: Box<Error> },
     #[error("TrackMetaData list is empty!?")]
     EmptyTrackMetaData,
     #[error("TrackMetaData has multiple items but expect a single item")]
@@ -60,9 +128,59 @@ pub enum Error {
     LastChangeFormatUnexpected(String),
     #[error("Device reports None for volume")]
     VolumeNone,
+    #[error("Device response is missing the {0} field")]
+    MissingResponseField(&'static str),
+    #[error("Action {action} of service {service} is not supported by this device")]
+    UnsupportedAction { service: String, action: String },
+    #[error("{} member(s) failed to join the group: {}", .0.len(), describe_failures(.0))]
+    GroupRoomsFailed(Vec<(String, Error)>),
+    #[error("The device is a member of the group coordinated by {coordinator}; use the coordinator instead")]
+    NotGroupCoordinator { coordinator: String },
+    #[error("Invalid date/time {0:?}; expected YYYY-MM-DD HH:MM:SS")]
+    InvalidDateTime(String),
+    #[error(
+        "Invalid channel map {0:?}; expected UUID:CHANNEL[,CHANNEL...] entries separated by ';'"
+    )]
+    InvalidChannelMap(String),
+    #[error("Device {0} is not listed in the zone group topology")]
+    DeviceNotInTopology(String),
+    #[error("Invalid IP address {0:?}")]
+    InvalidIpAddress(String),
+    #[error("{name} value {text:?} is not a number")]
+    InvalidNumber { name: &'static str, text: String },
+    #[error("{name} value {value} is outside the valid range {min}..={max}")]
+    ValueOutOfRange {
+        name: &'static str,
+        value: i64,
+        min: i64,
+        max: i64,
+    },
+}
+
+fn describe_failures(failures: &[(String, Error)]) -> String {
+    failures
+        .iter()
+        .map(|(uuid, err)| format!("{uuid}: {err:#}"))
+        .collect::<Vec<_>>()
+        .join(", ")
 }
 
 impl Error {
+    /// Produces an `Error::ResponseDecode` for a failure to decode
+    /// `xml` as `type_name`
+    pub fn response_decode(type_name: &'static str, error: instant_xml::Error, xml: &str) -> Error {
+        log::debug!("Failed to decode {type_name}: {error:#} while parsing {xml}");
+        let mut end = xml.len().min(RESPONSE_DECODE_BODY_LIMIT);
+        while !xml.is_char_boundary(end) {
+            end -= 1;
+        }
+        Error::ResponseDecode {
+            type_name,
+            error,
+            body: xml[..end].to_string(),
+        }
+    }
+
     pub async fn with_failed_http_response(response: reqwest::Response) -> Error {
         let status = response.status();
         let headers = response.headers().clone();
@@ -71,11 +189,62 @@ impl Error {
             Err(err) => format!("Failed to retrieve body from failed request: {err:#}"),
         };
 
-        return Error::FailedRequest {
+        Error::FailedRequest {
             status,
             body,
             headers,
-        };
+        }
+    }
+
+    /// Returns the HTTP status code if this error is a `FailedRequest`
+    pub fn status(&self) -> Option<StatusCode> {
+        match self {
+            Self::FailedRequest { status, .. } => Some(*status),
+            _ => None,
+        }
+    }
+
+    /// Returns true if the device responded with a redirect,
+    /// which happens for example when it wants the request to be
+    /// made via its SSL port.  Use `redirect_location` to find
+    /// out where it was redirecting to.
+    pub fn is_redirect(&self) -> bool {
+        self.status().map(|s| s.is_redirection()).unwrap_or(false)
+    }
+
+    /// Returns the Location header from a redirect response
+    pub fn redirect_location(&self) -> Option<&str> {
+        match self {
+            Self::FailedRequest {
+                status, headers, ..
+            } if status.is_redirection() => headers
+                .get(reqwest::header::LOCATION)
+                .and_then(|v| v.to_str().ok()),
+            _ => None,
+        }
+    }
+
+    /// Returns true if a SUBSCRIBE or UNSUBSCRIBE request failed
+    /// with 412 Precondition Failed, which indicates that the
+    /// subscription id is unknown to the device, typically because
+    /// the subscription expired.  A fresh subscription is required.
+    pub fn is_subscription_precondition_failed(&self) -> bool {
+        self.status() == Some(StatusCode::PRECONDITION_FAILED)
+    }
+
+    /// Returns the UPnP error code from the SOAP fault that the
+    /// device returned for a failed action, if any.
+    /// Common codes are 401 (Invalid Action), 402 (Invalid Args)
+    /// and 701 (Transition not available).
+    pub fn upnp_error_code(&self) -> Option<u32> {
+        match self {
+            Self::FailedRequest { body, .. } => {
+                let start = body.find("<errorCode>")? + "<errorCode>".len();
+                let len = body[start..].find("</errorCode>")?;
+                body[start..start + len].trim().parse().ok()
+            }
+            _ => None,
+        }
     }
 
     pub async fn check_response(response: reqwest::Response) -> Result<reqwest::Response> {
@@ -92,6 +261,23 @@ impl Error {
 pub struct SonosDevice {
     url: Url,
     device: DeviceSpec,
+    /// Shared between clones, so that the id is fetched at most
+    /// once for a given device
+    household_id: Arc<OnceLock<String>>,
+    /// The live subscriptions made via this device or its clones,
+    /// keyed by SID, for `unsubscribe_all`
+    subscriptions: upnp::SubscriptionRegistry,
+    /// Limits the number of in-flight actions; shared between clones.
+    /// None means unbounded.
+    concurrency: Arc<Mutex<Option<Arc<tokio::sync::Semaphore>>>>,
+    /// The local address on which to receive events; shared between
+    /// clones.  None means that it is determined automatically.
+    subscription_local_ip: Arc<Mutex<Option<IpAddr>>>,
+    /// The root of `url`, eg: `http://10.0.0.1:1400/`
+    base_url: Url,
+    /// Used for all requests to the device, including subscription
+    /// renewals, so that connections are pooled; shared between clones
+    client: reqwest::Client,
 }
 
 impl SonosDevice {
@@ -102,43 +288,154 @@ impl SonosDevice {
         Self::from_url(format!("http://{addr}:1400/xml/device_description.xml").parse()?).await
     }
 
+    /// Like `from_ip`, but connects to the device via HTTPS on its
+    /// SSL port (1443).  The device presents a self-signed certificate,
+    /// which is accepted without verification.
+    #[cfg(feature = "tls")]
+    pub async fn from_ip_secure(addr: Ipv4Addr) -> Result<Self> {
+        Self::from_url(format!("https://{addr}:1443/xml/device_description.xml").parse()?).await
+    }
+
     /// Resolves the SonosDevice whose name is equal to the provided
     /// name.  If no matching device is found within a reasonably
     /// short, unspecified, implementation-defined timeout, then
     /// an `Error::RoomNotFound` is produced.
     pub async fn for_room(room_name: &str) -> Result<Self> {
         let mut rx = discover(std::time::Duration::from_secs(15)).await?;
+        let mut found = vec![];
+        let mut errors = vec![];
         while let Some(device) = rx.recv().await {
-            if let Ok(name) = device.name().await {
-                if name == room_name {
-                    return Ok(device);
-                }
+            // Prefer the name from the description, which doesn't
+            // require any further network I/O
+            let name = match device.device.room_name.clone() {
+                Some(name) => name,
+                None => match device.name().await {
+                    Ok(name) => name,
+                    Err(err) => {
+                        errors.push((device.url.to_string(), err));
+                        continue;
+                    }
+                },
+            };
+            if name == room_name {
+                return Ok(device);
+            }
+            if !found.contains(&name) {
+                found.push(name);
             }
         }
 
-        Err(Error::RoomNotFound(room_name.to_string()))
+        found.sort();
+        Err(Error::RoomNotFound {
+            room_name: room_name.to_string(),
+            found,
+            errors,
+        })
     }
 
     /// Constructs a SonosDevice from the supplied URL, which must
     /// be the device_description.xml URL for that device.
     /// Validates that the device is actually a Sonos device
     /// before returning successfully.
+    ///
+    /// Devices also serve their description over HTTPS on
+    /// `DeviceSpec::ssl_port` (1443), using a self-signed certificate.
+    /// `https` URLs require the `tls` feature; without it they produce
+    /// `Error::HttpsNotSupported` rather than an opaque connection error.
     pub async fn from_url(url: Url) -> Result<Self> {
-        let response = reqwest::get(url.clone()).await?;
+        #[cfg(not(feature = "tls"))]
+        if url.scheme() == "https" {
+            return Err(Error::HttpsNotSupported(url));
+        }
+        Self::from_url_with_client(url, new_client()?).await
+    }
+
+    /// Fetches the device description from `url` using `client`,
+    /// which the resulting SonosDevice then uses for its requests
+    async fn from_url_with_client(url: Url, client: reqwest::Client) -> Result<Self> {
+        let response = client.get(url.clone()).send().await?;
 
         let response = Error::check_response(response).await?;
         let body = response.text().await?;
         let device = DeviceSpec::parse_xml(&body)?;
 
-        Ok(Self { url, device })
+        Ok(Self::with_client(url, device, client))
+    }
+
+    /// Constructs a SonosDevice from a previously obtained device
+    /// description, such as one that was persisted after an earlier
+    /// discovery.  `url` must be the device_description.xml URL
+    /// from which `spec` was obtained.
+    /// No network I/O is performed and the spec is trusted as-is,
+    /// but an error is produced if the HTTP client cannot be built.
+    pub fn from_spec(url: Url, spec: DeviceSpec) -> Result<Self> {
+        Ok(Self::with_client(url, spec, new_client()?))
+    }
+
+    /// Constructs a SonosDevice that issues its requests using `client`
+    fn with_client(url: Url, spec: DeviceSpec, client: reqwest::Client) -> Self {
+        let base_url = url.join("/").unwrap_or_else(|_| url.clone());
+        Self {
+            url,
+            base_url,
+            client,
+            device: spec,
+            household_id: Arc::new(OnceLock::new()),
+            subscriptions: Default::default(),
+            concurrency: Arc::new(Mutex::new(None)),
+            subscription_local_ip: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    /// Limits the number of SOAP requests that this device, and its
+    /// clones, will have in flight at any one time.
+    /// By default there is no limit, but some models misbehave when
+    /// they receive overlapping requests, and methods such as
+    /// `now_playing` issue several requests concurrently.  Setting
+    /// `Some(1)` serializes all requests to the device; `None` removes
+    /// the limit.  Requests that are already in flight are not affected.
+    pub fn set_max_concurrency(&self, max_in_flight: Option<usize>) {
+        *self.concurrency.lock().unwrap() =
+            max_in_flight.map(|n| Arc::new(tokio::sync::Semaphore::new(n.max(1))));
+    }
+
+    /// Sets the local address on which subscriptions made via this
+    /// device, and its clones, receive events.
+    /// By default the address is determined by connecting to the
+    /// device and using the local address of that connection, but
+    /// with asymmetric routing that can select an interface that the
+    /// device cannot connect back to.  `None` restores the default.
+    /// Existing subscriptions are not affected.
+    pub fn set_subscription_local_ip(&self, local_ip: Option<IpAddr>) {
+        *self.subscription_local_ip.lock().unwrap() = local_ip;
     }
 
-    /// Returns the room/zone name of the device
+    /// Returns the room/zone name of the device.
+    /// An unresponsive device produces an `Error::Io` with kind
+    /// `TimedOut` after `NAME_TIMEOUT`.
     pub async fn name(&self) -> Result<String> {
-        let attr = self.get_zone_attributes().await?;
+        let attr = tokio::time::timeout(NAME_TIMEOUT, self.get_zone_attributes())
+            .await
+            .map_err(|_| std::io::Error::from(std::io::ErrorKind::TimedOut))??;
         attr.current_zone_name.ok_or(Error::NoName)
     }
 
+    /// Returns the id of the household to which this device belongs.
+    /// Devices that share a household id are part of the same Sonos
+    /// system, which allows operations to be scoped to a single system
+    /// when several independent systems share a network.
+    /// The id is fetched once and cached thereafter.
+    pub async fn household_id(&self) -> Result<String> {
+        if let Some(id) = self.household_id.get() {
+            return Ok(id.clone());
+        }
+        let id = <Self as DeviceProperties>::get_household_id(self)
+            .await?
+            .current_household_id
+            .ok_or(Error::MissingResponseField("CurrentHouseholdID"))?;
+        Ok(self.household_id.get_or_init(|| id).clone())
+    }
+
     /// Returns information about the zone to which this device belongs
     pub async fn get_zone_group_state(&self) -> Result<Vec<ZoneGroup>> {
         let state = <Self as ZoneGroupTopology>::get_zone_group_state(self).await?;
@@ -151,6 +448,115 @@ impl SonosDevice {
         })
     }
 
+    /// Returns the zone group topology as a map keyed by room name.
+    /// See `ZoneGroupState::by_room` for details.
+    pub async fn topology_by_room(&self) -> Result<BTreeMap<String, RoomTopology>> {
+        let groups = self.get_zone_group_state().await?;
+        Ok(ZoneGroupState::from(groups).by_room())
+    }
+
+    /// Returns true if this device is currently grouped with other
+    /// rooms.  A stereo pair or home theater setup on its own is not
+    /// considered to be grouped.
+    pub async fn is_grouped(&self) -> Result<bool> {
+        let uuid = self
+            .device
+            .uuid()
+            .ok_or(Error::MissingResponseField("UDN"))?;
+        let groups = self.get_zone_group_state().await?;
+        // A device that is absent from the topology is not grouped
+        Ok(ZoneGroupState::from(groups)
+            .is_grouped(uuid)
+            .unwrap_or(false))
+    }
+
+    /// Returns the rooms that could be added to this device's group,
+    /// excluding satellites and rooms that are already in the group.
+    /// See `ZoneGroupState::groupable_rooms` for details.
+    pub async fn groupable_rooms(&self) -> Result<Vec<RoomRef>> {
+        let uuid = self
+            .device
+            .uuid()
+            .ok_or(Error::MissingResponseField("UDN"))?;
+        let groups = self.get_zone_group_state().await?;
+        Ok(ZoneGroupState::from(groups).groupable_rooms(uuid))
+    }
+
+    /// Returns the channel map of the stereo pair or home theater setup
+    /// that this device is part of, which describes the channel(s)
+    /// played by each of its devices.
+    /// The map is empty if this device is not part of such a setup.
+    pub async fn channel_map(&self) -> Result<ChannelMap> {
+        let uuid = self
+            .device
+            .uuid()
+            .ok_or(Error::MissingResponseField("UDN"))?;
+        let groups = self.get_zone_group_state().await?;
+        Ok(ZoneGroupState::from(groups)
+            .channel_map(uuid)
+            .unwrap_or_default())
+    }
+
+    /// Bonds two devices into a stereo pair, with the device identified
+    /// by `left_uuid` playing the left channel and `right_uuid` the right.
+    /// This should be called on the left device, which becomes the
+    /// visible member of the pair.
+    pub async fn create_stereo_pair(&self, left_uuid: &str, right_uuid: &str) -> Result<()> {
+        <Self as DeviceProperties>::create_stereo_pair(
+            self,
+            device_properties::CreateStereoPairRequest {
+                channel_map_set: ChannelMap::stereo_pair(left_uuid, right_uuid).to_string(),
+            },
+        )
+        .await
+    }
+
+    /// Separates the stereo pair that this device is part of.
+    /// Produces `Error::NotStereoPair` if the device is not part
+    /// of a stereo pair.
+    pub async fn separate_stereo_pair(&self) -> Result<()> {
+        let uuid = self
+            .device
+            .uuid()
+            .ok_or(Error::MissingResponseField("UDN"))?;
+        let groups = self.get_zone_group_state().await?;
+        let map = ZoneGroupState::from(groups)
+            .stereo_pair_map(uuid)
+            .ok_or_else(|| Error::NotStereoPair(uuid.to_string()))?;
+        <Self as DeviceProperties>::separate_stereo_pair(
+            self,
+            device_properties::SeparateStereoPairRequest {
+                channel_map_set: map.to_string(),
+            },
+        )
+        .await
+    }
+
+    /// Adds the surrounds and/or sub listed in `map` to this home
+    /// theater device.  `map` must list this device, playing `LF,RF`,
+    /// along with each satellite and its channel.
+    pub async fn add_ht_satellites(&self, map: &ChannelMap) -> Result<()> {
+        <Self as DeviceProperties>::add_ht_satellite(
+            self,
+            device_properties::AddHtSatelliteRequest {
+                ht_sat_chan_map_set: map.to_string(),
+            },
+        )
+        .await
+    }
+
+    /// Removes the satellite with the specified uuid from this
+    /// home theater device
+    pub async fn remove_ht_satellite(&self, satellite_uuid: &str) -> Result<()> {
+        <Self as DeviceProperties>::remove_ht_satellite(
+            self,
+            device_properties::RemoveHtSatelliteRequest {
+                sat_room_uuid: satellite_uuid.to_string(),
+            },
+        )
+        .await
+    }
+
     /// Sets the mute state for the master sound channel
     pub async fn set_mute(&self, mute: bool) -> Result<()> {
         <Self as RenderingControl>::set_mute(
@@ -207,6 +613,260 @@ impl SonosDevice {
         .ok_or(Error::VolumeNone)
     }
 
+    /// Adjusts the volume of the master sound channel by `delta`,
+    /// which may be negative, returning the resulting volume.
+    /// The device clamps the result to the range 0-100.
+    /// Unlike reading the volume and then setting it, this is
+    /// performed atomically by the device, so it won't race with
+    /// changes made by other controllers.
+    pub async fn adjust_volume(&self, delta: i32) -> Result<u16> {
+        <Self as RenderingControl>::set_relative_volume(
+            self,
+            rendering_control::SetRelativeVolumeRequest {
+                instance_id: 0,
+                channel: Channel::Master,
+                adjustment: delta,
+            },
+        )
+        .await?
+        .new_volume
+        .ok_or(Error::VolumeNone)
+    }
+
+    /// Gradually changes the volume of the master sound channel
+    /// to `target`, which is in the range 0-100.
+    /// The shape and speed of the fade is determined by `ramp_type`.
+    /// Returns the ramp time, in seconds, reported by the device.
+    pub async fn ramp_to_volume(&self, target: u16, ramp_type: RampType) -> Result<u32> {
+        let target = Volume::new(target)?;
+        <Self as RenderingControl>::ramp_to_volume(
+            self,
+            rendering_control::RampToVolumeRequest {
+                instance_id: 0,
+                channel: Channel::Master,
+                ramp_type,
+                desired_volume: target.value(),
+                reset_volume_after: false,
+                program_uri: String::new(),
+            },
+        )
+        .await?
+        .ramp_time
+        .ok_or(Error::MissingResponseField("RampTime"))
+    }
+
+    /// Sets the bass EQ level
+    pub async fn set_bass(&self, bass: Bass) -> Result<()> {
+        <Self as RenderingControl>::set_bass(
+            self,
+            rendering_control::SetBassRequest {
+                instance_id: 0,
+                desired_bass: bass.value(),
+            },
+        )
+        .await
+    }
+
+    /// Returns the bass EQ level
+    pub async fn get_bass(&self) -> Result<Bass> {
+        let bass = <Self as RenderingControl>::get_bass(
+            self,
+            rendering_control::GetBassRequest { instance_id: 0 },
+        )
+        .await?
+        .current_bass
+        .ok_or(Error::MissingResponseField("CurrentBass"))?;
+        Bass::new(bass)
+    }
+
+    /// Sets the treble EQ level
+    pub async fn set_treble(&self, treble: Treble) -> Result<()> {
+        <Self as RenderingControl>::set_treble(
+            self,
+            rendering_control::SetTrebleRequest {
+                instance_id: 0,
+                desired_treble: treble.value(),
+            },
+        )
+        .await
+    }
+
+    /// Returns the treble EQ level
+    pub async fn get_treble(&self) -> Result<Treble> {
+        let treble = <Self as RenderingControl>::get_treble(
+            self,
+            rendering_control::GetTrebleRequest { instance_id: 0 },
+        )
+        .await?
+        .current_treble
+        .ok_or(Error::MissingResponseField("CurrentTreble"))?;
+        Treble::new(treble)
+    }
+
+    /// Sets the value of an EQ setting.
+    /// Boolean settings such as `EqType::NightMode` use 1 for true
+    /// and 0 for false.
+    pub async fn set_eq(&self, eq_type: EqType, value: i16) -> Result<()> {
+        <Self as RenderingControl>::set_eq(
+            self,
+            rendering_control::SetEqRequest {
+                instance_id: 0,
+                eq_type: eq_type.to_string(),
+                desired_value: value,
+            },
+        )
+        .await
+    }
+
+    /// Returns the value of an EQ setting
+    pub async fn get_eq(&self, eq_type: EqType) -> Result<i16> {
+        <Self as RenderingControl>::get_eq(
+            self,
+            rendering_control::GetEqRequest {
+                instance_id: 0,
+                eq_type: eq_type.to_string(),
+            },
+        )
+        .await?
+        .current_value
+        .ok_or(Error::MissingResponseField("CurrentValue"))
+    }
+
+    /// Enables or disables night mode on a home theater device
+    pub async fn set_night_mode(&self, enabled: bool) -> Result<()> {
+        self.set_eq(EqType::NightMode, enabled.into()).await
+    }
+
+    /// Returns whether night mode is enabled on a home theater device
+    pub async fn get_night_mode(&self) -> Result<bool> {
+        Ok(self.get_eq(EqType::NightMode).await? != 0)
+    }
+
+    /// Enables or disables speech enhancement on a home theater device
+    pub async fn set_speech_enhancement(&self, enabled: bool) -> Result<()> {
+        self.set_eq(EqType::DialogLevel, enabled.into()).await
+    }
+
+    /// Returns whether speech enhancement is enabled on a home theater device
+    pub async fn get_speech_enhancement(&self) -> Result<bool> {
+        Ok(self.get_eq(EqType::DialogLevel).await? != 0)
+    }
+
+    /// Resets bass, treble and loudness to their defaults, as well as
+    /// the left/right channel balance.  The volume is not affected.
+    pub async fn reset_eq(&self) -> Result<()> {
+        <Self as RenderingControl>::reset_basic_eq(
+            self,
+            rendering_control::ResetBasicEqRequest { instance_id: 0 },
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Resets an extended EQ setting, such as `EqType::SubGain`,
+    /// to its default value
+    pub async fn reset_ext_eq(&self, eq_type: EqType) -> Result<()> {
+        <Self as RenderingControl>::reset_ext_eq(
+            self,
+            rendering_control::ResetExtEqRequest {
+                instance_id: 0,
+                eq_type: eq_type.to_string(),
+            },
+        )
+        .await
+    }
+
+    /// Returns hardware details such as the serial number and MAC
+    /// address, which are stable identifiers for the device even
+    /// if its IP address changes
+    pub async fn zone_info(&self) -> Result<ZoneInfo> {
+        <Self as DeviceProperties>::get_zone_info(self)
+            .await?
+            .try_into()
+    }
+
+    /// Returns the name, id and members of the group that this device
+    /// belongs to.  This is cheaper than `get_zone_group_state` when
+    /// only the current group is of interest.
+    pub async fn zone_group_attributes(&self) -> Result<ZoneGroupAttributes> {
+        <Self as ZoneGroupTopology>::get_zone_group_attributes(self)
+            .await?
+            .try_into()
+    }
+
+    /// Performs the QPlay authentication handshake.
+    /// QPlay is Tencent's protocol for casting from QQ Music and
+    /// related apps, which is used in mainland China.  The controller
+    /// provides a `seed` and the device responds with a `code` derived
+    /// from it, along with its manufacturer (MID) and device (DID)
+    /// identifiers, which the controller uses to verify that the
+    /// device is a licensed QPlay renderer.
+    pub async fn qplay_auth(&self, seed: &str) -> Result<q_play::QPlayAuthResponse> {
+        <Self as QPlay>::q_play_auth(
+            self,
+            q_play::QPlayAuthRequest {
+                seed: seed.to_string(),
+            },
+        )
+        .await
+    }
+
+    /// Returns true if the physical buttons on the device are locked
+    pub async fn get_button_lock(&self) -> Result<bool> {
+        match <Self as DeviceProperties>::get_button_lock_state(self)
+            .await?
+            .current_button_lock_state
+            .ok_or(Error::MissingResponseField("CurrentButtonLockState"))?
+        {
+            ButtonLockState::On => Ok(true),
+            ButtonLockState::Off => Ok(false),
+            ButtonLockState::Unspecified(_) => Err(Error::InvalidEnumVariantValue),
+        }
+    }
+
+    /// Locks or unlocks the physical buttons on the device
+    pub async fn set_button_lock(&self, locked: bool) -> Result<()> {
+        <Self as DeviceProperties>::set_button_lock_state(
+            self,
+            device_properties::SetButtonLockStateRequest {
+                desired_button_lock_state: if locked {
+                    ButtonLockState::On
+                } else {
+                    ButtonLockState::Off
+                },
+            },
+        )
+        .await
+    }
+
+    /// Returns the (left, right) input gain of the line-in connector.
+    /// Only devices with a line-in connector, such as the Port, Amp
+    /// and Play:5, support this.
+    pub async fn get_line_in_level(&self) -> Result<(i32, i32)> {
+        let levels = <Self as AudioIn>::get_line_in_level(self).await?;
+        Ok((
+            levels
+                .current_left_line_in_level
+                .ok_or(Error::MissingResponseField("CurrentLeftLineInLevel"))?,
+            levels
+                .current_right_line_in_level
+                .ok_or(Error::MissingResponseField("CurrentRightLineInLevel"))?,
+        ))
+    }
+
+    /// Sets the input gain of the line-in connector for the left and
+    /// right channels.
+    pub async fn set_line_in_level(&self, left: i32, right: i32) -> Result<()> {
+        <Self as AudioIn>::set_line_in_level(
+            self,
+            audio_in::SetLineInLevelRequest {
+                desired_left_line_in_level: left,
+                desired_right_line_in_level: right,
+            },
+        )
+        .await
+    }
+
     /// Stops playback
     pub async fn stop(&self) -> Result<()> {
         <Self as AVTransport>::stop(self, Default::default()).await
@@ -240,6 +900,160 @@ impl SonosDevice {
             .await
     }
 
+    /// Seeks to the specified 1-based track number in the queue
+    pub async fn seek_track(&self, track: u32) -> Result<()> {
+        <Self as AVTransport>::seek(
+            self,
+            av_transport::SeekRequest {
+                instance_id: 0,
+                unit: SeekMode::TrackNr,
+                target: track.to_string(),
+            },
+        )
+        .await
+    }
+
+    /// Seeks to the specified position within the current track
+    pub async fn seek_time(&self, position: std::time::Duration) -> Result<()> {
+        <Self as AVTransport>::seek(
+            self,
+            av_transport::SeekRequest {
+                instance_id: 0,
+                unit: SeekMode::RelTime,
+                target: duration_to_hms(position),
+            },
+        )
+        .await
+    }
+
+    /// Returns the current transport state, such as whether the
+    /// device is playing or stopped
+    pub async fn transport_state(&self) -> Result<TransportState> {
+        <Self as AVTransport>::get_transport_info(
+            self,
+            av_transport::GetTransportInfoRequest { instance_id: 0 },
+        )
+        .await?
+        .current_transport_state
+        .ok_or(Error::MissingResponseField("CurrentTransportState"))
+    }
+
+    /// Pauses playback if the device is playing, otherwise starts
+    /// playback, returning the state that was requested.
+    /// Any other state, including stopped or having no media,
+    /// results in an attempt to play.
+    pub async fn play_pause(&self) -> Result<TransportState> {
+        match self.transport_state().await? {
+            TransportState::Playing | TransportState::Transitioning => {
+                self.pause().await?;
+                Ok(TransportState::PausedPlayback)
+            }
+            _ => {
+                self.play().await?;
+                Ok(TransportState::Playing)
+            }
+        }
+    }
+
+    /// Waits until the transport reaches the `target` state, failing
+    /// with an `std::io::ErrorKind::TimedOut` error if that doesn't happen
+    /// within `timeout`.
+    /// AVTransport events are used to observe the state, so this
+    /// resolves promptly once the state changes.  If a subscription
+    /// cannot be established, such as when a host firewall blocks the
+    /// device from delivering events, the state is polled instead.
+    pub async fn wait_for_state(
+        &self,
+        target: TransportState,
+        timeout: std::time::Duration,
+    ) -> Result<()> {
+        const POLL_INTERVAL: std::time::Duration = std::time::Duration::from_millis(500);
+        let deadline = tokio::time::Instant::now() + timeout;
+        let timed_out = || Error::from(std::io::Error::from(std::io::ErrorKind::TimedOut));
+
+        match tokio::time::timeout_at(deadline, self.subscribe_av_transport()).await {
+            Err(_) => return Err(timed_out()),
+            Ok(Ok(mut events)) => {
+                // The initial event reports the current state, so
+                // there is no window in which a change can be missed
+                let reached = tokio::time::timeout_at(deadline, async {
+                    while let Ok(Some(event)) = events.recv().await {
+                        let state = event
+                            .last_change
+                            .as_ref()
+                            .and_then(|changes| changes.as_ref())
+                            .and_then(|changes| changes.map.get(&0))
+                            .and_then(|change| change.transport_state.as_ref());
+                        if state == Some(&target) {
+                            return true;
+                        }
+                    }
+                    false
+                })
+                .await;
+                events.unsubscribe().await;
+                match reached {
+                    Ok(true) => return Ok(()),
+                    Ok(false) => log::warn!("wait_for_state: event stream ended; polling instead"),
+                    Err(_) => return Err(timed_out()),
+                }
+            }
+            Ok(Err(err)) => {
+                log::warn!("wait_for_state: failed to subscribe ({err:#}); polling instead");
+            }
+        }
+
+        loop {
+            if self.transport_state().await? == target {
+                return Ok(());
+            }
+            if tokio::time::Instant::now() + POLL_INTERVAL > deadline {
+                return Err(timed_out());
+            }
+            tokio::time::sleep(POLL_INTERVAL).await;
+        }
+    }
+
+    /// Sets the transport to play from this device's own queue.
+    /// This is needed after playing a radio stream or line-in source,
+    /// or after leaving a group.
+    pub async fn use_local_queue(&self) -> Result<()> {
+        let uuid = self
+            .device
+            .uuid()
+            .ok_or(Error::MissingResponseField("UDN"))?;
+        self.set_av_transport_uri(&SonosUri::queue(uuid).to_string(), None)
+            .await
+    }
+
+    /// Starts playing the specified 1-based track number from the queue,
+    /// first switching the transport to the queue if it is currently
+    /// playing from some other source.
+    /// If the device rejects the track number and it is outside the
+    /// bounds of the queue, `Error::ValueOutOfRange` is produced.
+    pub async fn play_queue_track(&self, track: u32) -> Result<()> {
+        if self.current_grouping().await? != Grouping::OwnQueue {
+            self.use_local_queue().await?;
+        }
+
+        if let Err(err) = self.seek_track(track).await {
+            if err.upnp_error_code().is_some() {
+                let len = self.queue_len().await?;
+                if track == 0 || track > len {
+                    return Err(Error::ValueOutOfRange {
+                        name: "track",
+                        value: i64::from(track),
+                        min: 1,
+                        max: i64::from(len),
+                    });
+                }
+            }
+            return Err(err);
+        }
+
+        self.play().await
+    }
+
     /// Clears the queue
     pub async fn queue_clear(&self) -> Result<()> {
         <Self as AVTransport>::remove_all_tracks_from_queue(self, Default::default()).await
@@ -250,12 +1064,416 @@ impl SonosDevice {
             self,
             av_transport::SetPlayModeRequest {
                 instance_id: 0,
-                new_play_mode: new_play_mode,
+                new_play_mode,
             },
         )
         .await
     }
 
+    /// Returns the set of play modes that are valid for the current
+    /// source.  Shuffle and repeat are not valid when playing a radio
+    /// stream, for example, and `set_play_mode` will fail in that case,
+    /// so you can use this to check before making the request.
+    /// This information is only reported via AVTransport events,
+    /// so this method briefly subscribes to that service in order
+    /// to obtain it.
+    pub async fn valid_play_modes(&self) -> Result<Vec<CurrentPlayMode>> {
+        self.initial_av_transport_state()
+            .await?
+            .current_valid_play_modes
+            .and_then(|modes| modes.into_inner())
+            .map(|modes| modes.modes)
+            .ok_or(Error::MissingResponseField("CurrentValidPlayModes"))
+    }
+
+    /// Returns true if another app, such as a phone casting via
+    /// Spotify Connect, has taken direct control of this device.
+    /// While in that state, transport actions from this crate may
+    /// not behave as expected; use `end_direct_control_session` to
+    /// reclaim control.
+    /// As with `valid_play_modes`, this briefly subscribes to
+    /// AVTransport events in order to obtain the state.
+    pub async fn is_under_direct_control(&self) -> Result<bool> {
+        Ok(self
+            .initial_av_transport_state()
+            .await?
+            .is_under_direct_control())
+    }
+
+    /// Returns the current playback position, track and track uri
+    pub async fn position_info(&self) -> Result<PositionInfo> {
+        let response = <Self as AVTransport>::get_position_info(
+            self,
+            av_transport::GetPositionInfoRequest { instance_id: 0 },
+        )
+        .await?;
+        Ok(response.into())
+    }
+
+    /// Returns the transport state, volume, mute state and current
+    /// track in a single snapshot.  The underlying requests are issued
+    /// concurrently, so this is cheaper than making them one at a time.
+    pub async fn now_playing(&self) -> Result<NowPlaying> {
+        let (state, volume, mute, position) = tokio::try_join!(
+            self.transport_state(),
+            self.get_volume(),
+            self.get_mute(),
+            self.position_info(),
+        )?;
+        Ok(NowPlaying {
+            state,
+            volume,
+            mute,
+            track: position.track_meta_data,
+            position: position.rel_time.unwrap_or_default(),
+            duration: position.track_duration.unwrap_or_default(),
+        })
+    }
+
+    /// Returns details of the alarm that is currently sounding on
+    /// this device, or None if no alarm is running
+    pub async fn running_alarm(&self) -> Result<Option<RunningAlarm>> {
+        let response = <Self as AVTransport>::get_running_alarm_properties(
+            self,
+            av_transport::GetRunningAlarmPropertiesRequest { instance_id: 0 },
+        )
+        .await?;
+        Ok(RunningAlarm::from_response(response))
+    }
+
+    /// Snoozes the alarm that is currently sounding for the
+    /// specified duration
+    pub async fn snooze_alarm(&self, duration: std::time::Duration) -> Result<()> {
+        <Self as AVTransport>::snooze_alarm(
+            self,
+            av_transport::SnoozeAlarmRequest {
+                instance_id: 0,
+                duration: duration_to_hms(duration),
+            },
+        )
+        .await
+    }
+
+    /// Returns the current UTC and local time of the device
+    pub async fn get_time_now(&self) -> Result<TimeNow> {
+        <Self as AlarmClock>::get_time_now(self).await?.try_into()
+    }
+
+    /// Returns the NTP server that the device uses to set its clock
+    pub async fn get_time_server(&self) -> Result<String> {
+        <Self as AlarmClock>::get_time_server(self)
+            .await?
+            .current_time_server
+            .ok_or(Error::MissingResponseField("CurrentTimeServer"))
+    }
+
+    /// Sets the NTP server that the device uses to set its clock
+    pub async fn set_time_server(&self, time_server: &str) -> Result<()> {
+        <Self as AlarmClock>::set_time_server(
+            self,
+            alarm_clock::SetTimeServerRequest {
+                desired_time_server: time_server.to_string(),
+            },
+        )
+        .await
+    }
+
+    /// Returns the time zone configuration of the device.
+    /// Alarms fire according to the local time that this produces.
+    pub async fn get_time_zone(&self) -> Result<TimeZone> {
+        <Self as AlarmClock>::get_time_zone(self).await?.try_into()
+    }
+
+    /// Sets the time zone configuration of the device
+    pub async fn set_time_zone(&self, time_zone: TimeZone) -> Result<()> {
+        <Self as AlarmClock>::set_time_zone(
+            self,
+            alarm_clock::SetTimeZoneRequest {
+                index: time_zone.index,
+                auto_adjust_dst: time_zone.auto_adjust_dst,
+            },
+        )
+        .await
+    }
+
+    /// Ends any direct control session, returning control of the
+    /// device to the local controllers
+    pub async fn end_direct_control_session(&self) -> Result<()> {
+        <Self as AVTransport>::end_direct_control_session(
+            self,
+            av_transport::EndDirectControlSessionRequest { instance_id: 0 },
+        )
+        .await
+    }
+
+    /// Subscribes to AVTransport events just long enough to receive
+    /// the initial event, which describes the complete current state.
+    /// Fails with an `std::io::ErrorKind::TimedOut` error if no event
+    /// arrives within 10 seconds, and with the error that ended the
+    /// stream, if any, should it end before an event is received.
+    async fn initial_av_transport_state(&self) -> Result<av_transport::AVTransportLastChange> {
+        let mut stream = self.subscribe_av_transport().await?;
+        let event = tokio::time::timeout(std::time::Duration::from_secs(10), stream.recv()).await;
+        stream.unsubscribe().await;
+
+        let event = event
+            .map_err(|_| std::io::Error::from(std::io::ErrorKind::TimedOut))??
+            .ok_or_else(|| {
+                std::io::Error::new(
+                    std::io::ErrorKind::UnexpectedEof,
+                    "AVTransport event stream ended before the initial event",
+                )
+            })?;
+        event
+            .last_change
+            .and_then(|last_change| last_change.into_inner())
+            .and_then(|mut last_change| last_change.map.remove(&0))
+            .ok_or(Error::MissingResponseField("LastChange"))
+    }
+
+    /// Returns information about what this device is currently
+    /// playing from, based on the CurrentURI reported by GetMediaInfo.
+    /// This can be used to determine whether the device is grouped
+    /// with another coordinator.
+    pub async fn current_grouping(&self) -> Result<Grouping> {
+        let uri = <Self as AVTransport>::get_media_info(
+            self,
+            av_transport::GetMediaInfoRequest { instance_id: 0 },
+        )
+        .await?
+        .current_uri
+        .ok_or(Error::MissingResponseField("CurrentURI"))?;
+        Ok(Grouping::from_uri(&uri))
+    }
+
+    /// Returns a high level description of what the device is playing
+    /// from, combining the CurrentURI and metadata reported by
+    /// GetMediaInfo.  When playing from the queue, the current track
+    /// number is obtained via GetPositionInfo.
+    pub async fn source(&self) -> Result<Source> {
+        let media = <Self as AVTransport>::get_media_info(
+            self,
+            av_transport::GetMediaInfoRequest { instance_id: 0 },
+        )
+        .await?;
+        let uri = media
+            .current_uri
+            .ok_or(Error::MissingResponseField("CurrentURI"))?;
+        let metadata = media
+            .current_uri_meta_data
+            .and_then(|meta| meta.into_inner());
+        let title = metadata.as_ref().map(|meta| meta.title.as_str());
+
+        let track = match SonosUri::parse(&uri) {
+            SonosUri::Queue { .. } => self.position_info().await?.track,
+            _ => None,
+        };
+
+        Ok(Source::from_uri(&uri, title, track))
+    }
+
+    /// Returns the commands that are currently permitted, so that
+    /// a UI can disable those that are not; for example, Next and
+    /// Previous are not available when playing a radio stream.
+    pub async fn current_transport_actions(&self) -> Result<Vec<TransportAction>> {
+        let actions = <Self as AVTransport>::get_current_transport_actions(
+            self,
+            av_transport::GetCurrentTransportActionsRequest { instance_id: 0 },
+        )
+        .await?
+        .actions
+        .unwrap_or_default();
+        Ok(TransportAction::parse_list(&actions))
+    }
+
+    /// Makes this device join the group that is coordinated
+    /// by `coordinator`
+    pub async fn join(&self, coordinator: &SonosDevice) -> Result<()> {
+        let uuid = coordinator
+            .device
+            .uuid()
+            .ok_or(Error::MissingResponseField("UDN"))?;
+        let uri = SonosUri::Group {
+            coordinator: uuid.to_string(),
+        };
+        self.set_av_transport_uri(&uri.to_string(), None).await
+    }
+
+    /// Removes this device from its group, making it the
+    /// coordinator of a group of its own
+    pub async fn leave(&self) -> Result<()> {
+        <Self as AVTransport>::become_coordinator_of_standalone_group(
+            self,
+            av_transport::BecomeCoordinatorOfStandaloneGroupRequest { instance_id: 0 },
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Moves what is playing on this device to `target`.
+    /// This device must be the coordinator of its group; otherwise
+    /// `Error::NotGroupCoordinator` is produced.
+    ///
+    /// How this is done depends upon the source:
+    ///
+    /// * When playing from this device's queue, `target` joins this
+    ///   group and coordination of the group, and with it the queue,
+    ///   is handed over to `target`, so playback continues without
+    ///   interruption.  If `stop_here` is true this device then leaves
+    ///   the group, otherwise it remains grouped with `target`.
+    /// * Any other source, such as a radio stream or a service
+    ///   container, is started on `target` from the beginning or live
+    ///   position; the playback position is not transferred.  This
+    ///   device is then stopped if `stop_here` is true.
+    ///
+    /// Sources that are bound to this device, such as its line-in,
+    /// continue to be read from this device.
+    pub async fn transfer_to(&self, target: &SonosDevice, stop_here: bool) -> Result<()> {
+        let media = <Self as AVTransport>::get_media_info(
+            self,
+            av_transport::GetMediaInfoRequest { instance_id: 0 },
+        )
+        .await?;
+        let Some(uri) = media.current_uri.filter(|uri| !uri.is_empty()) else {
+            // Nothing is playing, so there is nothing to transfer
+            return Ok(());
+        };
+
+        match SonosUri::parse(&uri) {
+            SonosUri::Group { coordinator } => Err(Error::NotGroupCoordinator { coordinator }),
+            SonosUri::Queue { .. } => {
+                let target_uuid = target
+                    .device
+                    .uuid()
+                    .ok_or(Error::MissingResponseField("UDN"))?;
+                target.join(self).await?;
+                <Self as AVTransport>::delegate_group_coordination_to(
+                    self,
+                    av_transport::DelegateGroupCoordinationToRequest {
+                        instance_id: 0,
+                        new_coordinator: target_uuid.to_string(),
+                        rejoin_group: !stop_here,
+                    },
+                )
+                .await
+            }
+            _ => {
+                target
+                    .play_uri(
+                        &uri,
+                        media.current_uri_meta_data.and_then(|m| m.into_inner()),
+                    )
+                    .await?;
+                if stop_here {
+                    self.stop().await?;
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// Groups `members` with `coordinator`.
+    /// The coordinator must be made standalone before the others can
+    /// join it, so this first removes `coordinator` from any group that
+    /// it is in, and only then joins each of the members to it.
+    /// The members are joined concurrently; if any of them fail,
+    /// the others are still joined and `Error::GroupRoomsFailed`
+    /// reports the uuid and error for each that failed.
+    pub async fn group_rooms(coordinator: &SonosDevice, members: &[SonosDevice]) -> Result<()> {
+        coordinator.leave().await?;
+
+        let mut tasks = vec![];
+        for member in members {
+            let member = member.clone();
+            let coordinator = coordinator.clone();
+            tasks.push(tokio::spawn(async move {
+                let result = member.join(&coordinator).await;
+                (member, result)
+            }));
+        }
+
+        let mut failures = vec![];
+        for task in tasks {
+            let (member, result) = task.await.map_err(std::io::Error::other)?;
+            if let Err(err) = result {
+                let id = member
+                    .device
+                    .uuid()
+                    .map(|uuid| uuid.to_string())
+                    .unwrap_or_else(|| member.url.to_string());
+                failures.push((id, err));
+            }
+        }
+
+        if failures.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::GroupRoomsFailed(failures))
+        }
+    }
+
+    /// Sets every member of this device's group to the same `volume`,
+    /// in the range 0-100.  This device must be the coordinator of its
+    /// group; otherwise `Error::NotGroupCoordinator` is produced.
+    ///
+    /// This differs from `GroupRenderingControl::set_group_volume`,
+    /// which adjusts the members proportionally so that their relative
+    /// levels are preserved; here each member's own volume is set
+    /// directly and any differences between them are discarded.
+    ///
+    /// The members are set concurrently.  The result holds the uuid of
+    /// each member along with the outcome of setting its volume, so
+    /// that partial failures can be identified.
+    ///
+    /// A stereo pair shares a single volume, so it is set through its
+    /// left-hand member, which is the one the Sonos app shows, and
+    /// appears once in the result.  The description of each other
+    /// member is fetched using this device's HTTP client.
+    pub async fn set_group_volume_all(&self, volume: u16) -> Result<Vec<(String, Result<()>)>> {
+        let volume = Volume::new(volume)?.value();
+        let uuid = self
+            .device
+            .uuid()
+            .ok_or(Error::MissingResponseField("UDN"))?;
+        let groups = self.get_zone_group_state().await?;
+        let group = groups
+            .into_iter()
+            .find(|group| group.members.iter().any(|member| member.uuid == uuid))
+            .ok_or_else(|| Error::DeviceNotInTopology(uuid.to_string()))?;
+        if group.coordinator != uuid {
+            return Err(Error::NotGroupCoordinator {
+                coordinator: group.coordinator,
+            });
+        }
+
+        let mut tasks = vec![];
+        for member in group.room_leaders() {
+            let this = (member.uuid == uuid).then(|| self.clone());
+            let client = self.client.clone();
+            let member_uuid = member.uuid.clone();
+            let location = member.location.clone();
+            tasks.push(tokio::spawn(async move {
+                let result = async {
+                    let device = match this {
+                        Some(device) => device,
+                        None => {
+                            SonosDevice::from_url_with_client(location.parse()?, client).await?
+                        }
+                    };
+                    device.set_volume(volume).await
+                }
+                .await;
+                (member_uuid, result)
+            }));
+        }
+
+        let mut results = vec![];
+        for task in tasks {
+            results.push(task.await.map_err(std::io::Error::other)?);
+        }
+        Ok(results)
+    }
+
     pub async fn set_av_transport_uri(
         &self,
         uri: &str,
@@ -272,6 +1490,174 @@ impl SonosDevice {
         .await
     }
 
+    /// Preloads `uri` as the track to play once the current one ends,
+    /// allowing a gapless transition between them.
+    /// This is intended for use alongside `set_av_transport_uri` by a
+    /// player that feeds the device one URI at a time; call it again
+    /// after each transition to supply the following track.
+    /// When the device is playing from its queue, the queue determines
+    /// the next track, and the device manages the NextURI itself, so
+    /// there is no need to use this.
+    pub async fn set_next_uri(&self, uri: &str, metadata: Option<TrackMetaData>) -> Result<()> {
+        <Self as AVTransport>::set_next_av_transport_uri(
+            self,
+            av_transport::SetNextAvTransportUriRequest {
+                instance_id: 0,
+                next_uri: uri.to_string(),
+                next_uri_meta_data: metadata
+                    .map(|metadata| metadata.to_didl_string())
+                    .unwrap_or_default(),
+            },
+        )
+        .await
+    }
+
+    /// Like `set_av_transport_uri`, but passes `didl_metadata` to the
+    /// device exactly as provided.
+    /// This is useful for content such as service provided items whose
+    /// metadata isn't fully captured by `TrackMetaData`; the `DIDL-Lite`
+    /// returned by `ContentDirectory::browse` can be passed through as-is.
+    pub async fn set_av_transport_uri_raw(&self, uri: &str, didl_metadata: &str) -> Result<()> {
+        self.action(
+            av_transport::SERVICE_TYPE,
+            "SetAVTransportURI",
+            raw_didl::SetAvTransportUriRequest {
+                instance_id: 0,
+                current_uri: uri,
+                current_uri_meta_data: didl_metadata,
+            },
+        )
+        .await
+    }
+
+    /// Sets the transport URI and then begins playback.
+    /// This is typically used for one-shot playback of a stream,
+    /// for example an internet radio station via a URI such as
+    /// `x-rincon-mp3radio://example.com/stream.mp3`, or a service
+    /// provided station such as `x-sonosapi-stream:s12345?sid=254&flags=8224&sn=0`.
+    /// If setting the URI fails, playback is not attempted.
+    pub async fn play_uri(&self, uri: &str, metadata: Option<TrackMetaData>) -> Result<()> {
+        self.set_av_transport_uri(uri, metadata).await?;
+        self.play().await
+    }
+
+    /// Switches the transport to a container, such as an album or
+    /// playlist provided by a music service, and begins playback.
+    /// `uri` is typically of the form
+    /// `x-rincon-cpcontainer:1006206cspotify%3aplaylist%3a...`, and
+    /// `metadata` should describe the container, including the `desc`
+    /// that identifies the music service account that provides it;
+    /// the `TrackMetaData` of the corresponding favorite or browse
+    /// result can be used as-is.
+    /// The device manages the tracks of the container itself, so unlike
+    /// `queue_append`, which adds individual tracks to the queue, there
+    /// is no need to enumerate the tracks and the queue is not modified.
+    pub async fn play_container(&self, uri: &str, metadata: TrackMetaData) -> Result<()> {
+        self.play_uri(uri, Some(metadata)).await
+    }
+
+    /// Interrupts playback to play the notification sound or clip at
+    /// `uri`, optionally at the specified volume, and then restores the
+    /// prior source, position, volume and play state.
+    /// Playback of the clip is abandoned if it has not finished within
+    /// `NOTIFICATION_TIMEOUT`.
+    /// This should be used on a group coordinator; a group member will
+    /// leave its group in order to play the clip and will not rejoin it.
+    pub async fn play_notification(&self, uri: &str, volume: Option<u16>) -> Result<()> {
+        let (media, state, position, prior_volume) = tokio::try_join!(
+            <Self as AVTransport>::get_media_info(
+                self,
+                av_transport::GetMediaInfoRequest { instance_id: 0 },
+            ),
+            self.transport_state(),
+            self.position_info(),
+            self.get_volume(),
+        )?;
+        let was_playing = matches!(
+            state,
+            TransportState::Playing | TransportState::Transitioning
+        );
+
+        if let Some(volume) = volume {
+            self.set_volume(volume).await?;
+        }
+
+        let result = match self.play_uri(uri, None).await {
+            Ok(()) => self.wait_for_notification().await,
+            Err(err) => Err(err),
+        };
+
+        // Restore the prior state even if the clip failed to play.
+        // Each step is best-effort, so that a failure to restore the
+        // source doesn't leave the device at the notification volume.
+        let mut errors = vec![];
+        let mut restore = |step: &str, outcome: Result<()>| {
+            if let Err(err) = outcome {
+                log::warn!("play_notification: failed to restore {step}: {err:#}");
+                errors.push(err);
+            }
+        };
+        if let Some(prior_uri) = media.current_uri.filter(|uri| !uri.is_empty()) {
+            restore(
+                "source",
+                self.set_av_transport_uri(
+                    &prior_uri,
+                    media.current_uri_meta_data.and_then(|m| m.into_inner()),
+                )
+                .await,
+            );
+            if let (SonosUri::Queue { .. }, Some(track)) =
+                (SonosUri::parse(&prior_uri), position.track)
+            {
+                restore("track", self.seek_track(track).await);
+            }
+            if let Some(rel_time) = position.rel_time.filter(|t| !t.is_zero()) {
+                // Streams cannot be seeked, so this is best-effort
+                if let Err(err) = self.seek_time(rel_time).await {
+                    log::debug!("play_notification: failed to restore position: {err:#}");
+                }
+            }
+        }
+        if volume.is_some() {
+            restore("volume", self.set_volume(prior_volume).await);
+        }
+        if was_playing {
+            restore("playback", self.play().await);
+        }
+
+        result?;
+        match errors.into_iter().next() {
+            Some(err) => Err(err),
+            None => Ok(()),
+        }
+    }
+
+    /// Waits for the clip started by `play_notification` to finish.
+    /// The device may briefly report that it is still stopped before
+    /// it starts to play the clip, so the clip is only considered to
+    /// have finished once it has been seen to start.
+    async fn wait_for_notification(&self) -> Result<()> {
+        let deadline = tokio::time::Instant::now() + NOTIFICATION_TIMEOUT;
+        let mut started = false;
+        loop {
+            tokio::time::sleep(std::time::Duration::from_millis(500)).await;
+            match self.transport_state().await? {
+                TransportState::Playing | TransportState::Transitioning => started = true,
+                TransportState::Stopped | TransportState::PausedPlayback if started => {
+                    return Ok(())
+                }
+                _ => {}
+            }
+            if tokio::time::Instant::now() >= deadline {
+                log::warn!("play_notification: clip did not finish in time; stopping");
+                return self.stop().await;
+            }
+        }
+    }
+
+    /// Adds an item to the queue so that it will play next.
+    /// Use `QueueAddition::try_from` on the response to obtain
+    /// the position of the newly added item.
     pub async fn queue_prepend(
         &self,
         uri: &str,
@@ -290,6 +1676,9 @@ impl SonosDevice {
         .await
     }
 
+    /// Adds an item to the end of the queue.
+    /// Use `QueueAddition::try_from` on the response to obtain
+    /// the position of the newly added item.
     pub async fn queue_append(
         &self,
         uri: &str,
@@ -308,6 +1697,185 @@ impl SonosDevice {
         .await
     }
 
+    /// Inserts an item into the queue so that it becomes the track at
+    /// the 1-based position `at_position`, moving the track currently at
+    /// that position, and those after it, down by one.
+    /// A position of 0, or one beyond the end of the queue, appends
+    /// the item instead.
+    /// Use `QueueAddition::try_from` on the response to obtain
+    /// the position of the newly added item.
+    pub async fn queue_insert(
+        &self,
+        uri: &str,
+        metadata: Option<TrackMetaData>,
+        at_position: u32,
+    ) -> Result<av_transport::AddUriToQueueResponse> {
+        let position = if at_position == 0 || at_position > self.queue_len().await? {
+            // 0 means "add at the end"
+            0
+        } else {
+            at_position
+        };
+        <Self as AVTransport>::add_uri_to_queue(
+            self,
+            av_transport::AddUriToQueueRequest {
+                instance_id: 0,
+                enqueued_uri: uri.to_string(),
+                enqueued_uri_meta_data: metadata.into(),
+                desired_first_track_number_enqueued: position,
+                enqueue_as_next: false,
+            },
+        )
+        .await
+    }
+
+    /// Like `queue_prepend`, but passes `didl_metadata` to the device
+    /// exactly as provided.  See `set_av_transport_uri_raw`.
+    pub async fn queue_prepend_raw(
+        &self,
+        uri: &str,
+        didl_metadata: &str,
+    ) -> Result<av_transport::AddUriToQueueResponse> {
+        self.add_uri_to_queue_raw(uri, didl_metadata, true).await
+    }
+
+    /// Like `queue_append`, but passes `didl_metadata` to the device
+    /// exactly as provided.  See `set_av_transport_uri_raw`.
+    pub async fn queue_append_raw(
+        &self,
+        uri: &str,
+        didl_metadata: &str,
+    ) -> Result<av_transport::AddUriToQueueResponse> {
+        self.add_uri_to_queue_raw(uri, didl_metadata, false).await
+    }
+
+    async fn add_uri_to_queue_raw(
+        &self,
+        uri: &str,
+        didl_metadata: &str,
+        enqueue_as_next: bool,
+    ) -> Result<av_transport::AddUriToQueueResponse> {
+        self.action(
+            av_transport::SERVICE_TYPE,
+            "AddURIToQueue",
+            raw_didl::AddUriToQueueRequest {
+                instance_id: 0,
+                enqueued_uri: uri,
+                enqueued_uri_meta_data: didl_metadata,
+                desired_first_track_number_enqueued: 0,
+                enqueue_as_next,
+            },
+        )
+        .await
+    }
+
+    /// Adds an item to the saved queue (Sonos playlist) identified by
+    /// `object_id`, such as `SQ:3`, at the 0-based position `at_index`.
+    /// `update_id` must be the current UpdateID of the playlist, which
+    /// guards against concurrent edits.  It can be obtained from the
+    /// `update_id` of the `BrowseResult` produced by browsing the playlist
+    /// via `ContentDirectory::browse`, and the `new_update_id` in the
+    /// response can be used for a subsequent edit.
+    pub async fn playlist_add(
+        &self,
+        object_id: &str,
+        update_id: u32,
+        uri: &str,
+        metadata: Option<TrackMetaData>,
+        at_index: u32,
+    ) -> Result<av_transport::AddUriToSavedQueueResponse> {
+        <Self as AVTransport>::add_uri_to_saved_queue(
+            self,
+            av_transport::AddUriToSavedQueueRequest::new(
+                object_id.to_string(),
+                update_id,
+                uri.to_string(),
+                metadata.into(),
+                at_index,
+            ),
+        )
+        .await
+    }
+
+    /// Moves tracks within the saved queue (Sonos playlist) identified
+    /// by `object_id`.  Each element of `moves` is a pair of the 0-based
+    /// current position of a track and its 0-based new position.
+    /// See `playlist_add` for how to obtain `update_id`.
+    pub async fn playlist_reorder(
+        &self,
+        object_id: &str,
+        update_id: u32,
+        moves: &[(u32, u32)],
+    ) -> Result<av_transport::ReorderTracksInSavedQueueResponse> {
+        let join = |values: Vec<String>| values.join(",");
+        <Self as AVTransport>::reorder_tracks_in_saved_queue(
+            self,
+            av_transport::ReorderTracksInSavedQueueRequest::new(
+                object_id.to_string(),
+                update_id,
+                join(moves.iter().map(|(track, _)| track.to_string()).collect()),
+                join(moves.iter().map(|(_, to)| to.to_string()).collect()),
+            ),
+        )
+        .await
+    }
+
+    /// Replaces the contents of the queue with `items`, which are
+    /// pairs of URI and optional metadata.
+    /// The items are added in batches using AddMultipleURIsToQueue,
+    /// so this is a single round-trip after clearing the queue unless
+    /// there are more than 16 items.
+    /// If `play` is true, the queue is selected as the transport
+    /// source and playback begins from the first track.
+    pub async fn replace_queue(
+        &self,
+        items: &[(String, Option<TrackMetaData>)],
+        play: bool,
+    ) -> Result<()> {
+        // The device rejects larger batches
+        const MAX_URIS_PER_REQUEST: usize = 16;
+
+        self.queue_clear().await?;
+
+        for chunk in items.chunks(MAX_URIS_PER_REQUEST) {
+            let uris: Vec<&str> = chunk.iter().map(|(uri, _)| uri.as_str()).collect();
+            // Both lists are space separated, so each item must have
+            // some metadata in order for them to remain aligned
+            let metadata: Vec<String> = chunk
+                .iter()
+                .map(|(uri, metadata)| match metadata {
+                    Some(metadata) => metadata.to_didl_string(),
+                    None => TrackMetaData {
+                        url: uri.to_string(),
+                        ..Default::default()
+                    }
+                    .to_didl_string(),
+                })
+                .collect();
+
+            <Self as AVTransport>::add_multiple_uris_to_queue(
+                self,
+                av_transport::AddMultipleUrisToQueueRequest::new(
+                    0,
+                    chunk.len() as u32,
+                    uris.join(" "),
+                    metadata.join(" "),
+                    String::new(),
+                    String::new(),
+                    0,
+                    false,
+                ),
+            )
+            .await?;
+        }
+
+        if play && !items.is_empty() {
+            self.play_queue_track(1).await?;
+        }
+
+        Ok(())
+    }
+
     pub async fn queue_browse(
         &self,
         starting_index: u32,
@@ -317,8 +1885,8 @@ impl SonosDevice {
             self,
             queue::BrowseRequest {
                 queue_id: 0,
-                starting_index: starting_index,
-                requested_count: requested_count,
+                starting_index,
+                requested_count,
             },
         )
         .await?;
@@ -329,9 +1897,143 @@ impl SonosDevice {
         }
     }
 
+    /// Performs a ContentDirectory Search, returning up to `count`
+    /// matching items, starting at `start`, from within `container_id`
+    /// (eg: `A:TRACKS` for the music library tracks, or `0` for
+    /// everything).
+    /// `criteria` uses the UPnP search syntax of property/operator/value
+    /// expressions joined by `and`/`or`, for example:
+    /// `upnp:class derivedfrom "object.item.audioItem" and dc:creator contains "Queen"`.
+    /// Not all firmware implements Search; where it is missing,
+    /// `Error::UnsupportedAction` is produced and the Sonos-specific
+    /// equivalent is to browse an object id with the search terms
+    /// appended, such as `A:ARTIST:Queen`.
+    pub async fn search(
+        &self,
+        container_id: &str,
+        criteria: &str,
+        start: u32,
+        count: u32,
+    ) -> Result<BrowseResult> {
+        let response: SearchResponse = self
+            .action(
+                content_directory::SERVICE_TYPE,
+                "Search",
+                SearchRequest {
+                    container_id: container_id.to_string(),
+                    search_criteria: criteria.to_string(),
+                    filter: "*".to_string(),
+                    starting_index: start,
+                    requested_count: count,
+                    sort_criteria: String::new(),
+                },
+            )
+            .await?;
+        Ok(response.into())
+    }
+
+    /// Returns the number of tracks in the queue.
+    /// This issues a Browse request with a `requested_count` of 0,
+    /// which Sonos treats as a request for the counts only, so
+    /// no track metadata is returned or parsed.
+    pub async fn queue_len(&self) -> Result<u32> {
+        <Self as Queue>::browse(
+            self,
+            queue::BrowseRequest {
+                queue_id: 0,
+                starting_index: 0,
+                requested_count: 0,
+            },
+        )
+        .await?
+        .total_matches
+        .ok_or(Error::MissingResponseField("TotalMatches"))
+    }
+
+    /// Returns the UpdateID of the queue.  This changes whenever the
+    /// queue is edited, including by other controllers, so it can be
+    /// used to cheaply determine whether a cached copy of the queue
+    /// needs to be fetched again.
+    pub async fn queue_version(&self) -> Result<u32> {
+        <Self as Queue>::browse(
+            self,
+            queue::BrowseRequest {
+                queue_id: 0,
+                starting_index: 0,
+                requested_count: 0,
+            },
+        )
+        .await?
+        .update_id
+        .ok_or(Error::MissingResponseField("UpdateID"))
+    }
+
+    /// Returns a page of the queue, as `queue_browse` does, together
+    /// with the total length of the queue and its UpdateID
+    /// (see `queue_version`)
+    pub async fn queue_browse_detailed(
+        &self,
+        starting_index: u32,
+        requested_count: u32,
+    ) -> Result<BrowseResult> {
+        let response = <Self as Queue>::browse(
+            self,
+            queue::BrowseRequest {
+                queue_id: 0,
+                starting_index,
+                requested_count,
+            },
+        )
+        .await?;
+        Ok(response.into())
+    }
+
     pub fn url(&self) -> &Url {
         &self.url
     }
+
+    /// Returns the base URL of the device, such as `http://10.0.0.1:1400/`,
+    /// against which relative URLs reported by the device are resolved
+    pub fn base_url(&self) -> &Url {
+        &self.base_url
+    }
+
+    /// Fetches the image data of the largest icon advertised in
+    /// the device description, which depicts the model of speaker.
+    /// `DeviceSpec::largest_icon` describes its format and size.
+    pub async fn icon_bytes(&self) -> Result<bytes::Bytes> {
+        let icon = self
+            .device
+            .largest_icon()
+            .ok_or(Error::MissingResponseField("iconList"))?;
+        let response = self.client.get(self.resolve_url(&icon.url)?).send().await?;
+        let response = Error::check_response(response).await?;
+        Ok(response.bytes().await?)
+    }
+
+    /// Returns the album art URL of `track`, resolved against
+    /// `base_url`.  See `TrackMetaData::absolute_art_url`.
+    pub fn resolve_art(&self, track: &TrackMetaData) -> Option<Url> {
+        track.absolute_art_url(&self.base_url)
+    }
+
+    /// Resolves a URL reported by the device, such as the
+    /// `/getaa?...` form of album art URL, against `base_url`.
+    /// Absolute URLs are returned unchanged.
+    /// Produces `Error::InvalidUri` if `path` cannot be joined.
+    pub fn resolve_url(&self, path: &str) -> Result<Url> {
+        Ok(self.base_url.join(path)?)
+    }
+}
+
+/// Builds the HTTP client used for requests to devices.
+/// With the `tls` feature, the self-signed certificates that devices
+/// present on their SSL port are accepted.
+fn new_client() -> Result<reqwest::Client> {
+    let builder = reqwest::Client::builder();
+    #[cfg(feature = "tls")]
+    let builder = builder.danger_accept_invalid_certs(true);
+    Ok(builder.build()?)
 }
 
 const SOAP_ENCODING: &str = "http://schemas.xmlsoap.org/soap/encoding/";
@@ -397,6 +2099,40 @@ mod soap_empty_resp {
     pub struct Body {}
 }
 
+/// Variants of requests whose metadata is passed through as the
+/// caller provided DIDL string, rather than being re-encoded from
+/// a `TrackMetaData`
+mod raw_didl {
+    use crate::av_transport::SERVICE_TYPE;
+    use instant_xml::ToXml;
+
+    #[derive(ToXml, Debug, Clone, PartialEq)]
+    #[xml(rename = "AddURIToQueue", ns(SERVICE_TYPE))]
+    pub struct AddUriToQueueRequest<'a> {
+        #[xml(rename = "InstanceID", ns(""))]
+        pub instance_id: u32,
+        #[xml(rename = "EnqueuedURI", ns(""))]
+        pub enqueued_uri: &'a str,
+        #[xml(rename = "EnqueuedURIMetaData", ns(""))]
+        pub enqueued_uri_meta_data: &'a str,
+        #[xml(rename = "DesiredFirstTrackNumberEnqueued", ns(""))]
+        pub desired_first_track_number_enqueued: u32,
+        #[xml(rename = "EnqueueAsNext", ns(""))]
+        pub enqueue_as_next: bool,
+    }
+
+    #[derive(ToXml, Debug, Clone, PartialEq)]
+    #[xml(rename = "SetAVTransportURI", ns(SERVICE_TYPE))]
+    pub struct SetAvTransportUriRequest<'a> {
+        #[xml(rename = "InstanceID", ns(""))]
+        pub instance_id: u32,
+        #[xml(rename = "CurrentURI", ns(""))]
+        pub current_uri: &'a str,
+        #[xml(rename = "CurrentURIMetaData", ns(""))]
+        pub current_uri_meta_data: &'a str,
+    }
+}
+
 /// This trait decodes a SOAP response envelope into Self
 pub trait DecodeSoapResponse {
     /// xml is a complete Soap `<Envelope>` element.
@@ -410,7 +2146,8 @@ impl DecodeSoapResponse for () {
     fn decode_soap_xml(xml: &str) -> Result<()> {
         // Verify that it parses, but discard because it has no
         // useful content for us
-        let _envelope: soap_empty_resp::Envelope = instant_xml::from_str(xml)?;
+        let _envelope: soap_empty_resp::Envelope =
+            instant_xml::from_str(xml).map_err(|error| Error::response_decode("()", error, xml))?;
         Ok(())
     }
 }
@@ -428,7 +2165,106 @@ impl SonosDevice {
             .device
             .get_service(service)
             .ok_or_else(|| Error::UnsupportedService(service.to_string()))?;
-        service.subscribe(&self.url).await
+        let local_ip = *self.subscription_local_ip.lock().unwrap();
+        let mut stream: EventStream<T> = service
+            .subscribe_with_client(&self.client, &self.url, local_ip)
+            .await?;
+        stream.register(&self.subscriptions);
+        Ok(stream)
+    }
+
+    /// Cancels every subscription that was made via this device,
+    /// or any of its clones, and that has not already been cancelled.
+    /// The associated `EventStream`s will produce no further events.
+    /// Errors are ignored, as they are by `EventStream::unsubscribe`.
+    pub async fn unsubscribe_all(&self) {
+        let subscriptions = std::mem::take(&mut *self.subscriptions.lock().unwrap());
+        for subscription in subscriptions.into_values() {
+            subscription.cancel().await;
+        }
+    }
+
+    /// Subscribes to ZoneGroupTopology events, re-reading the full
+    /// zone group state each time an event is received.
+    /// The topology events themselves often carry only a partial
+    /// picture of what changed, so fetching fresh state is the most
+    /// reliable way to track the grouping of the household.
+    pub async fn subscribe_topology_with_state(&self) -> Result<TopologyStream> {
+        let events = self.subscribe_zone_group_topology().await?;
+        Ok(TopologyStream::new(events, self.clone()))
+    }
+
+    /// Fetches and parses the Service Control Protocol Description for
+    /// the specified service type on this device.
+    /// This is primarily useful for diagnostics, and for discovering
+    /// actions that are supported by the firmware on this device
+    /// but that are not known to this crate.
+    pub async fn fetch_scpd(&self, service: &str) -> Result<Scpd> {
+        let service = self
+            .device
+            .get_service(service)
+            .ok_or_else(|| Error::UnsupportedService(service.to_string()))?;
+
+        let response = self.client.get(service.scpd_url(&self.url)).send().await?;
+        let response = Error::check_response(response).await?;
+        let body = response.text().await?;
+        Scpd::parse_xml(&body)
+    }
+
+    /// Returns the known services that this device, or any of its
+    /// embedded devices, exposes.  This can be used to feature-detect,
+    /// for example, whether a device has line-in via `KnownService::AudioIn`.
+    pub fn supported_services(&self) -> Vec<KnownService> {
+        KnownService::ALL
+            .iter()
+            .copied()
+            .filter(|service| self.device.get_service(service.service_type()).is_some())
+            .collect()
+    }
+
+    /// Gathers the device description, zone info, zone group topology
+    /// and supported services into a JSON value that can be saved and
+    /// attached to an issue report.
+    /// Parts that cannot be retrieved are represented by an object of
+    /// the form `{"error": "..."}` rather than failing the snapshot.
+    #[cfg(feature = "serde")]
+    pub async fn diagnostic_snapshot(&self) -> Result<serde_json::Value> {
+        fn capture<T: serde::Serialize>(result: Result<T>) -> serde_json::Value {
+            match result.map(|value| serde_json::to_value(value)) {
+                Ok(Ok(value)) => value,
+                Ok(Err(err)) => serde_json::json!({ "error": format!("{err:#}") }),
+                Err(err) => serde_json::json!({ "error": format!("{err:#}") }),
+            }
+        }
+
+        let (zone_info, zone_group_state) =
+            tokio::join!(self.zone_info(), self.get_zone_group_state());
+
+        Ok(serde_json::json!({
+            "url": self.url.as_str(),
+            "device_spec": capture(Ok(&self.device)),
+            "zone_info": capture(zone_info),
+            "zone_group_state": capture(zone_group_state),
+            "supported_services": self
+                .supported_services()
+                .iter()
+                .map(|service| service.service_type())
+                .collect::<Vec<_>>(),
+        }))
+    }
+
+    /// Returns whether this device supports the specified action,
+    /// based on the service descriptions that were available when
+    /// this crate was generated.
+    /// Returns None if this model, service or action is not known,
+    /// in which case the only way to find out is to try it.
+    pub fn supports_action(&self, service: &str, action: &str) -> Option<bool> {
+        let model = self.device.model_number.as_deref()?;
+        if !KNOWN_MODELS.contains(&model) {
+            return None;
+        }
+        let models = action_supported_models(service, action)?;
+        Some(models.contains(&model))
     }
 
     /// This is a low level helper function for performing a SOAP Action
@@ -448,31 +2284,61 @@ impl SonosDevice {
             .get_service(service)
             .ok_or_else(|| Error::UnsupportedService(service.to_string()))?;
 
+        let unsupported = || Error::UnsupportedAction {
+            service: service.service_type.to_string(),
+            action: action.to_string(),
+        };
+
+        // If we have a description of this model, we can avoid making
+        // a request that is guaranteed to fail
+        if self.supports_action(&service.service_type, action) == Some(false) {
+            return Err(unsupported());
+        }
+
         let envelope = soap::Envelope {
             encoding_style: SOAP_ENCODING,
             body: soap::Body { payload },
         };
 
         let body = instant_xml::to_string(&envelope)?;
+
+        let semaphore = self.concurrency.lock().unwrap().clone();
+        let _permit = match semaphore {
+            Some(semaphore) => Some(
+                semaphore
+                    .acquire_owned()
+                    .await
+                    .expect("semaphore is never closed"),
+            ),
+            None => None,
+        };
+
         log::trace!("Sending: {body}");
 
         let soap_action = format!("\"{}#{action}\"", service.service_type);
         let url = service.control_url(&self.url);
 
-        let response = reqwest::Client::new()
+        let response = self
+            .client
             .post(url)
             .header("CONTENT-TYPE", "text/xml; charset=\"utf-8\"")
             .header("SOAPAction", soap_action)
-            .body::<String>(body.into())
+            .body::<String>(body)
             .send()
             .await?;
 
-        let response = Error::check_response(response).await?;
+        let response = match Error::check_response(response).await {
+            // UPnP error 401 is "Invalid Action"
+            Err(err) if err.upnp_error_code() == Some(401) => {
+                return Err(unsupported());
+            }
+            result => result?,
+        };
 
         let body = response.text().await?;
         log::trace!("Got response: {body}");
 
-        RESP::decode_soap_xml(&body)
+        RESP::decode_soap_xml(&xmlutil::strip_not_implemented(&body))
     }
 }
 
@@ -490,6 +2356,21 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_raw_didl_request() {
+        let request = raw_didl::AddUriToQueueRequest {
+            instance_id: 0,
+            enqueued_uri: "x-sonos-spotify:track",
+            enqueued_uri_meta_data: r#"<DIDL-Lite><item id="a&amp;b"/></DIDL-Lite>"#,
+            desired_first_track_number_enqueued: 0,
+            enqueue_as_next: false,
+        };
+        k9::snapshot!(
+            instant_xml::to_string(&request).unwrap(),
+            r#"<AddURIToQueue xmlns="urn:schemas-upnp-org:service:AVTransport:1"><InstanceID xmlns="">0</InstanceID><EnqueuedURI xmlns="">x-sonos-spotify:track</EnqueuedURI><EnqueuedURIMetaData xmlns="">&lt;DIDL-Lite&gt;&lt;item id=&quot;a&amp;amp;b&quot;/&gt;&lt;/DIDL-Lite&gt;</EnqueuedURIMetaData><DesiredFirstTrackNumberEnqueued xmlns="">0</DesiredFirstTrackNumberEnqueued><EnqueueAsNext xmlns="">false</EnqueueAsNext></AddURIToQueue>"#
+        );
+    }
+
     #[test]
     fn test_soap_envelope() {
         use crate::av_transport::StopRequest;
@@ -506,4 +2387,225 @@ mod test {
             r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><Stop xmlns="urn:schemas-upnp-org:service:AVTransport:1"><InstanceID xmlns="">0</InstanceID></Stop></s:Body></s:Envelope>"#
         );
     }
+
+    #[test]
+    fn test_from_spec() {
+        let spec = DeviceSpec::parse_xml(include_str!("../data/device_spec.xml")).unwrap();
+        let url: Url = "http://10.10.10.10:1400/xml/device_description.xml"
+            .parse()
+            .unwrap();
+        let device = SonosDevice::from_spec(url, spec.clone()).unwrap();
+        k9::assert_equal!(device.device_spec().friendly_name, spec.friendly_name);
+        k9::assert_equal!(device.base_url().as_str(), "http://10.10.10.10:1400/");
+        k9::assert_equal!(
+            device.device_spec().largest_icon(),
+            Some(&Icon {
+                mimetype: "image/png".to_string(),
+                width: 48,
+                height: 48,
+                depth: Some(24),
+                url: "/img/icon-S23.png".to_string(),
+            })
+        );
+        k9::assert_equal!(
+            device.resolve_url("/getaa?s=1&u=x").unwrap().as_str(),
+            "http://10.10.10.10:1400/getaa?s=1&u=x"
+        );
+        k9::assert_equal!(
+            device
+                .resolve_url("https://example.com/art.jpg")
+                .unwrap()
+                .as_str(),
+            "https://example.com/art.jpg"
+        );
+        assert!(matches!(
+            device.resolve_url("http://[bad"),
+            Err(Error::InvalidUri(_))
+        ));
+        assert!(device
+            .device_spec()
+            .get_service(av_transport::SERVICE_TYPE)
+            .is_some());
+
+        let services = device.supported_services();
+        assert!(services.contains(&KnownService::AVTransport));
+        assert!(services.contains(&KnownService::ZoneGroupTopology));
+        k9::assert_equal!(
+            KnownService::from_service_type(rendering_control::SERVICE_TYPE),
+            Some(KnownService::RenderingControl)
+        );
+        k9::assert_equal!(
+            KnownService::from_service_type("urn:example:service:Nope:1"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_not_implemented() {
+        use crate::av_transport::GetPositionInfoResponse;
+
+        let body = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:GetPositionInfoResponse xmlns:u="urn:schemas-upnp-org:service:AVTransport:1"><Track>1</Track><TrackDuration>0:00:00</TrackDuration><TrackMetaData>NOT_IMPLEMENTED</TrackMetaData><TrackURI>NOT_IMPLEMENTED</TrackURI><RelTime>0:00:00</RelTime><AbsTime>NOT_IMPLEMENTED</AbsTime><RelCount>2147483647</RelCount><AbsCount>2147483647</AbsCount></u:GetPositionInfoResponse></s:Body></s:Envelope>"#;
+
+        let response =
+            GetPositionInfoResponse::decode_soap_xml(&xmlutil::strip_not_implemented(body))
+                .unwrap();
+        k9::snapshot!(
+            response,
+            r#"
+GetPositionInfoResponse {
+    track: Some(
+        1,
+    ),
+    track_duration: Some(
+        "0:00:00",
+    ),
+    track_meta_data: None,
+    track_uri: None,
+    rel_time: Some(
+        "0:00:00",
+    ),
+    abs_time: None,
+    rel_count: Some(
+        2147483647,
+    ),
+    abs_count: Some(
+        2147483647,
+    ),
+}
+"#
+        );
+    }
+
+    #[test]
+    fn test_qplay_auth() {
+        use crate::q_play::{QPlayAuthRequest, QPlayAuthResponse};
+
+        k9::snapshot!(
+            instant_xml::to_string(&QPlayAuthRequest {
+                seed: "1234".to_string()
+            })
+            .unwrap(),
+            r#"<QPlayAuth xmlns="urn:schemas-tencent-com:service:QPlay:1"><Seed xmlns="">1234</Seed></QPlayAuth>"#
+        );
+
+        let response = QPlayAuthResponse::decode_soap_xml(
+            r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:QPlayAuthResponse xmlns:u="urn:schemas-tencent-com:service:QPlay:1"><Code>abcdef</Code><MID>62900058</MID><DID>sonos</DID></u:QPlayAuthResponse></s:Body></s:Envelope>"#,
+        )
+        .unwrap();
+        k9::snapshot!(
+            response,
+            r#"
+QPlayAuthResponse {
+    code: Some(
+        "abcdef",
+    ),
+    mid: Some(
+        "62900058",
+    ),
+    did: Some(
+        "sonos",
+    ),
+}
+"#
+        );
+    }
+
+    #[tokio::test]
+    async fn test_unsupported_action() {
+        let mut spec = DeviceSpec::parse_xml(include_str!("../data/device_spec.xml")).unwrap();
+        // Pretend to be a Play:1, which doesn't have HT forwarding
+        spec.model_number = Some("S1".to_string());
+        let device = SonosDevice::from_spec(
+            "http://127.0.0.1:1/xml/device_description.xml"
+                .parse()
+                .unwrap(),
+            spec,
+        )
+        .unwrap();
+
+        k9::assert_equal!(
+            device.supports_action(device_properties::SERVICE_TYPE, "GetHTForwardState"),
+            Some(false)
+        );
+        k9::assert_equal!(
+            device.supports_action(device_properties::SERVICE_TYPE, "GetZoneInfo"),
+            Some(true)
+        );
+        k9::assert_equal!(
+            device.supports_action(device_properties::SERVICE_TYPE, "Bogus"),
+            None
+        );
+
+        match <SonosDevice as DeviceProperties>::get_ht_forward_state(&device).await {
+            Err(Error::UnsupportedAction { service, action }) => {
+                k9::assert_equal!(service, device_properties::SERVICE_TYPE);
+                k9::assert_equal!(action, "GetHTForwardState");
+            }
+            other => panic!("expected UnsupportedAction, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_response_decode_error() {
+        let body = format!("<s:Envelope>{}", "x".repeat(RESPONSE_DECODE_BODY_LIMIT));
+        match av_transport::GetPositionInfoResponse::decode_soap_xml(&body) {
+            Err(Error::ResponseDecode {
+                type_name, body, ..
+            }) => {
+                k9::assert_equal!(type_name, "av_transport::GetPositionInfoResponse");
+                k9::assert_equal!(body.len(), RESPONSE_DECODE_BODY_LIMIT);
+                assert!(body.starts_with("<s:Envelope>"));
+            }
+            other => panic!("unexpected result {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_error_status_helpers() {
+        let fault = Error::FailedRequest {
+            status: StatusCode::INTERNAL_SERVER_ERROR,
+            body: r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><s:Fault><faultcode>s:Client</faultcode><faultstring>UPnPError</faultstring><detail><UPnPError xmlns="urn:schemas-upnp-org:control-1-0"><errorCode>701</errorCode></UPnPError></detail></s:Fault></s:Body></s:Envelope>"#.to_string(),
+            headers: Default::default(),
+        };
+        k9::assert_equal!(fault.upnp_error_code(), Some(701));
+        assert!(!fault.is_redirect());
+        assert!(!fault.is_subscription_precondition_failed());
+
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert(
+            reqwest::header::LOCATION,
+            "https://10.10.10.10:1443/xml/device_description.xml"
+                .parse()
+                .unwrap(),
+        );
+        let redirect = Error::FailedRequest {
+            status: StatusCode::TEMPORARY_REDIRECT,
+            body: String::new(),
+            headers,
+        };
+        assert!(redirect.is_redirect());
+        k9::assert_equal!(
+            redirect.redirect_location(),
+            Some("https://10.10.10.10:1443/xml/device_description.xml")
+        );
+        k9::assert_equal!(redirect.upnp_error_code(), None);
+
+        let precondition = Error::FailedRequest {
+            status: StatusCode::PRECONDITION_FAILED,
+            body: String::new(),
+            headers: Default::default(),
+        };
+        assert!(precondition.is_subscription_precondition_failed());
+        k9::assert_equal!(Error::NoName.status(), None);
+
+        let not_found = Error::RoomNotFound {
+            room_name: "Kitchn".to_string(),
+            found: vec!["Kitchen".to_string(), "Study".to_string()],
+            errors: vec![],
+        };
+        k9::snapshot!(
+            not_found.to_string(),
+            "Room Kitchn not found. Found rooms: Kitchen, Study"
+        );
+    }
 }
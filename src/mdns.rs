@@ -0,0 +1,205 @@
+use crate::{Result, SonosDevice};
+use std::collections::HashSet;
+use std::net::{IpAddr, Ipv4Addr};
+use std::time::Duration;
+use tokio::net::UdpSocket;
+use tokio::sync::mpsc::{channel, Receiver};
+
+/// The DNS-SD service type that Sonos devices advertise via mDNS
+pub const SONOS_MDNS_SERVICE: &str = "_sonos._tcp.local";
+
+const MDNS_ADDR: (Ipv4Addr, u16) = (Ipv4Addr::new(224, 0, 0, 251), 5353);
+const TYPE_PTR: u16 = 12;
+const CLASS_IN: u16 = 1;
+/// Requests a unicast response to our ephemeral port
+const UNICAST_RESPONSE: u16 = 0x8000;
+
+/// Discover SonosDevices on the network using mDNS (Bonjour),
+/// stopping once the specified timeout is reached.
+/// This is an alternative to `discover` for use on networks where
+/// SSDP multicast is blocked.
+/// Returns a channel that will yield `SonosDevice` instances as responses
+/// to the query are detected.  Unlike `discover`, each responding
+/// address is yielded at most once.
+pub async fn discover_mdns(timeout: Duration) -> Result<Receiver<SonosDevice>> {
+    let socket = UdpSocket::bind("0.0.0.0:0").await?;
+    socket.set_multicast_ttl_v4(255).ok();
+    socket
+        .send_to(&build_query(SONOS_MDNS_SERVICE), MDNS_ADDR)
+        .await?;
+
+    let deadline = tokio::time::Instant::now() + timeout;
+
+    let (tx, rx) = channel(8);
+
+    tokio::spawn(async move {
+        let mut buf = [0u8; 9000];
+        let mut seen = HashSet::new();
+
+        loop {
+            let received = tokio::select! {
+                received = tokio::time::timeout_at(deadline, socket.recv_from(&mut buf)) => received,
+                _ = tx.closed() => {
+                    log::trace!("mDNS discovery receiver was dropped");
+                    break;
+                }
+            };
+            match received {
+                Ok(Ok((n_read, peer))) => {
+                    let packet = &buf[0..n_read];
+                    if !is_sonos_response(packet) {
+                        continue;
+                    }
+                    // The responder is the device itself, so rather than
+                    // chasing SRV and A records we use its source address
+                    let IpAddr::V4(addr) = peer.ip() else {
+                        continue;
+                    };
+                    if !seen.insert(addr) {
+                        continue;
+                    }
+                    log::trace!("mDNS: response from {peer:?}");
+                    match SonosDevice::from_ip(addr).await {
+                        Ok(device) => {
+                            if tx.send(device).await.is_err() {
+                                break;
+                            }
+                        }
+                        Err(err) => {
+                            log::trace!("mDNS: {addr} is not a usable device: {err:#}");
+                        }
+                    }
+                }
+                Ok(Err(err)) => {
+                    log::error!("{err:#}");
+                    break;
+                }
+                Err(_) => break,
+            }
+        }
+    });
+
+    Ok(rx)
+}
+
+/// Builds a DNS query packet asking for PTR records for `service`
+fn build_query(service: &str) -> Vec<u8> {
+    let mut packet = vec![
+        0, 0, // ID
+        0, 0, // Flags: standard query
+        0, 1, // QDCOUNT
+        0, 0, // ANCOUNT
+        0, 0, // NSCOUNT
+        0, 0, // ARCOUNT
+    ];
+    for label in service.split('.') {
+        packet.push(label.len() as u8);
+        packet.extend_from_slice(label.as_bytes());
+    }
+    packet.push(0);
+    packet.extend_from_slice(&TYPE_PTR.to_be_bytes());
+    packet.extend_from_slice(&(CLASS_IN | UNICAST_RESPONSE).to_be_bytes());
+    packet
+}
+
+/// Returns true if `packet` is a DNS response containing a record
+/// whose name is the Sonos service type
+fn is_sonos_response(packet: &[u8]) -> bool {
+    fn u16_at(packet: &[u8], offset: usize) -> Option<u16> {
+        Some(u16::from_be_bytes([
+            *packet.get(offset)?,
+            *packet.get(offset + 1)?,
+        ]))
+    }
+
+    fn check(packet: &[u8]) -> Option<bool> {
+        let flags = u16_at(packet, 2)?;
+        if flags & 0x8000 == 0 {
+            // It's a query, perhaps our own
+            return Some(false);
+        }
+        let questions = u16_at(packet, 4)?;
+        let records = u16_at(packet, 6)? as usize
+            + u16_at(packet, 8)? as usize
+            + u16_at(packet, 10)? as usize;
+
+        let mut offset = 12;
+        for _ in 0..questions {
+            let (_, next) = read_name(packet, offset)?;
+            offset = next + 4;
+        }
+        for _ in 0..records {
+            let (name, next) = read_name(packet, offset)?;
+            if name.eq_ignore_ascii_case(SONOS_MDNS_SERVICE) {
+                return Some(true);
+            }
+            let rdlength = u16_at(packet, next + 8)? as usize;
+            offset = next + 10 + rdlength;
+        }
+        Some(false)
+    }
+
+    check(packet).unwrap_or(false)
+}
+
+/// Reads a possibly-compressed DNS name starting at `offset`.
+/// Returns the dotted name and the offset immediately following
+/// it in the packet.
+fn read_name(packet: &[u8], mut offset: usize) -> Option<(String, usize)> {
+    let mut labels = vec![];
+    let mut end = None;
+    // Bound the number of pointers we follow so that a malicious
+    // packet cannot make us loop forever
+    let mut jumps = 0;
+
+    loop {
+        let len = *packet.get(offset)? as usize;
+        if len == 0 {
+            return Some((labels.join("."), end.unwrap_or(offset + 1)));
+        }
+        if len & 0xc0 == 0xc0 {
+            let pointer = ((len & 0x3f) << 8) | *packet.get(offset + 1)? as usize;
+            end.get_or_insert(offset + 2);
+            jumps += 1;
+            if jumps > 16 {
+                return None;
+            }
+            offset = pointer;
+            continue;
+        }
+        let label = packet.get(offset + 1..offset + 1 + len)?;
+        labels.push(String::from_utf8_lossy(label).into_owned());
+        offset += 1 + len;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_mdns_packets() {
+        let query = build_query(SONOS_MDNS_SERVICE);
+        k9::assert_equal!(
+            read_name(&query, 12).unwrap(),
+            (SONOS_MDNS_SERVICE.to_string(), query.len() - 4)
+        );
+        // Our own query is not a response
+        assert!(!is_sonos_response(&query));
+
+        // Turn the query into a response with a single PTR answer
+        // whose name is a compression pointer to the question
+        let mut response = query.clone();
+        response[2] = 0x84;
+        response[7] = 1;
+        response.extend_from_slice(&[0xc0, 12]);
+        response.extend_from_slice(&TYPE_PTR.to_be_bytes());
+        response.extend_from_slice(&CLASS_IN.to_be_bytes());
+        response.extend_from_slice(&[0, 0, 0x11, 0x94]);
+        response.extend_from_slice(&[0, 2, 0xc0, 12]);
+        assert!(is_sonos_response(&response));
+
+        // A truncated response is rejected rather than panicking
+        assert!(!is_sonos_response(&response[0..query.len()]));
+    }
+}
@@ -0,0 +1,276 @@
+use instant_xml::FromXml;
+
+const UPNP_SERVICE: &str = "urn:schemas-upnp-org:service-1-0";
+
+/// A parsed Service Control Protocol Description.
+/// This describes the actions and state variables that a service
+/// on a specific device actually supports, which may differ from
+/// the set of actions known to the generated code in this crate.
+/// Use `SonosDevice::fetch_scpd` to obtain one.
+#[derive(Debug, FromXml, Clone, PartialEq)]
+#[xml(rename = "scpd", ns(UPNP_SERVICE))]
+pub struct Scpd {
+    action_list: Option<ActionList>,
+    service_state_table: Option<ServiceStateTable>,
+}
+
+impl Scpd {
+    pub fn parse_xml(xml: &str) -> crate::Result<Self> {
+        instant_xml::from_str(xml).map_err(|error| crate::Error::XmlParse {
+            error,
+            text: xml.to_string(),
+        })
+    }
+
+    pub fn actions(&self) -> &[ScpdAction] {
+        match &self.action_list {
+            None => &[],
+            Some(list) => &list.actions,
+        }
+    }
+
+    pub fn state_variables(&self) -> &[ScpdStateVariable] {
+        match &self.service_state_table {
+            None => &[],
+            Some(table) => &table.state_variables,
+        }
+    }
+
+    pub fn get_action(&self, name: &str) -> Option<&ScpdAction> {
+        self.actions().iter().find(|a| a.name == name)
+    }
+
+    pub fn get_state_variable(&self, name: &str) -> Option<&ScpdStateVariable> {
+        self.state_variables().iter().find(|sv| sv.name == name)
+    }
+}
+
+#[derive(Debug, FromXml, Clone, PartialEq)]
+#[xml(rename = "actionList", ns(UPNP_SERVICE))]
+struct ActionList {
+    actions: Vec<ScpdAction>,
+}
+
+#[derive(Debug, FromXml, Clone, PartialEq)]
+#[xml(rename = "serviceStateTable", ns(UPNP_SERVICE))]
+struct ServiceStateTable {
+    state_variables: Vec<ScpdStateVariable>,
+}
+
+#[derive(Debug, FromXml, Clone, PartialEq)]
+#[xml(rename = "action", ns(UPNP_SERVICE))]
+pub struct ScpdAction {
+    pub name: String,
+    argument_list: Option<ArgumentList>,
+}
+
+impl ScpdAction {
+    pub fn arguments(&self) -> &[ScpdArgument] {
+        match &self.argument_list {
+            None => &[],
+            Some(list) => &list.arguments,
+        }
+    }
+}
+
+#[derive(Debug, FromXml, Clone, PartialEq)]
+#[xml(rename = "argumentList", ns(UPNP_SERVICE))]
+struct ArgumentList {
+    arguments: Vec<ScpdArgument>,
+}
+
+#[derive(Debug, FromXml, Clone, PartialEq)]
+#[xml(rename = "argument", ns(UPNP_SERVICE))]
+pub struct ScpdArgument {
+    pub name: String,
+    /// Either `in` or `out`
+    pub direction: String,
+    #[xml(rename = "relatedStateVariable")]
+    pub related_state_variable: String,
+}
+
+impl ScpdArgument {
+    pub fn is_input(&self) -> bool {
+        self.direction.eq_ignore_ascii_case("in")
+    }
+}
+
+#[derive(Debug, FromXml, Clone, PartialEq)]
+#[xml(rename = "stateVariable", ns(UPNP_SERVICE))]
+pub struct ScpdStateVariable {
+    #[xml(rename = "sendEvents", attribute)]
+    send_events: Option<String>,
+    pub name: String,
+    #[xml(rename = "dataType")]
+    pub data_type: String,
+    #[xml(rename = "defaultValue")]
+    pub default_value: Option<String>,
+    allowed_value_list: Option<AllowedValueList>,
+    #[xml(rename = "allowedValueRange")]
+    pub allowed_value_range: Option<AllowedValueRange>,
+}
+
+impl ScpdStateVariable {
+    /// Returns true if changes to this variable are reported
+    /// via the event subscription mechanism
+    pub fn sends_events(&self) -> bool {
+        self.send_events.as_deref() == Some("yes")
+    }
+
+    pub fn allowed_values(&self) -> &[String] {
+        match &self.allowed_value_list {
+            None => &[],
+            Some(list) => &list.values,
+        }
+    }
+}
+
+#[derive(Debug, FromXml, Clone, PartialEq)]
+#[xml(rename = "allowedValueList", ns(UPNP_SERVICE))]
+struct AllowedValueList {
+    #[xml(rename = "allowedValue")]
+    values: Vec<String>,
+}
+
+#[derive(Debug, FromXml, Clone, PartialEq)]
+#[xml(rename = "allowedValueRange", ns(UPNP_SERVICE))]
+pub struct AllowedValueRange {
+    pub minimum: String,
+    pub maximum: String,
+    pub step: Option<String>,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_scpd() {
+        let scpd = Scpd::parse_xml(
+            r#"<?xml version="1.0" encoding="utf-8" ?>
+<scpd xmlns="urn:schemas-upnp-org:service-1-0">
+  <specVersion><major>1</major><minor>0</minor></specVersion>
+  <serviceStateTable>
+    <stateVariable sendEvents="no">
+      <name>Mute</name>
+      <dataType>boolean</dataType>
+    </stateVariable>
+    <stateVariable sendEvents="no">
+      <name>Volume</name>
+      <dataType>ui2</dataType>
+      <allowedValueRange>
+        <minimum>0</minimum>
+        <maximum>100</maximum>
+        <step>1</step>
+      </allowedValueRange>
+    </stateVariable>
+    <stateVariable sendEvents="no">
+      <name>A_ARG_TYPE_Channel</name>
+      <dataType>string</dataType>
+      <allowedValueList>
+        <allowedValue>Master</allowedValue>
+        <allowedValue>LF</allowedValue>
+        <allowedValue>RF</allowedValue>
+      </allowedValueList>
+    </stateVariable>
+    <stateVariable sendEvents="yes">
+      <name>LastChange</name>
+      <dataType>string</dataType>
+    </stateVariable>
+  </serviceStateTable>
+  <actionList>
+    <action>
+      <name>GetVolume</name>
+      <argumentList>
+        <argument>
+          <name>Channel</name>
+          <direction>in</direction>
+          <relatedStateVariable>A_ARG_TYPE_Channel</relatedStateVariable>
+        </argument>
+        <argument>
+          <name>CurrentVolume</name>
+          <direction>out</direction>
+          <relatedStateVariable>Volume</relatedStateVariable>
+        </argument>
+      </argumentList>
+    </action>
+    <action>
+      <name>ResetBasicEQ</name>
+    </action>
+  </actionList>
+</scpd>"#,
+        )
+        .unwrap();
+
+        let actions: Vec<(&str, Vec<(&str, bool)>)> = scpd
+            .actions()
+            .iter()
+            .map(|a| {
+                (
+                    a.name.as_str(),
+                    a.arguments()
+                        .iter()
+                        .map(|arg| (arg.name.as_str(), arg.is_input()))
+                        .collect(),
+                )
+            })
+            .collect();
+        k9::snapshot!(
+            actions,
+            r#"
+[
+    (
+        "GetVolume",
+        [
+            (
+                "Channel",
+                true,
+            ),
+            (
+                "CurrentVolume",
+                false,
+            ),
+        ],
+    ),
+    (
+        "ResetBasicEQ",
+        [],
+    ),
+]
+"#
+        );
+
+        let volume = scpd.get_state_variable("Volume").unwrap();
+        k9::snapshot!(
+            &volume.allowed_value_range,
+            r#"
+Some(
+    AllowedValueRange {
+        minimum: "0",
+        maximum: "100",
+        step: Some(
+            "1",
+        ),
+    },
+)
+"#
+        );
+        k9::snapshot!(
+            scpd.get_state_variable("A_ARG_TYPE_Channel")
+                .unwrap()
+                .allowed_values(),
+            r#"
+[
+    "Master",
+    "LF",
+    "RF",
+]
+"#
+        );
+        assert!(scpd
+            .get_state_variable("LastChange")
+            .unwrap()
+            .sends_events());
+        assert!(!volume.sends_events());
+    }
+}
@@ -0,0 +1,602 @@
+use crate::av_transport::{
+    AVTransportLastChange, AddMultipleUrisToQueueResponse, AddUriToQueueResponse,
+    GetPositionInfoResponse, GetRunningAlarmPropertiesResponse,
+};
+use crate::upnp::DecodeXml;
+use crate::{try_hms_to_duration, CurrentPlayMode, Error, SonosUri, TrackMetaData, TransportState};
+use std::time::Duration;
+
+/// The set of play modes that are valid for the current source,
+/// as reported by the `CurrentValidPlayModes` AVTransport state
+/// variable.  For example, shuffle and repeat are not valid
+/// when playing a radio stream.
+/// Tokens that are not known to `CurrentPlayMode` (such as `REPEAT`
+/// or `CROSSFADE`) are represented as `CurrentPlayMode::Unspecified`.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ValidPlayModes {
+    pub modes: Vec<CurrentPlayMode>,
+}
+
+impl ValidPlayModes {
+    pub fn contains(&self, mode: &CurrentPlayMode) -> bool {
+        self.modes.contains(mode)
+    }
+}
+
+impl DecodeXml for ValidPlayModes {
+    fn decode_xml(xml: &str) -> crate::Result<Self> {
+        let modes = xml
+            .split(',')
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .map(str::parse)
+            .collect::<crate::Result<Vec<CurrentPlayMode>>>()?;
+        Ok(Self { modes })
+    }
+}
+
+impl AVTransportLastChange {
+    /// Returns true if the event reports that another app, such as
+    /// a phone casting via Spotify Connect, has taken direct control
+    /// of the device and that session has not been suspended.
+    /// Note that events after the initial event only carry the
+    /// fields that changed, so this is only meaningful for an event
+    /// that includes `direct_control_client_id`.
+    pub fn is_under_direct_control(&self) -> bool {
+        let has_client = self
+            .direct_control_client_id
+            .as_deref()
+            .map(|id| !id.is_empty())
+            .unwrap_or(false);
+        has_client && self.direct_control_is_suspended != Some(true)
+    }
+}
+
+/// The playback position, as reported by the AVTransport
+/// GetPositionInfo action.
+/// Use `SonosDevice::position_info` to obtain this.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PositionInfo {
+    /// The 1-based track number in the queue
+    pub track: Option<u32>,
+    pub track_duration: Option<Duration>,
+    pub track_meta_data: Option<TrackMetaData>,
+    pub track_uri: Option<SonosUri>,
+    /// The position within the current track.
+    /// This and the other durations are None when the device doesn't
+    /// know them, as is the case for live streams, which is distinct
+    /// from being at the start of the track.
+    pub rel_time: Option<Duration>,
+    pub abs_time: Option<Duration>,
+    /// None when not applicable
+    pub rel_count: Option<i32>,
+    pub abs_count: Option<i32>,
+}
+
+impl From<GetPositionInfoResponse> for PositionInfo {
+    fn from(response: GetPositionInfoResponse) -> Self {
+        fn duration(hms: Option<String>) -> Option<Duration> {
+            hms.as_deref().and_then(try_hms_to_duration)
+        }
+        /// The counts are reported as i32::MAX when not applicable
+        fn count(count: Option<i32>) -> Option<i32> {
+            count.filter(|&count| count != i32::MAX)
+        }
+
+        Self {
+            track: response.track,
+            track_duration: duration(response.track_duration),
+            track_meta_data: response.track_meta_data.and_then(|m| m.into_inner()),
+            track_uri: response
+                .track_uri
+                .filter(|uri| !uri.is_empty())
+                .map(|uri| SonosUri::parse(&uri)),
+            rel_time: duration(response.rel_time),
+            abs_time: duration(response.abs_time),
+            rel_count: count(response.rel_count),
+            abs_count: count(response.abs_count),
+        }
+    }
+}
+
+/// A snapshot of what a device is currently playing, suitable for
+/// display on a dashboard.
+/// Use `SonosDevice::now_playing` to obtain this.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NowPlaying {
+    pub state: TransportState,
+    pub volume: u16,
+    pub mute: bool,
+    pub track: Option<TrackMetaData>,
+    /// The position within the current track
+    pub position: Duration,
+    /// The duration of the current track; zero for streams
+    pub duration: Duration,
+}
+
+/// Describes an alarm that is currently sounding.
+/// Use `SonosDevice::running_alarm` to obtain this.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RunningAlarm {
+    pub alarm_id: u32,
+    pub group_id: Option<String>,
+    /// The time at which the alarm started, as reported by the device
+    pub logged_start_time: Option<String>,
+}
+
+impl RunningAlarm {
+    /// Returns None if the response indicates that no alarm is running
+    pub fn from_response(response: GetRunningAlarmPropertiesResponse) -> Option<Self> {
+        let alarm_id = response.alarm_id.filter(|&id| id != 0)?;
+        Some(Self {
+            alarm_id,
+            group_id: response.group_id.filter(|id| !id.is_empty()),
+            logged_start_time: response.logged_start_time.filter(|t| !t.is_empty()),
+        })
+    }
+}
+
+/// Describes what a device is currently playing from, in terms
+/// of its relationship with other devices.
+/// Use `SonosDevice::current_grouping` to obtain this.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Grouping {
+    /// Playing from its own queue, so it is either ungrouped
+    /// or is the coordinator of its group
+    OwnQueue,
+    /// Grouped with, and following, the coordinator with the
+    /// specified uuid
+    FollowingCoordinator(String),
+    /// Playing from a line-in source
+    LineIn,
+    /// Playing from a TV/HDMI/optical source
+    Tv,
+    /// Playing some other URI, such as a radio stream
+    External(String),
+}
+
+impl Grouping {
+    /// Classifies an AVTransport URI
+    pub fn from_uri(uri: &str) -> Self {
+        match SonosUri::parse(uri) {
+            SonosUri::Queue { .. } => Self::OwnQueue,
+            SonosUri::Group { coordinator } => Self::FollowingCoordinator(coordinator),
+            SonosUri::LineIn { .. } => Self::LineIn,
+            SonosUri::HomeTheater { .. } => Self::Tv,
+            _ => Self::External(uri.to_string()),
+        }
+    }
+}
+
+/// A high level description of what a device is playing from.
+/// Use `SonosDevice::source` to obtain this.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    /// Playing from its own queue.  `track` is the 1-based number
+    /// of the current track, or 0 if the queue is empty
+    Queue { track: u32 },
+    /// Playing a radio station or other live stream.  `station` is
+    /// the title from the metadata, or the URI if it has none
+    Radio { station: String },
+    /// Playing from a line-in source
+    LineIn,
+    /// Playing from a TV/HDMI/optical source
+    Tv,
+    /// Grouped with, and following, the coordinator with the
+    /// specified uuid
+    GroupMember { coordinator_uuid: String },
+    /// Playing via Spotify Connect or a Spotify stream; `uri` is the
+    /// AVTransport URI
+    Spotify { uri: String },
+    /// Playing some other URI, or nothing at all
+    Unknown(String),
+}
+
+impl Source {
+    /// Classifies an AVTransport URI.  `title` is the title from
+    /// its metadata, and `track` is the current track number, which
+    /// is only used when playing from the queue
+    pub fn from_uri(uri: &str, title: Option<&str>, track: Option<u32>) -> Self {
+        let station = |fallback: &str| {
+            title
+                .filter(|title| !title.is_empty())
+                .unwrap_or(fallback)
+                .to_string()
+        };
+
+        if uri.starts_with("x-sonos-spotify:")
+            || (uri.starts_with("x-sonos-vli:") && uri.contains("spotify"))
+        {
+            return Self::Spotify {
+                uri: uri.to_string(),
+            };
+        }
+
+        match SonosUri::parse(uri) {
+            SonosUri::Queue { .. } => Self::Queue {
+                track: track.unwrap_or(0),
+            },
+            SonosUri::Group { coordinator } => Self::GroupMember {
+                coordinator_uuid: coordinator,
+            },
+            SonosUri::LineIn { .. } => Self::LineIn,
+            SonosUri::HomeTheater { .. } => Self::Tv,
+            SonosUri::Mp3Radio { .. } => Self::Radio {
+                station: station(uri),
+            },
+            SonosUri::SonosApi { kind, .. }
+                if matches!(kind.as_str(), "stream" | "radio" | "hls" | "hls-static") =>
+            {
+                Self::Radio {
+                    station: station(uri),
+                }
+            }
+            _ if uri.starts_with("aac:") || uri.starts_with("hls-radio:") => Self::Radio {
+                station: station(uri),
+            },
+            _ => Self::Unknown(uri.to_string()),
+        }
+    }
+}
+
+/// A command that is currently permitted by the AVTransport,
+/// as reported by `GetCurrentTransportActions`.
+/// Use `SonosDevice::current_transport_actions` to obtain these.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum TransportAction {
+    Play,
+    Stop,
+    Pause,
+    Next,
+    Previous,
+    Seek,
+    /// A token that is not otherwise known to this crate,
+    /// such as `X_DLNA_SeekTime`
+    Other(String),
+}
+
+impl TransportAction {
+    /// Parses a comma separated list of actions, such as
+    /// `Play,Stop,Pause,Next,Previous,Seek`
+    pub fn parse_list(actions: &str) -> Vec<Self> {
+        actions
+            .split(',')
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .map(Self::from)
+            .collect()
+    }
+
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Play => "Play",
+            Self::Stop => "Stop",
+            Self::Pause => "Pause",
+            Self::Next => "Next",
+            Self::Previous => "Previous",
+            Self::Seek => "Seek",
+            Self::Other(action) => action,
+        }
+    }
+}
+
+impl From<&str> for TransportAction {
+    fn from(action: &str) -> Self {
+        match action {
+            "Play" => Self::Play,
+            "Stop" => Self::Stop,
+            "Pause" => Self::Pause,
+            "Next" => Self::Next,
+            "Previous" => Self::Previous,
+            "Seek" => Self::Seek,
+            other => Self::Other(other.to_string()),
+        }
+    }
+}
+
+impl std::fmt::Display for TransportAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Describes where items were added to the queue.
+/// This can be obtained from the response of `queue_append`
+/// or `queue_prepend` via `QueueAddition::try_from`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QueueAddition {
+    /// The 1-based position in the queue of the first item that
+    /// was added; pass this to `seek_track` to play it
+    pub first_track_number: u32,
+    pub num_tracks_added: u32,
+    pub new_queue_length: u32,
+}
+
+impl QueueAddition {
+    fn from_fields(
+        first_track_number: Option<u32>,
+        num_tracks_added: Option<u32>,
+        new_queue_length: Option<u32>,
+    ) -> crate::Result<Self> {
+        Ok(Self {
+            first_track_number: first_track_number
+                .ok_or(Error::MissingResponseField("FirstTrackNumberEnqueued"))?,
+            num_tracks_added: num_tracks_added
+                .ok_or(Error::MissingResponseField("NumTracksAdded"))?,
+            new_queue_length: new_queue_length
+                .ok_or(Error::MissingResponseField("NewQueueLength"))?,
+        })
+    }
+}
+
+impl TryFrom<AddUriToQueueResponse> for QueueAddition {
+    type Error = Error;
+    fn try_from(response: AddUriToQueueResponse) -> crate::Result<Self> {
+        Self::from_fields(
+            response.first_track_number_enqueued,
+            response.num_tracks_added,
+            response.new_queue_length,
+        )
+    }
+}
+
+impl TryFrom<AddMultipleUrisToQueueResponse> for QueueAddition {
+    type Error = Error;
+    fn try_from(response: AddMultipleUrisToQueueResponse) -> crate::Result<Self> {
+        Self::from_fields(
+            response.first_track_number_enqueued,
+            response.num_tracks_added,
+            response.new_queue_length,
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::av_transport::AVTransportLastChangeMap;
+
+    #[test]
+    fn test_valid_play_modes() {
+        let last_change = AVTransportLastChangeMap::decode_xml(
+            r#"<Event xmlns="urn:schemas-upnp-org:metadata-1-0/AVT/" xmlns:r="urn:schemas-rinconnetworks-com:metadata-1-0/"><InstanceID val="0"><TransportState val="PLAYING"/><CurrentPlayMode val="NORMAL"/><r:CurrentValidPlayModes val="SHUFFLE,REPEAT,SHUFFLE_REPEAT_ONE,REPEAT_ONE,CROSSFADE"/></InstanceID></Event>"#,
+        )
+        .unwrap();
+
+        let modes = last_change.map[&0]
+            .current_valid_play_modes
+            .clone()
+            .unwrap()
+            .into_inner()
+            .unwrap();
+        k9::snapshot!(
+            &modes,
+            r#"
+ValidPlayModes {
+    modes: [
+        Shuffle,
+        Unspecified(
+            "REPEAT",
+        ),
+        ShuffleRepeatOne,
+        RepeatOne,
+        Unspecified(
+            "CROSSFADE",
+        ),
+    ],
+}
+"#
+        );
+        assert!(modes.contains(&CurrentPlayMode::Shuffle));
+        assert!(!modes.contains(&CurrentPlayMode::ShuffleNorepeat));
+    }
+
+    #[test]
+    fn test_transport_actions() {
+        let actions = TransportAction::parse_list("Set, Stop,Pause,Play,X_DLNA_SeekTime,,Next");
+        k9::snapshot!(
+            &actions,
+            r#"
+[
+    Other(
+        "Set",
+    ),
+    Stop,
+    Pause,
+    Play,
+    Other(
+        "X_DLNA_SeekTime",
+    ),
+    Next,
+]
+"#
+        );
+        k9::assert_equal!(actions[1].to_string(), "Stop");
+        assert!(!actions.contains(&TransportAction::Previous));
+        k9::assert_equal!(TransportAction::parse_list(""), vec![]);
+    }
+
+    #[test]
+    fn test_grouping() {
+        k9::assert_equal!(
+            Grouping::from_uri("x-rincon-queue:RINCON_000E58A0123401400#0"),
+            Grouping::OwnQueue
+        );
+        k9::assert_equal!(
+            Grouping::from_uri("x-rincon:RINCON_000E58A0123401400"),
+            Grouping::FollowingCoordinator("RINCON_000E58A0123401400".to_string())
+        );
+        k9::assert_equal!(
+            Grouping::from_uri("x-rincon-stream:RINCON_000E58A0123401400"),
+            Grouping::LineIn
+        );
+        k9::assert_equal!(
+            Grouping::from_uri("x-sonos-htastream:RINCON_000E58A0123401400:spdif"),
+            Grouping::Tv
+        );
+        k9::assert_equal!(
+            Grouping::from_uri("x-rincon-mp3radio://example.com/stream"),
+            Grouping::External("x-rincon-mp3radio://example.com/stream".to_string())
+        );
+    }
+
+    #[test]
+    fn test_source() {
+        k9::assert_equal!(
+            Source::from_uri("x-rincon-queue:RINCON_000E58A0123401400#0", None, Some(3)),
+            Source::Queue { track: 3 }
+        );
+        k9::assert_equal!(
+            Source::from_uri("x-rincon:RINCON_000E58A0123401400", None, None),
+            Source::GroupMember {
+                coordinator_uuid: "RINCON_000E58A0123401400".to_string()
+            }
+        );
+        k9::assert_equal!(
+            Source::from_uri("x-rincon-stream:RINCON_000E58A0123401400", None, None),
+            Source::LineIn
+        );
+        k9::assert_equal!(
+            Source::from_uri(
+                "x-sonos-htastream:RINCON_000E58A0123401400:spdif",
+                None,
+                None
+            ),
+            Source::Tv
+        );
+        k9::assert_equal!(
+            Source::from_uri(
+                "x-sonosapi-stream:s12345?sid=254&flags=8224&sn=0",
+                Some("Radio Paradise"),
+                None
+            ),
+            Source::Radio {
+                station: "Radio Paradise".to_string()
+            }
+        );
+        k9::assert_equal!(
+            Source::from_uri("x-rincon-mp3radio://example.com/stream", Some(""), None),
+            Source::Radio {
+                station: "x-rincon-mp3radio://example.com/stream".to_string()
+            }
+        );
+        k9::assert_equal!(
+            Source::from_uri(
+                "x-sonos-vli:RINCON_000E58A0123401400:2,spotify:abc",
+                None,
+                None
+            ),
+            Source::Spotify {
+                uri: "x-sonos-vli:RINCON_000E58A0123401400:2,spotify:abc".to_string()
+            }
+        );
+        k9::assert_equal!(
+            Source::from_uri("", None, None),
+            Source::Unknown(String::new())
+        );
+    }
+
+    #[test]
+    fn test_queue_addition() {
+        use crate::DecodeSoapResponse;
+
+        let response = AddUriToQueueResponse::decode_soap_xml(
+            r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:AddURIToQueueResponse xmlns:u="urn:schemas-upnp-org:service:AVTransport:1"><FirstTrackNumberEnqueued>7</FirstTrackNumberEnqueued><NumTracksAdded>1</NumTracksAdded><NewQueueLength>7</NewQueueLength></u:AddURIToQueueResponse></s:Body></s:Envelope>"#,
+        )
+        .unwrap();
+        k9::assert_equal!(
+            QueueAddition::try_from(response).unwrap(),
+            QueueAddition {
+                first_track_number: 7,
+                num_tracks_added: 1,
+                new_queue_length: 7,
+            }
+        );
+    }
+
+    #[test]
+    fn test_direct_control() {
+        let decode = |fields: &str| {
+            let mut last_change = AVTransportLastChangeMap::decode_xml(&format!(
+                r#"<Event xmlns="urn:schemas-upnp-org:metadata-1-0/AVT/" xmlns:r="urn:schemas-rinconnetworks-com:metadata-1-0/"><InstanceID val="0"><TransportState val="PLAYING"/>{fields}</InstanceID></Event>"#
+            ))
+            .unwrap();
+            last_change.map.remove(&0).unwrap()
+        };
+
+        assert!(!decode(r#"<r:DirectControlClientID val=""/>"#).is_under_direct_control());
+        assert!(decode(
+            r#"<r:DirectControlClientID val="com.spotify.connect"/><r:DirectControlIsSuspended val="0"/><r:DirectControlAccountID val="acct"/>"#
+        )
+        .is_under_direct_control());
+        assert!(!decode(
+            r#"<r:DirectControlClientID val="com.spotify.connect"/><r:DirectControlIsSuspended val="1"/>"#
+        )
+        .is_under_direct_control());
+    }
+
+    #[test]
+    fn test_running_alarm() {
+        use crate::DecodeSoapResponse;
+
+        let decode = |body: &str| {
+            GetRunningAlarmPropertiesResponse::decode_soap_xml(&format!(
+                r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:GetRunningAlarmPropertiesResponse xmlns:u="urn:schemas-upnp-org:service:AVTransport:1">{body}</u:GetRunningAlarmPropertiesResponse></s:Body></s:Envelope>"#
+            ))
+            .unwrap()
+        };
+
+        k9::assert_equal!(
+            RunningAlarm::from_response(decode(
+                "<AlarmID>0</AlarmID><GroupID></GroupID><LoggedStartTime></LoggedStartTime>"
+            )),
+            None
+        );
+        k9::assert_equal!(
+            RunningAlarm::from_response(decode(
+                "<AlarmID>12</AlarmID><GroupID>RINCON_000E58A0123401400:123</GroupID><LoggedStartTime>2024-01-02 07:00:00</LoggedStartTime>"
+            )),
+            Some(RunningAlarm {
+                alarm_id: 12,
+                group_id: Some("RINCON_000E58A0123401400:123".to_string()),
+                logged_start_time: Some("2024-01-02 07:00:00".to_string()),
+            })
+        );
+    }
+
+    #[test]
+    fn test_position_info() {
+        use crate::DecodeSoapResponse;
+
+        let response = GetPositionInfoResponse::decode_soap_xml(&crate::xmlutil::strip_not_implemented(
+            r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:GetPositionInfoResponse xmlns:u="urn:schemas-upnp-org:service:AVTransport:1"><Track>3</Track><TrackDuration>0:03:25</TrackDuration><TrackMetaData></TrackMetaData><TrackURI>x-sonosapi-hls:catalog%3astation?sid=204</TrackURI><RelTime>0:01:02</RelTime><AbsTime>NOT_IMPLEMENTED</AbsTime><RelCount>2147483647</RelCount><AbsCount>2147483647</AbsCount></u:GetPositionInfoResponse></s:Body></s:Envelope>"#,
+        ))
+        .unwrap();
+        k9::snapshot!(
+            PositionInfo::from(response),
+            r#"
+PositionInfo {
+    track: Some(
+        3,
+    ),
+    track_duration: Some(
+        205s,
+    ),
+    track_meta_data: None,
+    track_uri: Some(
+        SonosApi {
+            kind: "hls",
+            path: "catalog%3astation?sid=204",
+        },
+    ),
+    rel_time: Some(
+        62s,
+    ),
+    abs_time: None,
+    rel_count: None,
+    abs_count: None,
+}
+"#
+        );
+    }
+}
@@ -0,0 +1,183 @@
+use crate::{Error, Result};
+use std::str::FromStr;
+
+macro_rules! ranged_newtype {
+    ($(#[$meta:meta])* $name:ident, $inner:ty, $min:expr, $max:expr) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub struct $name($inner);
+
+        impl $name {
+            pub const MIN: $name = $name($min);
+            pub const MAX: $name = $name($max);
+
+            /// Constructs a new value, returning `Error::ValueOutOfRange`
+            /// if it is outside of the range accepted by the device
+            pub fn new(value: $inner) -> Result<Self> {
+                if ($min..=$max).contains(&value) {
+                    Ok(Self(value))
+                } else {
+                    Err(Error::ValueOutOfRange {
+                        name: stringify!($name),
+                        value: value.into(),
+                        min: $min,
+                        max: $max,
+                    })
+                }
+            }
+
+            /// Constructs a new value, clamping it to the range
+            /// accepted by the device
+            pub fn saturating(value: $inner) -> Self {
+                Self(value.clamp($min, $max))
+            }
+
+            pub fn value(self) -> $inner {
+                self.0
+            }
+        }
+
+        impl TryFrom<$inner> for $name {
+            type Error = Error;
+            fn try_from(value: $inner) -> Result<Self> {
+                Self::new(value)
+            }
+        }
+
+        impl From<$name> for $inner {
+            fn from(value: $name) -> $inner {
+                value.0
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                self.0.fmt(f)
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = Error;
+            fn from_str(s: &str) -> Result<Self> {
+                let value: $inner = s.parse().map_err(|_| Error::InvalidNumber {
+                    name: stringify!($name),
+                    text: s.to_string(),
+                })?;
+                Self::new(value)
+            }
+        }
+    };
+}
+
+ranged_newtype!(
+    /// A volume level in the range 0-100
+    Volume,
+    u16,
+    0,
+    100
+);
+
+ranged_newtype!(
+    /// A bass EQ level in the range -10 to 10
+    Bass,
+    i16,
+    -10,
+    10
+);
+
+ranged_newtype!(
+    /// A treble EQ level in the range -10 to 10
+    Treble,
+    i16,
+    -10,
+    10
+);
+
+/// The EQ settings that can be read and changed via the RenderingControl
+/// `GetEQ` and `SetEQ` actions.  Most of these are only supported by
+/// home theater devices such as soundbars.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EqType {
+    /// Speech enhancement (bool)
+    DialogLevel,
+    /// -15 to 15
+    MusicSurroundLevel,
+    /// Night mode, which reduces the loudness of sudden sounds (bool)
+    NightMode,
+    /// -10 to 10
+    SubGain,
+    /// bool
+    SurroundEnable,
+    /// -15 to 15
+    SurroundLevel,
+    /// 0 = ambient, 1 = full
+    SurroundMode,
+    /// -10 to 10
+    HeightChannelLevel,
+}
+
+impl EqType {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::DialogLevel => "DialogLevel",
+            Self::MusicSurroundLevel => "MusicSurroundLevel",
+            Self::NightMode => "NightMode",
+            Self::SubGain => "SubGain",
+            Self::SurroundEnable => "SurroundEnable",
+            Self::SurroundLevel => "SurroundLevel",
+            Self::SurroundMode => "SurroundMode",
+            Self::HeightChannelLevel => "HeightChannelLevel",
+        }
+    }
+}
+
+impl std::fmt::Display for EqType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for EqType {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self> {
+        Ok(match s {
+            "DialogLevel" => Self::DialogLevel,
+            "MusicSurroundLevel" => Self::MusicSurroundLevel,
+            "NightMode" => Self::NightMode,
+            "SubGain" => Self::SubGain,
+            "SurroundEnable" => Self::SurroundEnable,
+            "SurroundLevel" => Self::SurroundLevel,
+            "SurroundMode" => Self::SurroundMode,
+            "HeightChannelLevel" => Self::HeightChannelLevel,
+            _ => return Err(Error::InvalidEnumVariantValue),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_ranges() {
+        k9::assert_equal!(Volume::new(42).unwrap().value(), 42);
+        k9::assert_equal!(Volume::saturating(250), Volume::MAX);
+        k9::assert_equal!(Bass::saturating(-20), Bass::MIN);
+        k9::assert_equal!("-3".parse::<Treble>().unwrap().to_string(), "-3");
+        k9::snapshot!(
+            Volume::new(101).unwrap_err().to_string(),
+            "Volume value 101 is outside the valid range 0..=100"
+        );
+        assert!(matches!(
+            "11".parse::<Bass>(),
+            Err(Error::ValueOutOfRange { name: "Bass", .. })
+        ));
+        assert!(matches!(
+            "loud".parse::<Volume>(),
+            Err(Error::InvalidNumber { name: "Volume", text }) if text == "loud"
+        ));
+        k9::assert_equal!("NightMode".parse::<EqType>().unwrap(), EqType::NightMode);
+        k9::assert_equal!(EqType::DialogLevel.to_string(), "DialogLevel");
+        assert!("Loudness".parse::<EqType>().is_err());
+    }
+}
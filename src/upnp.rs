@@ -1,14 +1,19 @@
 use crate::Error;
 use instant_xml::FromXml;
 use reqwest::{Method, Response, Url};
-use std::net::IpAddr;
-use tokio::io::AsyncReadExt;
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::net::{IpAddr, SocketAddr};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
 use tokio::net::{TcpListener, TcpStream};
 use tokio::sync::mpsc::{channel, Receiver, Sender};
 use url::Host;
 
 const UPNP_DEVICE: &str = "urn:schemas-upnp-org:device-1-0";
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[derive(Debug, FromXml, Clone)]
 #[xml(rename = "device", ns(UPNP_DEVICE))]
 pub struct DeviceSpec {
@@ -24,7 +29,16 @@ pub struct DeviceSpec {
     pub model_name: Option<String>,
     #[xml(rename = "SSLPort")]
     pub ssl_port: Option<u16>,
+    /// The Unique Device Name, which is of the form `uuid:RINCON_XXX`
+    #[xml(rename = "UDN")]
+    pub udn: Option<String>,
+    /// The room name as of the time the description was fetched.
+    /// This avoids a round trip to the device, but `SonosDevice::name`
+    /// is authoritative.
+    #[xml(rename = "roomName")]
+    pub room_name: Option<String>,
 
+    icon_list: Option<IconList>,
     service_list: Option<ServiceList>,
     device_list: Option<DeviceList>,
 }
@@ -38,6 +52,35 @@ impl DeviceSpec {
         Ok(spec.device)
     }
 
+    /// Splits the `friendly_name` into its component parts.
+    /// See `FriendlyName` for more details.
+    pub fn parsed_friendly_name(&self) -> FriendlyName {
+        FriendlyName::parse(&self.friendly_name)
+    }
+
+    /// Returns the device uuid, such as `RINCON_XXX`, from the `udn`
+    pub fn uuid(&self) -> Option<&str> {
+        let udn = self.udn.as_deref()?;
+        Some(udn.strip_prefix("uuid:").unwrap_or(udn))
+    }
+
+    /// Returns the icons advertised by the device.
+    /// Their `url`s are relative; use `SonosDevice::resolve_url`
+    /// to obtain a URL that can be fetched.
+    pub fn icons(&self) -> &[Icon] {
+        match &self.icon_list {
+            None => &[],
+            Some(list) => &list.icons,
+        }
+    }
+
+    /// Returns the icon with the most pixels, if any
+    pub fn largest_icon(&self) -> Option<&Icon> {
+        self.icons()
+            .iter()
+            .max_by_key(|icon| u64::from(icon.width) * u64::from(icon.height))
+    }
+
     pub fn services(&self) -> &[Service] {
         match &self.service_list {
             None => &[],
@@ -65,12 +108,96 @@ impl DeviceSpec {
     }
 }
 
+/// The structured form of a `DeviceSpec::friendly_name`.
+/// Sonos devices name themselves using the pattern
+/// `"<ip or room> - <model>[ <role>] - <uuid>"`, for example
+/// `"192.168.1.157 - Sonos Port - RINCON_XXX"` for the root device
+/// and `"Study - Sonos Port Media Renderer - RINCON_XXX"` for one of
+/// its embedded devices.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct FriendlyName {
+    /// The IP address, if the leading portion of the name is an IP address
+    pub ip: Option<IpAddr>,
+    /// The room name, if the leading portion of the name is not an IP address
+    pub room: Option<String>,
+    /// The model name, eg: `Sonos Port`
+    pub model: String,
+    /// The role of an embedded device, eg: `Media Renderer`
+    pub role: Option<String>,
+    /// The `RINCON_` uuid of the device
+    pub uuid: Option<String>,
+}
+
+const FRIENDLY_NAME_ROLES: &[&str] = &["Media Server", "Media Renderer"];
+
+impl FriendlyName {
+    pub fn parse(name: &str) -> Self {
+        // Work from the right so that a room name containing
+        // the separator doesn't confuse us
+        let (rest, uuid) = match name.rsplit_once(" - ") {
+            Some((rest, uuid)) if uuid.starts_with("RINCON_") => (rest, Some(uuid.to_string())),
+            _ => (name, None),
+        };
+
+        let (prefix, model) = match rest.rsplit_once(" - ") {
+            Some((prefix, model)) => (Some(prefix), model),
+            None => (None, rest),
+        };
+
+        let (model, role) = FRIENDLY_NAME_ROLES
+            .iter()
+            .find_map(|role| {
+                model
+                    .strip_suffix(role)
+                    .and_then(|m| m.strip_suffix(' '))
+                    .map(|m| (m, Some(role.to_string())))
+            })
+            .unwrap_or((model, None));
+
+        let ip = prefix.and_then(|p| p.parse::<IpAddr>().ok());
+        let room = match (prefix, ip) {
+            (Some(prefix), None) => Some(prefix.to_string()),
+            _ => None,
+        };
+
+        Self {
+            ip,
+            room,
+            model: model.to_string(),
+            role,
+            uuid,
+        }
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, FromXml, Clone)]
+#[xml(rename = "iconList", ns(UPNP_DEVICE))]
+struct IconList {
+    pub icons: Vec<Icon>,
+}
+
+/// An icon listed in the device description
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, FromXml, Clone, PartialEq, Eq)]
+#[xml(rename = "icon", ns(UPNP_DEVICE))]
+pub struct Icon {
+    pub mimetype: String,
+    pub width: u32,
+    pub height: u32,
+    /// The color depth, in bits
+    pub depth: Option<u32>,
+    pub url: String,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[derive(Debug, FromXml, Clone)]
 #[xml(rename = "serviceList", ns(UPNP_DEVICE))]
 struct ServiceList {
     pub services: Vec<Service>,
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[derive(Debug, FromXml, Clone)]
 #[xml(rename = "deviceList", ns(UPNP_DEVICE))]
 struct DeviceList {
@@ -83,6 +210,7 @@ struct Root {
     device: DeviceSpec,
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[derive(Debug, FromXml, Clone)]
 #[xml(rename = "service", ns(UPNP_DEVICE))]
 pub struct Service {
@@ -125,30 +253,39 @@ impl Service {
     pub async fn subscribe<T: DecodeXml + 'static>(
         &self,
         url: &Url,
+    ) -> crate::Result<EventStream<T>> {
+        self.subscribe_with_client(&reqwest::Client::new(), url, None)
+            .await
+    }
+
+    /// Like `subscribe`, but uses `client` for the SUBSCRIBE request
+    /// and for the subsequent renewals and UNSUBSCRIBE, so that its
+    /// connections can be reused rather than establishing new ones
+    /// each time the subscription is renewed.
+    /// If `local_ip` is provided, the event listener is bound to that
+    /// address and the device is asked to deliver events to it;
+    /// otherwise the address is determined by `probe_local_ip`.
+    pub async fn subscribe_with_client<T: DecodeXml + 'static>(
+        &self,
+        client: &reqwest::Client,
+        url: &Url,
+        local_ip: Option<IpAddr>,
     ) -> crate::Result<EventStream<T>> {
         let sub_url = self.event_sub_url(url);
 
-        // Figure out an appropriate local address to talk to
-        // this device
-        let host = url
-            .host()
-            .ok_or_else(|| Error::NoIpInDeviceUrl(url.clone()))?;
-        let ip: IpAddr = match host {
-            Host::Domain(_s) => return Err(Error::NoIpInDeviceUrl(url.clone())),
-            Host::Ipv4(v4) => v4.into(),
-            Host::Ipv6(v6) => v6.into(),
+        let local_ip = match local_ip {
+            Some(ip) => ip,
+            None => probe_local_ip(url).await?,
         };
-
-        let probe = TcpStream::connect((ip, url.port().unwrap_or(80))).await?;
-        let listener = TcpListener::bind((probe.local_addr()?.ip(), 0)).await?;
+        let listener = TcpListener::bind((local_ip, 0)).await?;
         let local = listener.local_addr()?;
 
-        let response = reqwest::Client::new()
+        let response = client
             .request(
                 Method::from_bytes(b"SUBSCRIBE").expect("SUBSCRIBE to be a valid method"),
                 sub_url.clone(),
             )
-            .header("CALLBACK", format!("<http://{local}>"))
+            .header("CALLBACK", format!("<{}>", callback_url(local)))
             .header("NT", "upnp:event")
             .header("TIMEOUT", format!("Second-{SUBSCRIPTION_TIMEOUT}"))
             .send()
@@ -170,23 +307,78 @@ impl Service {
         log::trace!("Got response: {body}");
 
         let (tx, rx) = channel(16);
+        let cancelled = Arc::new(AtomicBool::new(false));
         {
             let sid = sid.clone();
             let sub_url = sub_url.clone();
-            tokio::spawn(async move { process_subscription(listener, tx, sid, sub_url).await });
+            let client = client.clone();
+            let cancelled = Arc::clone(&cancelled);
+            tokio::spawn(async move {
+                process_subscription(listener, tx, client, sid, sub_url, cancelled).await
+            });
         }
 
-        Ok(EventStream { sid, rx, sub_url })
+        Ok(EventStream {
+            sid,
+            rx,
+            client: client.clone(),
+            sub_url,
+            callback_addr: local,
+            cancelled,
+            registry: None,
+        })
     }
 }
 
 const SUBSCRIPTION_TIMEOUT: u64 = 60;
 
+/// Determines the local address that the host uses to reach the
+/// device at `url`, by connecting to it and observing which
+/// interface was selected
+async fn probe_local_ip(url: &Url) -> crate::Result<IpAddr> {
+    let host = url
+        .host()
+        .ok_or_else(|| Error::NoIpInDeviceUrl(url.clone()))?;
+    let port = url.port_or_known_default().unwrap_or(80);
+    let ip: IpAddr = match host {
+        Host::Domain(domain) => tokio::net::lookup_host((domain, port))
+            .await
+            .ok()
+            .and_then(|mut addrs| addrs.next())
+            .map(|addr| addr.ip())
+            .ok_or_else(|| Error::SubscriptionRequiresIpAddress(domain.to_string()))?,
+        Host::Ipv4(v4) => v4.into(),
+        Host::Ipv6(v6) => v6.into(),
+    };
+
+    local_ip_for(ip, port).await
+}
+
+/// Determines the local address that the host uses to reach `ip`,
+/// by connecting to `port` on it and observing which interface was
+/// selected
+pub(crate) async fn local_ip_for(ip: IpAddr, port: u16) -> crate::Result<IpAddr> {
+    let probe = TcpStream::connect((ip, port)).await?;
+    Ok(probe.local_addr()?.ip())
+}
+
+/// Produces the URL for the CALLBACK header of a SUBSCRIBE request.
+/// IPv6 addresses must be bracketed in URLs, and any scope id is
+/// omitted because it is only meaningful to the local host.
+fn callback_url(local: SocketAddr) -> String {
+    match local {
+        SocketAddr::V4(v4) => format!("http://{}:{}", v4.ip(), v4.port()),
+        SocketAddr::V6(v6) => format!("http://[{}]:{}", v6.ip(), v6.port()),
+    }
+}
+
 async fn process_subscription<T: DecodeXml + 'static>(
     listener: TcpListener,
     tx: Sender<SubscriptionMessage<T>>,
+    client: reqwest::Client,
     sid: String,
     sub_url: Url,
+    cancelled: Arc<AtomicBool>,
 ) -> crate::Result<()> {
     let mut deadline =
         tokio::time::Instant::now() + tokio::time::Duration::from_secs(SUBSCRIPTION_TIMEOUT - 10);
@@ -201,6 +393,13 @@ async fn process_subscription<T: DecodeXml + 'static>(
                 return Ok(());
             }
             Err(_) => {
+                if cancelled.load(Ordering::Relaxed) {
+                    // Already cancelled via unsubscribe_all; the device
+                    // would reject a renewal, and there is nothing to
+                    // report to the stream
+                    return Ok(());
+                }
+
                 log::debug!("time to renew!");
                 // Time to renew subscription
                 let renew = match tx.try_send(SubscriptionMessage::Ping) {
@@ -211,7 +410,19 @@ async fn process_subscription<T: DecodeXml + 'static>(
                     }
                 };
 
-                renew_or_cancel_sub(&sub_url, renew, &sid).await?;
+                if let Err(err) = renew_or_cancel_sub(&client, &sub_url, renew, &sid).await {
+                    if renew {
+                        // Let the consumer know why the stream is ending
+                        let err = Error::SubscriptionRenewalFailed {
+                            sid: sid.clone(),
+                            error: Box::new(err),
+                        };
+                        log::error!("{err:#}");
+                        tx.send(SubscriptionMessage::Error(err)).await.ok();
+                        return Ok(());
+                    }
+                    return Err(err);
+                }
 
                 if renew {
                     deadline = tokio::time::Instant::now()
@@ -224,6 +435,8 @@ async fn process_subscription<T: DecodeXml + 'static>(
     }
 }
 
+const NOTIFY_RESPONSE: &[u8] = b"HTTP/1.1 200 OK\r\nContent-Length: 0\r\nConnection: close\r\n\r\n";
+
 async fn handle_subscription_request<T: DecodeXml>(
     mut client: TcpStream,
     tx: Sender<SubscriptionMessage<T>>,
@@ -232,6 +445,10 @@ async fn handle_subscription_request<T: DecodeXml>(
     let mut buf = [0u8; 4096];
 
     while let Ok(len) = client.read(&mut buf).await {
+        if len == 0 {
+            log::error!("Connection closed before the request was complete");
+            break;
+        }
         reqbuf.extend_from_slice(&buf[0..len]);
 
         let mut headers = [httparse::EMPTY_HEADER; 16];
@@ -244,37 +461,46 @@ async fn handle_subscription_request<T: DecodeXml>(
             }
             Ok(httparse::Status::Partial) => continue,
             Ok(httparse::Status::Complete(body_start)) => {
-                // It's only *maybe* complete; check the content-length
-                // vs. the data in the buffer
-                if let Some(cl) = req
-                    .headers
-                    .iter()
-                    .find(|h| h.name.eq_ignore_ascii_case("Content-Length"))
-                {
-                    match std::str::from_utf8(cl.value)
-                        .ok()
-                        .and_then(|s| s.parse::<usize>().ok())
-                    {
-                        Some(cl) => {
-                            let avail = reqbuf.len() - body_start;
-                            if avail < cl {
-                                // We need more data
-                                continue;
-                            }
-                        }
-                        None => {
-                            log::error!("Invalid header: {cl:?}");
-                            break;
-                        }
+                // It's only *maybe* complete; check the framing of the
+                // body vs. the data in the buffer
+                let body = match request_body(req.headers, &reqbuf[body_start..]) {
+                    Ok(Some(body)) => body,
+                    Ok(None) => {
+                        // We need more data
+                        continue;
                     }
-                }
-                let body = String::from_utf8_lossy(&reqbuf[body_start..]).to_string();
+                    Err(err) => {
+                        log::error!("{err:#}");
+                        break;
+                    }
+                };
+                let body = String::from_utf8_lossy(&body).to_string();
+                let sid = find_header(req.headers, "SID").map(|s| s.to_string());
+                let nt = find_header(req.headers, "NT").map(|s| s.to_string());
+                let seq = find_header(req.headers, "SEQ").and_then(|s| s.trim().parse().ok());
 
                 log::trace!("{req:#?}");
                 log::trace!("{body}");
 
-                match T::decode_xml(&body) {
-                    Ok(event) => {
+                let event = T::decode_xml(&body);
+
+                // Acknowledge the notification regardless of whether
+                // we could make sense of it; the device doesn't care
+                // about our parsing problems and may back off if we
+                // fail to respond.
+                if let Err(err) = client.write_all(NOTIFY_RESPONSE).await {
+                    log::error!("Failed to acknowledge event: {err:#}");
+                }
+                client.shutdown().await.ok();
+
+                match event {
+                    Ok(payload) => {
+                        let event = SubscriptionEvent {
+                            sid,
+                            seq,
+                            nt,
+                            payload,
+                        };
                         if let Err(err) = tx.send(SubscriptionMessage::Event(event)).await {
                             log::error!("Channel is dead {err:#}");
                             return Ok(());
@@ -292,8 +518,80 @@ async fn handle_subscription_request<T: DecodeXml>(
     Ok(())
 }
 
-async fn renew_or_cancel_sub(sub_url: &Url, subscribe: bool, sid: &str) -> crate::Result<Response> {
-    let mut request = reqwest::Client::new()
+fn find_header<'a>(headers: &'a [httparse::Header], name: &str) -> Option<Cow<'a, str>> {
+    headers
+        .iter()
+        .find(|h| h.name.eq_ignore_ascii_case(name))
+        .map(|h| String::from_utf8_lossy(h.value))
+}
+
+/// Extracts the body of a request, taking into account either
+/// the Content-Length or chunked Transfer-Encoding.
+/// Returns Ok(None) if more data is required.
+fn request_body(headers: &[httparse::Header], data: &[u8]) -> std::io::Result<Option<Vec<u8>>> {
+    if let Some(te) = find_header(headers, "Transfer-Encoding") {
+        if te
+            .split(',')
+            .any(|coding| coding.trim().eq_ignore_ascii_case("chunked"))
+        {
+            return decode_chunked(data);
+        }
+    }
+
+    match find_header(headers, "Content-Length") {
+        Some(cl) => match cl.trim().parse::<usize>() {
+            Ok(cl) if data.len() < cl => Ok(None),
+            Ok(cl) => Ok(Some(data[0..cl].to_vec())),
+            Err(_) => Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                format!("Invalid Content-Length header: {cl}"),
+            )),
+        },
+        None => Ok(Some(data.to_vec())),
+    }
+}
+
+/// Decodes a body that uses chunked Transfer-Encoding.
+/// Returns Ok(None) if the terminating chunk has not yet been received.
+/// Any trailers are ignored.
+fn decode_chunked(mut data: &[u8]) -> std::io::Result<Option<Vec<u8>>> {
+    let invalid = |reason: &str| {
+        std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            format!("Invalid chunked body: {reason}"),
+        )
+    };
+
+    let mut body = vec![];
+    loop {
+        let (consumed, size) = match httparse::parse_chunk_size(data) {
+            Ok(httparse::Status::Complete(result)) => result,
+            Ok(httparse::Status::Partial) => return Ok(None),
+            Err(_) => return Err(invalid("bad chunk size")),
+        };
+        data = &data[consumed..];
+        if size == 0 {
+            return Ok(Some(body));
+        }
+        let size = usize::try_from(size).map_err(|_| invalid("chunk too large"))?;
+        if data.len() < size + 2 {
+            return Ok(None);
+        }
+        body.extend_from_slice(&data[0..size]);
+        if &data[size..size + 2] != b"\r\n" {
+            return Err(invalid("missing CRLF after chunk data"));
+        }
+        data = &data[size + 2..];
+    }
+}
+
+async fn renew_or_cancel_sub(
+    client: &reqwest::Client,
+    sub_url: &Url,
+    subscribe: bool,
+    sid: &str,
+) -> crate::Result<Response> {
+    let mut request = client
         .request(
             Method::from_bytes(if subscribe {
                 b"SUBSCRIBE"
@@ -314,9 +612,36 @@ async fn renew_or_cancel_sub(sub_url: &Url, subscribe: bool, sid: &str) -> crate
     Ok(response)
 }
 
+async fn cancel_subscription(
+    client: &reqwest::Client,
+    sub_url: &Url,
+    sid: &str,
+) -> crate::Result<()> {
+    renew_or_cancel_sub(client, sub_url, false, sid).await?;
+    Ok(())
+}
+
 enum SubscriptionMessage<T> {
     Ping,
-    Event(T),
+    Event(SubscriptionEvent<T>),
+    /// The subscription has terminated because of this error
+    Error(Error),
+}
+
+/// An event delivered via a subscription, together with the
+/// metadata from the NOTIFY request that carried it.
+#[derive(Debug, Clone)]
+pub struct SubscriptionEvent<T> {
+    /// The subscription identifier, from the `SID` header
+    pub sid: Option<String>,
+    /// The event key, from the `SEQ` header.  This is 0 for the
+    /// initial event and increments for each subsequent event,
+    /// so a gap in the sequence indicates that events were missed
+    /// and that a full state fetch may be needed to resync.
+    pub seq: Option<u32>,
+    /// The notification type, from the `NT` header
+    pub nt: Option<String>,
+    pub payload: T,
 }
 
 /// A helper trait for parsing a uPNP event stream into
@@ -344,29 +669,142 @@ pub trait EncodeXml {
 pub struct EventStream<T: DecodeXml> {
     rx: Receiver<SubscriptionMessage<T>>,
     sid: String,
+    client: reqwest::Client,
     sub_url: Url,
+    callback_addr: SocketAddr,
+    /// Set once the subscription has been explicitly cancelled,
+    /// either via `unsubscribe` or `SonosDevice::unsubscribe_all`
+    cancelled: Arc<AtomicBool>,
+    /// The registry of the SonosDevice that made this subscription,
+    /// from which it is removed once it is unsubscribed or dropped
+    registry: Option<SubscriptionRegistry>,
 }
 
 impl<T: DecodeXml> EventStream<T> {
-    /// Receives the next event from the stream
-    pub async fn recv(&mut self) -> Option<T> {
+    /// Receives the next event from the stream.
+    /// Returns `Ok(None)` once the stream has ended, or an error,
+    /// such as `Error::SubscriptionRenewalFailed`, that explains
+    /// why the stream is ending.
+    pub async fn recv(&mut self) -> crate::Result<Option<T>> {
+        Ok(self.recv_event().await?.map(|event| event.payload))
+    }
+
+    /// Receives the next event from the stream, along with its
+    /// SID and SEQ metadata.  See `recv` for the meaning of the result.
+    pub async fn recv_event(&mut self) -> crate::Result<Option<SubscriptionEvent<T>>> {
         loop {
-            let msg = self.rx.recv().await?;
-            match msg {
-                SubscriptionMessage::Ping => {}
-                SubscriptionMessage::Event(v) => {
-                    return Some(v);
-                }
+            match self.rx.recv().await {
+                None => return Ok(None),
+                Some(SubscriptionMessage::Ping) => {}
+                Some(SubscriptionMessage::Event(v)) => return Ok(Some(v)),
+                Some(SubscriptionMessage::Error(err)) => return Err(err),
             }
         }
     }
 
+    /// Returns the next event if one is immediately available,
+    /// without waiting.  Returns `Ok(None)` if there are no pending
+    /// events, or if the subscription has ended, and an error if the
+    /// subscription terminated because of an error.
+    pub fn try_recv(&mut self) -> crate::Result<Option<T>> {
+        loop {
+            match self.rx.try_recv() {
+                Err(_) => return Ok(None),
+                Ok(SubscriptionMessage::Ping) => {}
+                Ok(SubscriptionMessage::Event(v)) => return Ok(Some(v.payload)),
+                Ok(SubscriptionMessage::Error(err)) => return Err(err),
+            }
+        }
+    }
+
+    /// Returns the subscription identifier
+    pub fn sid(&self) -> &str {
+        &self.sid
+    }
+
+    /// Returns the local address on which this stream listens for
+    /// event notifications from the device.  The device connects to
+    /// this address, so a host firewall must allow inbound connections
+    /// to its port.
+    pub fn callback_addr(&self) -> SocketAddr {
+        self.callback_addr
+    }
+
     /// Explicitly cancel the subscription
     pub async fn unsubscribe(self) {
-        renew_or_cancel_sub(&self.sub_url, false, &self.sid)
+        self.cancelled.store(true, Ordering::Relaxed);
+        self.deregister();
+        cancel_subscription(&self.client, &self.sub_url, &self.sid)
             .await
             .ok();
     }
+
+    /// Records this subscription in `registry`, so that it can be
+    /// cancelled via `SonosDevice::unsubscribe_all`.
+    /// Subscriptions that have already been cancelled are pruned
+    /// from the registry at the same time.
+    pub(crate) fn register(&mut self, registry: &SubscriptionRegistry) {
+        let handle = SubscriptionHandle {
+            client: self.client.clone(),
+            sub_url: self.sub_url.clone(),
+            sid: self.sid.clone(),
+            cancelled: Arc::clone(&self.cancelled),
+        };
+        let mut handles = registry.lock().unwrap();
+        handles.retain(|_, handle| !handle.cancelled.load(Ordering::Relaxed));
+        handles.insert(self.sid.clone(), handle);
+        self.registry = Some(Arc::clone(registry));
+    }
+
+    fn deregister(&self) {
+        if let Some(registry) = &self.registry {
+            if let Ok(mut handles) = registry.lock() {
+                handles.remove(&self.sid);
+            }
+        }
+    }
+}
+
+/// The live subscriptions made via a SonosDevice and its clones,
+/// keyed by SID
+pub(crate) type SubscriptionRegistry = Arc<Mutex<HashMap<String, SubscriptionHandle>>>;
+
+/// Allows a subscription to be cancelled independently of its
+/// EventStream; used by `SonosDevice::unsubscribe_all`
+#[derive(Debug)]
+pub(crate) struct SubscriptionHandle {
+    client: reqwest::Client,
+    sub_url: Url,
+    sid: String,
+    cancelled: Arc<AtomicBool>,
+}
+
+impl SubscriptionHandle {
+    /// Cancels the subscription, unless it was already cancelled
+    pub async fn cancel(&self) {
+        if !self.cancelled.swap(true, Ordering::Relaxed) {
+            cancel_subscription(&self.client, &self.sub_url, &self.sid)
+                .await
+                .ok();
+        }
+    }
+}
+
+/// Dropping an EventStream without unsubscribing leaves the subscription
+/// active on the device until the background task next attempts to renew
+/// it, so in debug builds we log a warning to help track down the source.
+impl<T: DecodeXml> Drop for EventStream<T> {
+    fn drop(&mut self) {
+        self.deregister();
+        #[cfg(debug_assertions)]
+        if !self.cancelled.load(Ordering::Relaxed) {
+            log::warn!(
+                "EventStream for {} with SID {} was dropped without calling unsubscribe",
+                self.sub_url,
+                self.sid
+            );
+        }
+    }
 }
 
 pub(crate) const UPNP_EVENT: &str = "urn:schemas-upnp-org:event-1-0";
@@ -375,10 +813,119 @@ pub(crate) const UPNP_EVENT: &str = "urn:schemas-upnp-org:event-1-0";
 mod test {
     use super::*;
 
+    #[test]
+    fn test_decode_chunked() {
+        k9::assert_equal!(
+            decode_chunked(b"5\r\nhello\r\n7;ext=1\r\n, world\r\n0\r\n\r\n").unwrap(),
+            Some(b"hello, world".to_vec())
+        );
+        k9::assert_equal!(decode_chunked(b"5\r\nhello\r\n").unwrap(), None);
+        k9::assert_equal!(decode_chunked(b"5\r\nhel").unwrap(), None);
+        assert!(decode_chunked(b"zz\r\nhello\r\n").is_err());
+        assert!(decode_chunked(b"2\r\nhello\r\n").is_err());
+    }
+
+    #[test]
+    fn test_request_body() {
+        let chunked = [httparse::Header {
+            name: "transfer-encoding",
+            value: b"chunked",
+        }];
+        k9::assert_equal!(
+            request_body(&chunked, b"3\r\nabc\r\n0\r\n\r\n").unwrap(),
+            Some(b"abc".to_vec())
+        );
+
+        let content_length = [httparse::Header {
+            name: "Content-Length",
+            value: b"3",
+        }];
+        k9::assert_equal!(request_body(&content_length, b"ab").unwrap(), None);
+        k9::assert_equal!(
+            request_body(&content_length, b"abc").unwrap(),
+            Some(b"abc".to_vec())
+        );
+    }
+
+    #[test]
+    fn test_callback_url() {
+        k9::assert_equal!(
+            callback_url("192.168.1.2:1234".parse().unwrap()),
+            "http://192.168.1.2:1234"
+        );
+        k9::assert_equal!(
+            callback_url("[fd00::2]:1234".parse().unwrap()),
+            "http://[fd00::2]:1234"
+        );
+        k9::assert_equal!(
+            callback_url(SocketAddr::V6(std::net::SocketAddrV6::new(
+                "fe80::2".parse().unwrap(),
+                1234,
+                0,
+                3
+            ))),
+            "http://[fe80::2]:1234"
+        );
+    }
+
+    #[test]
+    fn parse_friendly_name() {
+        let spec_text = include_str!("../data/device_spec.xml");
+        let spec = DeviceSpec::parse_xml(spec_text).unwrap();
+        let names: Vec<FriendlyName> = std::iter::once(&spec)
+            .chain(spec.device_list.iter().flat_map(|l| l.devices.iter()))
+            .map(|d| d.parsed_friendly_name())
+            .collect();
+        k9::snapshot!(
+            names,
+            r#"
+[
+    FriendlyName {
+        ip: Some(
+            192.168.1.157,
+        ),
+        room: None,
+        model: "Sonos Port",
+        role: None,
+        uuid: Some(
+            "RINCON_XXX",
+        ),
+    },
+    FriendlyName {
+        ip: Some(
+            192.168.1.157,
+        ),
+        room: None,
+        model: "Sonos Port",
+        role: Some(
+            "Media Server",
+        ),
+        uuid: Some(
+            "RINCON_XXX",
+        ),
+    },
+    FriendlyName {
+        ip: None,
+        room: Some(
+            "Some Room",
+        ),
+        model: "Sonos Port",
+        role: Some(
+            "Media Renderer",
+        ),
+        uuid: Some(
+            "RINCON_XXX",
+        ),
+    },
+]
+"#
+        );
+    }
+
     #[test]
     fn parse_device_spec() {
         let spec_text = include_str!("../data/device_spec.xml");
-        let spec: Root = instant_xml::from_str(&spec_text).unwrap();
+        let spec: Root = instant_xml::from_str(spec_text).unwrap();
         k9::snapshot!(
             spec,
             r#"
@@ -398,6 +945,27 @@ Root {
         ssl_port: Some(
             1443,
         ),
+        udn: Some(
+            "uuid:RINCON_XXX",
+        ),
+        room_name: Some(
+            "Some Room",
+        ),
+        icon_list: Some(
+            IconList {
+                icons: [
+                    Icon {
+                        mimetype: "image/png",
+                        width: 48,
+                        height: 48,
+                        depth: Some(
+                            24,
+                        ),
+                        url: "/img/icon-S23.png",
+                    },
+                ],
+            },
+        ),
         service_list: Some(
             ServiceList {
                 services: [
@@ -476,6 +1044,11 @@ Root {
                             "Sonos Port",
                         ),
                         ssl_port: None,
+                        udn: Some(
+                            "uuid:RINCON_48A6B826F33201400_MS",
+                        ),
+                        room_name: None,
+                        icon_list: None,
                         service_list: Some(
                             ServiceList {
                                 services: [
@@ -511,6 +1084,25 @@ Root {
                             "Sonos Port",
                         ),
                         ssl_port: None,
+                        udn: Some(
+                            "uuid:RINCON_XXX",
+                        ),
+                        room_name: None,
+                        icon_list: Some(
+                            IconList {
+                                icons: [
+                                    Icon {
+                                        mimetype: "image/png",
+                                        width: 48,
+                                        height: 48,
+                                        depth: Some(
+                                            24,
+                                        ),
+                                        url: "/img/icon-S23.png",
+                                    },
+                                ],
+                            },
+                        ),
                         service_list: Some(
                             ServiceList {
                                 services: [
@@ -569,4 +1161,87 @@ Root {
 "#
         );
     }
+
+    #[derive(Debug, PartialEq)]
+    struct Text(String);
+
+    impl DecodeXml for Text {
+        fn decode_xml(xml: &str) -> crate::Result<Self> {
+            Ok(Self(xml.to_string()))
+        }
+    }
+
+    #[test]
+    fn test_try_recv() {
+        let (tx, rx) = channel(4);
+        let mut stream: EventStream<Text> = EventStream {
+            rx,
+            sid: "uuid:sub".to_string(),
+            client: reqwest::Client::new(),
+            sub_url: "http://127.0.0.1:1400/event".parse().unwrap(),
+            callback_addr: "127.0.0.1:3400".parse().unwrap(),
+            cancelled: Arc::new(AtomicBool::new(true)),
+            registry: None,
+        };
+        k9::assert_equal!(stream.try_recv().unwrap(), None);
+
+        tx.try_send(SubscriptionMessage::Ping).ok().unwrap();
+        tx.try_send(SubscriptionMessage::Event(SubscriptionEvent {
+            sid: None,
+            seq: Some(0),
+            nt: None,
+            payload: Text("hello".to_string()),
+        }))
+        .ok()
+        .unwrap();
+        tx.try_send(SubscriptionMessage::Ping).ok().unwrap();
+
+        k9::assert_equal!(stream.try_recv().unwrap(), Some(Text("hello".to_string())));
+        k9::assert_equal!(stream.try_recv().unwrap(), None);
+
+        tx.try_send(SubscriptionMessage::Error(
+            Error::SubscriptionRenewalFailed {
+                sid: "uuid:sub".to_string(),
+                error: Box::new(Error::SubscriptionFailedNoSid),
+            },
+        ))
+        .ok()
+        .unwrap();
+        assert!(matches!(
+            stream.try_recv(),
+            Err(Error::SubscriptionRenewalFailed { .. })
+        ));
+    }
+
+    #[test]
+    fn test_subscription_registry() {
+        let stream = |sid: &str, cancelled: bool| -> EventStream<Text> {
+            EventStream {
+                rx: channel(1).1,
+                sid: sid.to_string(),
+                client: reqwest::Client::new(),
+                sub_url: "http://127.0.0.1:1400/event".parse().unwrap(),
+                callback_addr: "127.0.0.1:3400".parse().unwrap(),
+                cancelled: Arc::new(AtomicBool::new(cancelled)),
+                registry: None,
+            }
+        };
+        let registry = SubscriptionRegistry::default();
+
+        let mut first = stream("uuid:first", false);
+        first.register(&registry);
+        let mut second = stream("uuid:second", false);
+        second.register(&registry);
+        k9::assert_equal!(registry.lock().unwrap().len(), 2);
+
+        drop(first);
+        k9::assert_equal!(registry.lock().unwrap().len(), 1);
+
+        // Cancelled subscriptions are pruned when the next is registered
+        second.cancelled.store(true, Ordering::Relaxed);
+        let mut third = stream("uuid:third", true);
+        third.register(&registry);
+        let sids: Vec<String> = registry.lock().unwrap().keys().cloned().collect();
+        k9::assert_equal!(sids, vec!["uuid:third".to_string()]);
+    }
 }
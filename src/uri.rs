@@ -0,0 +1,159 @@
+use std::fmt;
+
+/// A typed representation of the URI schemes that Sonos uses for
+/// AVTransport URIs.
+/// Use `SonosUri::parse` to classify a URI and `to_string` to
+/// produce one; the two round-trip.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SonosUri {
+    /// `x-rincon-queue:RINCON_XXX#0`: the queue of the device with
+    /// the specified uuid
+    Queue { uuid: String, index: u32 },
+    /// `x-rincon:RINCON_XXX`: follow the group coordinator with the
+    /// specified uuid
+    Group { coordinator: String },
+    /// `x-rincon-stream:RINCON_XXX`: the line-in of the device with
+    /// the specified uuid
+    LineIn { uuid: String },
+    /// `x-sonos-htastream:RINCON_XXX:spdif`: the TV input of the home
+    /// theater device with the specified uuid
+    HomeTheater { uuid: String, input: String },
+    /// `x-rincon-mp3radio://example.com/stream.mp3`.
+    /// `location` is everything after the scheme, including the
+    /// leading `//`
+    Mp3Radio { location: String },
+    /// A music service provided item such as
+    /// `x-sonosapi-stream:s12345?sid=254&flags=8224&sn=0`.
+    /// `kind` is the part of the scheme following `x-sonosapi-`
+    /// (eg: `stream`, `radio`, `hls`) and `path` is everything
+    /// after the scheme
+    SonosApi { kind: String, path: String },
+    /// Any other URI, such as a plain http URL
+    Other(String),
+}
+
+impl SonosUri {
+    /// Classifies `uri`.  URIs that use a recognized scheme but are
+    /// malformed are returned as `SonosUri::Other`.
+    pub fn parse(uri: &str) -> Self {
+        Self::parse_known(uri).unwrap_or_else(|| Self::Other(uri.to_string()))
+    }
+
+    fn parse_known(uri: &str) -> Option<Self> {
+        let (scheme, rest) = uri.split_once(':')?;
+        match scheme {
+            "x-rincon-queue" => {
+                let (uuid, index) = rest.split_once('#')?;
+                Some(Self::Queue {
+                    uuid: uuid.to_string(),
+                    index: index.parse().ok()?,
+                })
+            }
+            "x-rincon" => Some(Self::Group {
+                coordinator: rest.to_string(),
+            }),
+            "x-rincon-stream" => Some(Self::LineIn {
+                uuid: rest.to_string(),
+            }),
+            "x-sonos-htastream" => {
+                let (uuid, input) = rest.split_once(':')?;
+                Some(Self::HomeTheater {
+                    uuid: uuid.to_string(),
+                    input: input.to_string(),
+                })
+            }
+            "x-rincon-mp3radio" => Some(Self::Mp3Radio {
+                location: rest.to_string(),
+            }),
+            _ => {
+                let kind = scheme.strip_prefix("x-sonosapi-")?;
+                Some(Self::SonosApi {
+                    kind: kind.to_string(),
+                    path: rest.to_string(),
+                })
+            }
+        }
+    }
+
+    /// Returns the uri for the queue of the device with the specified
+    /// uuid, which is the uri used to play from the queue
+    pub fn queue(uuid: &str) -> Self {
+        Self::Queue {
+            uuid: uuid.to_string(),
+            index: 0,
+        }
+    }
+}
+
+impl From<&str> for SonosUri {
+    fn from(uri: &str) -> Self {
+        Self::parse(uri)
+    }
+}
+
+impl fmt::Display for SonosUri {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Queue { uuid, index } => write!(f, "x-rincon-queue:{uuid}#{index}"),
+            Self::Group { coordinator } => write!(f, "x-rincon:{coordinator}"),
+            Self::LineIn { uuid } => write!(f, "x-rincon-stream:{uuid}"),
+            Self::HomeTheater { uuid, input } => write!(f, "x-sonos-htastream:{uuid}:{input}"),
+            Self::Mp3Radio { location } => write!(f, "x-rincon-mp3radio:{location}"),
+            Self::SonosApi { kind, path } => write!(f, "x-sonosapi-{kind}:{path}"),
+            Self::Other(uri) => f.write_str(uri),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        for uri in [
+            "x-rincon-queue:RINCON_000E58A0123401400#0",
+            "x-rincon:RINCON_000E58A0123401400",
+            "x-rincon-stream:RINCON_000E58A0123401400",
+            "x-sonos-htastream:RINCON_000E58A0123401400:spdif",
+            "x-rincon-mp3radio://example.com/stream.mp3",
+            "x-sonosapi-stream:s12345?sid=254&flags=8224&sn=0",
+            "x-sonosapi-hls:catalog%3astation?sid=204&flags=8232&sn=3",
+            "http://example.com/track.mp3",
+            "x-rincon-queue:RINCON_000E58A0123401400",
+        ] {
+            k9::assert_equal!(SonosUri::parse(uri).to_string(), uri);
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        k9::snapshot!(
+            SonosUri::parse("x-sonos-htastream:RINCON_000E58A0123401400:spdif"),
+            r#"
+HomeTheater {
+    uuid: "RINCON_000E58A0123401400",
+    input: "spdif",
+}
+"#
+        );
+        k9::snapshot!(
+            SonosUri::parse("x-sonosapi-stream:s12345?sid=254"),
+            r#"
+SonosApi {
+    kind: "stream",
+    path: "s12345?sid=254",
+}
+"#
+        );
+        k9::assert_equal!(
+            SonosUri::parse("x-rincon-queue:RINCON_000E58A0123401400#0"),
+            SonosUri::queue("RINCON_000E58A0123401400")
+        );
+        // Malformed queue uri: no index
+        k9::assert_equal!(
+            SonosUri::parse("x-rincon-queue:RINCON_000E58A0123401400"),
+            SonosUri::Other("x-rincon-queue:RINCON_000E58A0123401400".to_string())
+        );
+    }
+}
@@ -1,9 +1,109 @@
 use crate::upnp::{DecodeXml, EncodeXml};
 use instant_xml::{Deserializer, FromXml, Id, Kind, ToXml};
 
+/// Sonos devices report the literal text `NOT_IMPLEMENTED` for values
+/// that are not applicable to the current state (for example,
+/// `AbsTime` in `GetPositionInfo`, or `CurrentURI` when nothing is
+/// loaded).  This crate treats that sentinel as equivalent to an
+/// absent value everywhere:
+///
+/// * `DecodeXmlString` yields `None` for it, as it does for empty text.
+/// * SOAP response bodies are passed through `strip_not_implemented`
+///   before they are decoded, so plain `Option<String>` and numeric
+///   fields are `None` rather than carrying the sentinel through.
+pub const NOT_IMPLEMENTED: &str = "NOT_IMPLEMENTED";
+
+/// Removes elements whose text consists solely of the `NOT_IMPLEMENTED`
+/// sentinel, so that they decode as absent.
+pub(crate) fn strip_not_implemented(xml: &str) -> std::borrow::Cow<'_, str> {
+    const SENTINEL: &str = ">NOT_IMPLEMENTED</";
+    if !xml.contains(SENTINEL) {
+        return xml.into();
+    }
+
+    let mut result = String::with_capacity(xml.len());
+    let mut remain = xml;
+    while let Some(idx) = remain.find(SENTINEL) {
+        let close_start = idx + SENTINEL.len();
+        let element = remain[..idx].rfind('<').and_then(|open| {
+            let name = remain[open + 1..idx].split_whitespace().next()?;
+            let close = format!("{name}>");
+            remain[close_start..]
+                .starts_with(&close)
+                .then(|| (open, close_start + close.len()))
+        });
+        match element {
+            Some((open, end)) => {
+                result.push_str(&remain[..open]);
+                remain = &remain[end..];
+            }
+            None => {
+                result.push_str(&remain[..close_start]);
+                remain = &remain[close_start..];
+            }
+        }
+    }
+    result.push_str(remain);
+    result.into()
+}
+
+/// Decodes a single layer of XML entity escaping, returning the input
+/// unchanged if it contains no recognized entities.
+/// This is used to repair values that were escaped twice, which
+/// happens when DIDL-Lite is embedded as a string in a SOAP response
+/// by firmware or services that escape it one time too many.
+pub(crate) fn unescape_entities(text: &str) -> std::borrow::Cow<'_, str> {
+    if !text.contains('&') {
+        return text.into();
+    }
+
+    let mut result = String::with_capacity(text.len());
+    let mut remain = text;
+    let mut changed = false;
+    while let Some(idx) = remain.find('&') {
+        result.push_str(&remain[..idx]);
+        remain = &remain[idx..];
+        let decoded = remain.find(';').and_then(|end| {
+            let c = match &remain[1..end] {
+                "amp" => '&',
+                "lt" => '<',
+                "gt" => '>',
+                "quot" => '"',
+                "apos" => '\'',
+                entity => {
+                    let num = entity.strip_prefix('#')?;
+                    let code = match num.strip_prefix(['x', 'X']) {
+                        Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                        None => num.parse().ok()?,
+                    };
+                    char::from_u32(code)?
+                }
+            };
+            Some((c, end + 1))
+        });
+        match decoded {
+            Some((c, len)) => {
+                result.push(c);
+                remain = &remain[len..];
+                changed = true;
+            }
+            None => {
+                result.push('&');
+                remain = &remain[1..];
+            }
+        }
+    }
+    if !changed {
+        return text.into();
+    }
+    result.push_str(remain);
+    result.into()
+}
+
 /// This is a wrapper container that can be used to adapt a
 /// scalar embedded xml string value into a more rich Rust
 /// type representation.
+/// Empty and `NOT_IMPLEMENTED` values are represented as `None`.
 #[derive(Debug, PartialEq, Clone, Default)]
 pub struct DecodeXmlString<T>(pub Option<T>)
 where
@@ -34,7 +134,7 @@ where
             Some(value) => {
                 // eprintln!("decode: {value}");
 
-                let is_empty = value.trim().is_empty() || value == "NOT_IMPLEMENTED";
+                let is_empty = value.trim().is_empty() || value == NOT_IMPLEMENTED;
 
                 if !is_empty {
                     let parsed = T::decode_xml(&value).map_err(|err| {
@@ -1,5 +1,8 @@
-use crate::upnp::DecodeXml;
+use crate::upnp::{DecodeXml, EventStream};
+use crate::zone_group_topology::ZoneGroupTopologyEvent;
+use crate::{ChannelMap, ChannelRole, SonosDevice};
 use instant_xml::FromXml;
+use std::collections::BTreeMap;
 
 #[derive(Debug, PartialEq, Clone)]
 pub struct ZoneGroupState {
@@ -20,6 +23,228 @@ impl DecodeXml for ZoneGroupState {
     }
 }
 
+impl From<Vec<ZoneGroup>> for ZoneGroupState {
+    fn from(groups: Vec<ZoneGroup>) -> Self {
+        Self { groups }
+    }
+}
+
+impl ZoneGroupState {
+    /// Iterates over the members of every group.
+    /// Satellites are not included; they can be found via
+    /// the `satellites` field of each member, or by using
+    /// `all_zones_flat`.
+    pub fn all_members(&self) -> impl Iterator<Item = &ZoneGroupMember> {
+        self.groups.iter().flat_map(|group| group.members.iter())
+    }
+
+    /// Iterates over every device in every group, yielding each
+    /// together with the group to which it belongs.
+    /// Satellites (such as the surrounds and sub of a home theater
+    /// setup) are included only when `include_satellites` is true.
+    pub fn all_zones_flat(&self, include_satellites: bool) -> impl Iterator<Item = FlatZone<'_>> {
+        self.groups.iter().flat_map(move |group| {
+            group.members.iter().flat_map(move |member| {
+                let satellites: &[Satellite] = if include_satellites {
+                    &member.satellites
+                } else {
+                    &[]
+                };
+                std::iter::once(FlatZone {
+                    group_id: &group.id,
+                    coordinator: &group.coordinator,
+                    uuid: &member.uuid,
+                    zone_name: &member.zone_name,
+                    location: &member.location,
+                    satellite_of: None,
+                })
+                .chain(satellites.iter().map(move |sat| FlatZone {
+                    group_id: &group.id,
+                    coordinator: &group.coordinator,
+                    uuid: &sat.uuid,
+                    zone_name: &sat.zone_name,
+                    location: &sat.location,
+                    satellite_of: Some(&member.uuid),
+                }))
+            })
+        })
+    }
+}
+
+impl ZoneGroupState {
+    /// Returns the channel map of the stereo pair or home theater setup
+    /// that includes the device with the specified uuid.
+    /// Returns None if the device is not part of such a setup.
+    pub fn channel_map(&self, uuid: &str) -> Option<ChannelMap> {
+        self.all_members()
+            .flat_map(|member| [&member.ht_sat_chan_map_set, &member.channel_map_set])
+            .flatten()
+            .filter_map(|text| text.parse::<ChannelMap>().ok())
+            .find(|map| map.channels_for(uuid).is_some())
+    }
+
+    /// Returns the channel map of the stereo pair that includes the
+    /// device with the specified uuid.  Unlike `channel_map`, home
+    /// theater setups are not considered.
+    pub fn stereo_pair_map(&self, uuid: &str) -> Option<ChannelMap> {
+        self.all_members()
+            .filter_map(|member| member.channel_map_set.as_deref())
+            .filter_map(|text| text.parse::<ChannelMap>().ok())
+            .find(|map| map.channels_for(uuid).is_some())
+    }
+
+    /// Returns true if the device with the specified uuid is in a group
+    /// that spans more than one room, as counted by `ZoneGroup::room_count`.
+    /// Returns None if the device is not present in the topology.
+    pub fn is_grouped(&self, uuid: &str) -> Option<bool> {
+        let group = self.groups.iter().find(|group| {
+            group.members.iter().any(|member| {
+                member.uuid == uuid || member.satellites.iter().any(|sat| sat.uuid == uuid)
+            })
+        })?;
+        Some(group.room_count() > 1)
+    }
+
+    /// Returns the rooms that could be added to the group containing
+    /// the device with the specified uuid: every room except those
+    /// already in that group, sorted by name.
+    /// Satellites are not included, and each stereo pair appears once.
+    /// Rooms are told apart by their devices, so two rooms that share
+    /// a name are both listed.
+    /// The uuid of each room is that of its group coordinator where
+    /// the coordinator is in the room, otherwise the left-hand member
+    /// of a stereo pair, otherwise its first member.
+    pub fn groupable_rooms(&self, uuid: &str) -> Vec<RoomRef> {
+        let mut rooms: Vec<RoomRef> = self
+            .groups
+            .iter()
+            .filter(|group| {
+                !group.members.iter().any(|member| {
+                    member.uuid == uuid || member.satellites.iter().any(|sat| sat.uuid == uuid)
+                })
+            })
+            .flat_map(|group| group.room_leaders())
+            .map(|member| RoomRef {
+                name: member.zone_name.clone(),
+                uuid: member.uuid.clone(),
+                location: member.location.clone(),
+            })
+            .collect();
+
+        rooms.sort_by(|a, b| a.name.cmp(&b.name).then_with(|| a.uuid.cmp(&b.uuid)));
+        rooms
+    }
+
+    /// Collapses the topology into a map keyed by room name.
+    /// Satellites are folded into the room of the member to which
+    /// they are bonded, and members that share a room name (such as
+    /// the two halves of a stereo pair) are merged into a single entry.
+    pub fn by_room(&self) -> BTreeMap<String, RoomTopology> {
+        let mut rooms: BTreeMap<String, RoomTopology> = BTreeMap::new();
+
+        for group in &self.groups {
+            let mut group_rooms: Vec<String> = group
+                .members
+                .iter()
+                .map(|member| member.zone_name.clone())
+                .collect();
+            group_rooms.sort();
+            group_rooms.dedup();
+
+            for member in &group.members {
+                let room = rooms
+                    .entry(member.zone_name.clone())
+                    .or_insert_with(|| RoomTopology {
+                        group_id: group.id.clone(),
+                        coordinator: group.coordinator.clone(),
+                        is_coordinator: false,
+                        uuids: vec![],
+                        satellites: vec![],
+                        group_rooms: group_rooms.clone(),
+                        group_room_count: group.room_count(),
+                    });
+                room.uuids.push(member.uuid.clone());
+                room.satellites
+                    .extend(member.satellites.iter().map(|sat| sat.uuid.clone()));
+                if member.uuid == group.coordinator {
+                    room.is_coordinator = true;
+                }
+            }
+        }
+
+        rooms
+    }
+}
+
+/// Describes a room and the group to which it belongs.
+/// Use `SonosDevice::topology_by_room` or `ZoneGroupState::by_room`
+/// to obtain this.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RoomTopology {
+    /// The `id` of the group to which the room belongs
+    pub group_id: String,
+    /// The uuid of the coordinator of that group
+    pub coordinator: String,
+    /// true if the coordinator of the group is in this room
+    pub is_coordinator: bool,
+    /// The uuids of the group members in this room.  This has more
+    /// than one entry for a stereo pair.
+    pub uuids: Vec<String>,
+    /// The uuids of satellites (surrounds, sub) bonded to this room
+    pub satellites: Vec<String>,
+    /// The sorted names of all of the rooms in the group,
+    /// including this one
+    pub group_rooms: Vec<String>,
+    /// The number of rooms in the group; see `ZoneGroup::room_count`
+    pub group_room_count: usize,
+}
+
+impl RoomTopology {
+    /// Returns true if the room is grouped with other rooms
+    pub fn is_grouped(&self) -> bool {
+        self.group_room_count > 1
+    }
+}
+
+/// Identifies a room that can be added to a group.
+/// Use `SonosDevice::groupable_rooms` or
+/// `ZoneGroupState::groupable_rooms` to obtain this.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RoomRef {
+    pub name: String,
+    /// The uuid of the device that should join the group
+    pub uuid: String,
+    /// URL of the device_description.xml of that device, which can
+    /// be passed to `SonosDevice::from_url`
+    pub location: String,
+}
+
+/// A device in the zone group topology, as yielded by
+/// `ZoneGroupState::all_zones_flat`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FlatZone<'a> {
+    /// The `id` of the group to which the device belongs
+    pub group_id: &'a str,
+    /// The uuid of the coordinator of the group
+    pub coordinator: &'a str,
+    pub uuid: &'a str,
+    pub zone_name: &'a str,
+    /// URL of the device_description.xml
+    pub location: &'a str,
+    /// For a satellite, the uuid of the member that it is bonded to
+    pub satellite_of: Option<&'a str>,
+}
+
+impl FlatZone<'_> {
+    pub fn is_coordinator(&self) -> bool {
+        self.satellite_of.is_none() && self.uuid == self.coordinator
+    }
+
+    pub fn is_satellite(&self) -> bool {
+        self.satellite_of.is_some()
+    }
+}
+
 #[derive(Debug, FromXml)]
 #[xml(rename = "ZoneGroupState")]
 struct ZoneGroupStateHelper {
@@ -33,21 +258,109 @@ struct ZoneGroups {
     pub groups: Vec<ZoneGroup>,
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[derive(Debug, FromXml, PartialEq, Eq, Clone)]
 pub struct ZoneGroup {
     #[xml(rename = "Coordinator", attribute)]
     pub coordinator: String,
+    /// Identifies the group, in the form `COORDINATOR_UUID:GENERATION`,
+    /// such as `RINCON_000E58A0123401400:3435548679`.
+    /// Use `coordinator_uuid` and `generation` to obtain its parts.
     #[xml(rename = "ID", attribute)]
     pub id: String,
 
     pub members: Vec<ZoneGroupMember>,
 }
 
+impl ZoneGroup {
+    /// Returns the uuid of the coordinator portion of the `id`.
+    /// This is the device that formed the group, which is usually,
+    /// but not necessarily, the current `coordinator`.
+    pub fn coordinator_uuid(&self) -> &str {
+        match self.id.split_once(':') {
+            Some((uuid, _)) => uuid,
+            None => &self.id,
+        }
+    }
+
+    /// Splits the members of the group into rooms, in the order in
+    /// which each room's first member is listed.
+    /// The members of a stereo pair list one another in their
+    /// `channel_map_set` and form a single room; every other member
+    /// is a room of its own.  Satellites are not members, so they
+    /// never appear here.  Rooms are told apart by their devices
+    /// rather than their names, so two rooms that happen to share
+    /// a name remain separate.
+    fn rooms(&self) -> Vec<Vec<&ZoneGroupMember>> {
+        let mut rooms: Vec<Vec<&ZoneGroupMember>> = vec![];
+        for member in &self.members {
+            let paired = member
+                .channel_map_set
+                .as_deref()
+                .and_then(|text| text.parse::<ChannelMap>().ok())
+                .and_then(|map| {
+                    rooms.iter().position(|room| {
+                        room.iter()
+                            .any(|earlier| map.channels_for(&earlier.uuid).is_some())
+                    })
+                });
+            match paired {
+                Some(idx) => rooms[idx].push(member),
+                None => rooms.push(vec![member]),
+            }
+        }
+        rooms
+    }
+
+    /// Returns one member to stand for each room of the group, in the
+    /// same order as `rooms`: the group coordinator where it is in the
+    /// room, otherwise the left-hand member of a stereo pair, otherwise
+    /// the room's first member.  The right-hand member of a stereo pair
+    /// is hidden by the Sonos app, and is never chosen where the
+    /// channel map identifies its partner.
+    pub(crate) fn room_leaders(&self) -> Vec<&ZoneGroupMember> {
+        self.rooms()
+            .into_iter()
+            .map(|room| {
+                let left = room
+                    .iter()
+                    .filter_map(|member| member.channel_map_set.as_deref())
+                    .filter_map(|text| text.parse::<ChannelMap>().ok())
+                    .find_map(|map| map.uuid_for(&ChannelRole::LeftFront).map(str::to_string));
+                room.iter()
+                    .find(|member| member.uuid == self.coordinator)
+                    .or_else(|| {
+                        room.iter()
+                            .find(|member| Some(&member.uuid) == left.as_ref())
+                    })
+                    .copied()
+                    .unwrap_or(room[0])
+            })
+            .collect()
+    }
+
+    /// Returns the number of rooms in the group.
+    /// The members of a stereo pair count as a single room, and
+    /// satellites are not counted.  Rooms are told apart by their
+    /// devices rather than their names, so two rooms that happen to
+    /// share a name are counted separately.
+    pub fn room_count(&self) -> usize {
+        self.rooms().len()
+    }
+
+    /// Returns the numeric portion of the `id`, which changes each
+    /// time the group is re-formed
+    pub fn generation(&self) -> Option<u64> {
+        self.id.split_once(':')?.1.parse().ok()
+    }
+}
+
 /// Helper for DRY; Satellite and ZoneGroupMember are almost
 /// identical structs but have to be separate in order for
 /// instant_xml to generate appropriate serde logic
 macro_rules! machine_info {
     (pub struct $ty:ident { $($inner:tt)* }) => {
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[derive(Debug, FromXml, PartialEq, Eq, Clone)]
 pub struct $ty {
     $($inner)*
@@ -109,6 +422,12 @@ pub struct $ty {
     pub ssl_port: u16,
     #[xml(rename = "HHSSLPort", attribute)]
     pub hhssl_port: u16,
+    /// The stereo pair channel map; see `ChannelMap`
+    #[xml(rename = "ChannelMapSet", attribute)]
+    pub channel_map_set: Option<String>,
+    /// The home theater channel map; see `ChannelMap`
+    #[xml(rename = "HTSatChanMapSet", attribute)]
+    pub ht_sat_chan_map_set: Option<String>,
 }
     };
 }
@@ -124,6 +443,336 @@ machine_info! {
     }
 }
 
+impl ZoneGroupMember {
+    /// Returns battery information for portable speakers such
+    /// as the Move and Roam, parsed from the `more_info` field.
+    /// Returns None for devices that do not have a battery.
+    pub fn battery(&self) -> Option<BatteryInfo> {
+        BatteryInfo::parse(&self.more_info)
+    }
+
+    /// Returns the room type that was chosen for the icon of this
+    /// room, parsed from an `icon` such as `x-rincon-roomicon:living`.
+    /// Returns None if no room icon is set.
+    pub fn room_icon(&self) -> Option<RoomIcon> {
+        RoomIcon::parse(&self.icon)
+    }
+}
+
+/// The room type that selects the icon shown for a room by the
+/// Sonos app, as found in `ZoneGroupMember::icon`.
+/// Use `ZoneGroupMember::room_icon` to obtain this.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum RoomIcon {
+    Living,
+    MasterBedroom,
+    Bedroom,
+    GuestRoom,
+    KidsRoom,
+    Kitchen,
+    Dining,
+    Bathroom,
+    Office,
+    Den,
+    FamilyRoom,
+    MediaRoom,
+    Garage,
+    Patio,
+    Pool,
+    Outdoor,
+    /// A room type that is not otherwise known to this crate
+    Other(String),
+}
+
+impl RoomIcon {
+    /// Parses a value of the form `x-rincon-roomicon:living`.
+    /// Returns None if the value doesn't use that scheme.
+    pub fn parse(icon: &str) -> Option<Self> {
+        let room = icon.strip_prefix("x-rincon-roomicon:")?;
+        Some(match room {
+            "living" => Self::Living,
+            "masterbedroom" => Self::MasterBedroom,
+            "bedroom" => Self::Bedroom,
+            "guestroom" => Self::GuestRoom,
+            "kidsroom" => Self::KidsRoom,
+            "kitchen" => Self::Kitchen,
+            "dining" => Self::Dining,
+            "bathroom" => Self::Bathroom,
+            "office" => Self::Office,
+            "den" => Self::Den,
+            "familyroom" => Self::FamilyRoom,
+            "mediaroom" => Self::MediaRoom,
+            "garage" => Self::Garage,
+            "patio" => Self::Patio,
+            "pool" => Self::Pool,
+            "outdoor" => Self::Outdoor,
+            other => Self::Other(other.to_string()),
+        })
+    }
+}
+
+impl ZoneGroupMember {
+    /// Classifies the satellites bonded to this member, such as the
+    /// sub and surrounds of a home theater setup, so that the setup can
+    /// be described for display.
+    /// This is a best-effort classification based on the home theater
+    /// channel maps present in the topology, falling back to the zone
+    /// name that the device reports for a sub.
+    pub fn bonded_config(&self) -> BondedConfig {
+        let satellites = self
+            .satellites
+            .iter()
+            .map(|sat| {
+                // Prefer the satellite's own view of the map
+                let maps: Vec<ChannelMap> = [&sat.ht_sat_chan_map_set, &self.ht_sat_chan_map_set]
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|text| text.parse().ok())
+                    .collect();
+                let role = maps
+                    .iter()
+                    .flat_map(|map| map.entries.iter())
+                    .filter(|entry| entry.uuid == sat.uuid)
+                    .map(|entry| SatelliteRole::from_channels(&entry.channels))
+                    .find(|role| *role != SatelliteRole::Unknown)
+                    .unwrap_or(if sat.zone_name == "Sub" {
+                        SatelliteRole::Sub
+                    } else {
+                        SatelliteRole::Unknown
+                    });
+                BondedSatellite {
+                    uuid: sat.uuid.clone(),
+                    role,
+                }
+            })
+            .collect();
+
+        BondedConfig { satellites }
+    }
+}
+
+/// The role that a satellite plays in a home theater setup
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SatelliteRole {
+    Sub,
+    SurroundLeft,
+    SurroundRight,
+    Unknown,
+}
+
+impl SatelliteRole {
+    fn from_channels(channels: &[ChannelRole]) -> Self {
+        if channels.contains(&ChannelRole::Subwoofer) {
+            Self::Sub
+        } else if channels.contains(&ChannelRole::LeftRear) {
+            Self::SurroundLeft
+        } else if channels.contains(&ChannelRole::RightRear) {
+            Self::SurroundRight
+        } else {
+            Self::Unknown
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BondedSatellite {
+    pub uuid: String,
+    pub role: SatelliteRole,
+}
+
+/// The satellites bonded to a group member.
+/// Use `ZoneGroupMember::bonded_config` to obtain this.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BondedConfig {
+    pub satellites: Vec<BondedSatellite>,
+}
+
+impl BondedConfig {
+    pub fn is_empty(&self) -> bool {
+        self.satellites.is_empty()
+    }
+
+    pub fn has_sub(&self) -> bool {
+        self.count(SatelliteRole::Sub) > 0
+    }
+
+    /// Returns the number of satellites that play the specified role
+    pub fn count(&self, role: SatelliteRole) -> usize {
+        self.satellites
+            .iter()
+            .filter(|sat| sat.role == role)
+            .count()
+    }
+
+    /// Returns the number of surround speakers
+    pub fn surround_count(&self) -> usize {
+        self.count(SatelliteRole::SurroundLeft) + self.count(SatelliteRole::SurroundRight)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChargeState {
+    Charging,
+    NotCharging,
+    Unspecified(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BatteryInfo {
+    /// Charge level in the range 0-100
+    pub percent: u8,
+    pub charging: ChargeState,
+    pub temperature_c: Option<i32>,
+}
+
+impl BatteryInfo {
+    /// Parses the comma separated `Key:Value` pairs that are
+    /// present in `ZoneGroupMember::more_info`, such as
+    /// `RawBattPct:99,BattPct:100,BattChg:CHARGING,BattTmp:33`
+    pub fn parse(more_info: &str) -> Option<Self> {
+        let mut percent = None;
+        let mut charging = None;
+        let mut temperature_c = None;
+
+        for pair in more_info.split(',') {
+            let Some((key, value)) = pair.split_once(':') else {
+                continue;
+            };
+            let value = value.trim();
+            match key.trim() {
+                "BattPct" => percent = value.parse().ok(),
+                "BattChg" => {
+                    charging = Some(match value {
+                        "CHARGING" => ChargeState::Charging,
+                        "NOT_CHARGING" => ChargeState::NotCharging,
+                        _ => ChargeState::Unspecified(value.to_string()),
+                    })
+                }
+                "BattTmp" => temperature_c = value.parse().ok(),
+                _ => {}
+            }
+        }
+
+        Some(Self {
+            percent: percent?,
+            charging: charging.unwrap_or_else(|| ChargeState::Unspecified(String::new())),
+            temperature_c,
+        })
+    }
+}
+
+/// Hardware details for a device, as reported by the
+/// DeviceProperties GetZoneInfo action.
+/// Use `SonosDevice::zone_info` to obtain this.
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ZoneInfo {
+    pub serial_number: String,
+    /// The MAC address, formatted like `00:0E:58:A0:12:34`
+    pub mac_address: String,
+    pub ip_address: std::net::IpAddr,
+    pub software_version: String,
+    pub display_software_version: Option<String>,
+    pub hardware_version: Option<String>,
+}
+
+impl TryFrom<crate::device_properties::GetZoneInfoResponse> for ZoneInfo {
+    type Error = crate::Error;
+
+    fn try_from(info: crate::device_properties::GetZoneInfoResponse) -> crate::Result<Self> {
+        use crate::Error::MissingResponseField;
+        Ok(Self {
+            serial_number: info
+                .serial_number
+                .ok_or(MissingResponseField("SerialNumber"))?,
+            mac_address: info.mac_address.ok_or(MissingResponseField("MACAddress"))?,
+            ip_address: {
+                let ip = info.ip_address.ok_or(MissingResponseField("IPAddress"))?;
+                ip.parse().map_err(|_| crate::Error::InvalidIpAddress(ip))?
+            },
+            software_version: info
+                .software_version
+                .ok_or(MissingResponseField("SoftwareVersion"))?,
+            display_software_version: info.display_software_version,
+            hardware_version: info.hardware_version,
+        })
+    }
+}
+
+/// The identity of the group that a device belongs to, as reported
+/// by the ZoneGroupTopology GetZoneGroupAttributes action.
+/// This is cheaper to obtain than the full `ZoneGroupState`.
+/// Use `SonosDevice::zone_group_attributes` to obtain this.
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ZoneGroupAttributes {
+    /// The name of the group, such as `Kitchen + 1`
+    pub current_zone_group_name: String,
+    /// The group id, in the same form as `ZoneGroup::id`
+    pub current_zone_group_id: String,
+    /// The uuids of the devices in the group, excluding satellites
+    pub member_uuids: Vec<String>,
+    pub household_id: Option<String>,
+}
+
+impl TryFrom<crate::zone_group_topology::GetZoneGroupAttributesResponse> for ZoneGroupAttributes {
+    type Error = crate::Error;
+
+    fn try_from(
+        attrs: crate::zone_group_topology::GetZoneGroupAttributesResponse,
+    ) -> crate::Result<Self> {
+        use crate::Error::MissingResponseField;
+        Ok(Self {
+            current_zone_group_name: attrs
+                .current_zone_group_name
+                .ok_or(MissingResponseField("CurrentZoneGroupName"))?,
+            current_zone_group_id: attrs
+                .current_zone_group_id
+                .ok_or(MissingResponseField("CurrentZoneGroupID"))?,
+            member_uuids: attrs
+                .current_zone_player_uuids_in_group
+                .unwrap_or_default()
+                .split(',')
+                .filter(|uuid| !uuid.is_empty())
+                .map(|uuid| uuid.to_string())
+                .collect(),
+            household_id: attrs.current_muse_household_id.filter(|id| !id.is_empty()),
+        })
+    }
+}
+
+/// A ZoneGroupTopology event stream that re-reads the full zone
+/// group state from the device each time an event is received.
+/// Use `SonosDevice::subscribe_topology_with_state` to obtain one.
+pub struct TopologyStream {
+    events: EventStream<ZoneGroupTopologyEvent>,
+    device: SonosDevice,
+}
+
+impl TopologyStream {
+    pub(crate) fn new(events: EventStream<ZoneGroupTopologyEvent>, device: SonosDevice) -> Self {
+        Self { events, device }
+    }
+
+    /// Receives the next event from the stream, together with the
+    /// zone group state that was fetched in response to it.
+    /// Returns `Ok(None)` once the stream has ended.
+    pub async fn recv(
+        &mut self,
+    ) -> crate::Result<Option<(ZoneGroupTopologyEvent, Vec<ZoneGroup>)>> {
+        let Some(event) = self.events.recv().await? else {
+            return Ok(None);
+        };
+        let groups = self.device.get_zone_group_state().await?;
+        Ok(Some((event, groups)))
+    }
+
+    /// Explicitly cancel the subscription
+    pub async fn unsubscribe(self) {
+        self.events.unsubscribe().await
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -131,19 +780,19 @@ mod test {
     #[test]
     fn test_parse_group_state() {
         let group_state = include_str!("../data/zone_group_state.xml");
-        let parsed = ZoneGroupState::decode_xml(&group_state).unwrap();
+        let parsed = ZoneGroupState::decode_xml(group_state).unwrap();
         k9::snapshot!(
             parsed,
             r#"
 ZoneGroupState {
     groups: [
         ZoneGroup {
-            coordinator: "RINCON_XXX",
-            id: "RINCON_XXX:3435548679",
+            coordinator: "RINCON_000E5800016101400",
+            id: "RINCON_000E5800016101400:3435548679",
             members: [
                 ZoneGroupMember {
                     satellites: [],
-                    uuid: "RINCON_XXX",
+                    uuid: "RINCON_000E5800016101400",
                     location: "http://10.10.10.161:1400/xml/device_description.xml",
                     zone_name: "Primary Bath",
                     icon: "",
@@ -171,17 +820,19 @@ ZoneGroupState {
                     more_info: "RawBattPct:99,BattPct:100,BattChg:CHARGING,BattTmp:33",
                     ssl_port: 1443,
                     hhssl_port: 1843,
+                    channel_map_set: None,
+                    ht_sat_chan_map_set: None,
                 },
             ],
         },
         ZoneGroup {
-            coordinator: "RINCON_XXX",
-            id: "RINCON_XXX:3326086195",
+            coordinator: "RINCON_000E5800019601400",
+            id: "RINCON_000E5800019601400:3326086195",
             members: [
                 ZoneGroupMember {
                     satellites: [
                         Satellite {
-                            uuid: "RINCON_XXX",
+                            uuid: "RINCON_000E5800013101400",
                             location: "http://10.10.10.131:1400/xml/device_description.xml",
                             zone_name: "Some Room",
                             icon: "",
@@ -209,9 +860,13 @@ ZoneGroupState {
                             more_info: "",
                             ssl_port: 1443,
                             hhssl_port: 1843,
+                            channel_map_set: None,
+                            ht_sat_chan_map_set: Some(
+                                "RINCON_000E5800019601400:LF,RF;RINCON_000E5800013101400:LR",
+                            ),
                         },
                         Satellite {
-                            uuid: "RINCON_XXX",
+                            uuid: "RINCON_000E5800022601400",
                             location: "http://10.10.10.226:1400/xml/device_description.xml",
                             zone_name: "Some Room",
                             icon: "",
@@ -239,9 +894,13 @@ ZoneGroupState {
                             more_info: "",
                             ssl_port: 1443,
                             hhssl_port: 1843,
+                            channel_map_set: None,
+                            ht_sat_chan_map_set: Some(
+                                "RINCON_000E5800019601400:LF,RF;RINCON_000E5800022601400:RR",
+                            ),
                         },
                     ],
-                    uuid: "RINCON_XXX",
+                    uuid: "RINCON_000E5800019601400",
                     location: "http://10.10.10.196:1400/xml/device_description.xml",
                     zone_name: "Some Room",
                     icon: "",
@@ -269,16 +928,20 @@ ZoneGroupState {
                     more_info: "",
                     ssl_port: 1443,
                     hhssl_port: 1843,
+                    channel_map_set: None,
+                    ht_sat_chan_map_set: Some(
+                        "RINCON_000E5800019601400:LF,RF;RINCON_000E5800013101400:LR;RINCON_000E5800022601400:RR",
+                    ),
                 },
             ],
         },
         ZoneGroup {
-            coordinator: "RINCON_XXX",
-            id: "RINCON_XXX:2302873263",
+            coordinator: "RINCON_000E5800016601400",
+            id: "RINCON_000E5800016601400:2302873263",
             members: [
                 ZoneGroupMember {
                     satellites: [],
-                    uuid: "RINCON_XXX",
+                    uuid: "RINCON_000E5800016601400",
                     location: "http://10.10.10.166:1400/xml/device_description.xml",
                     zone_name: "Study",
                     icon: "",
@@ -306,16 +969,18 @@ ZoneGroupState {
                     more_info: "TargetRoomName:Study",
                     ssl_port: 1443,
                     hhssl_port: 1843,
+                    channel_map_set: None,
+                    ht_sat_chan_map_set: None,
                 },
             ],
         },
         ZoneGroup {
-            coordinator: "RINCON_XXX",
-            id: "RINCON_XXX:4111376911",
+            coordinator: "RINCON_000E5800013801400",
+            id: "RINCON_000E5800013801400:4111376911",
             members: [
                 ZoneGroupMember {
                     satellites: [],
-                    uuid: "RINCON_XXX",
+                    uuid: "RINCON_000E5800013801400",
                     location: "http://10.10.10.138:1400/xml/device_description.xml",
                     zone_name: "Beam",
                     icon: "x-rincon-roomicon:masterbedroom",
@@ -343,16 +1008,18 @@ ZoneGroupState {
                     more_info: "",
                     ssl_port: 1443,
                     hhssl_port: 1843,
+                    channel_map_set: None,
+                    ht_sat_chan_map_set: None,
                 },
             ],
         },
         ZoneGroup {
-            coordinator: "RINCON_XXX",
-            id: "RINCON_XXX:2134456247",
+            coordinator: "RINCON_000E5800016501400",
+            id: "RINCON_000E5800016501400:2134456247",
             members: [
                 ZoneGroupMember {
                     satellites: [],
-                    uuid: "RINCON_XXX",
+                    uuid: "RINCON_000E5800016501400",
                     location: "http://10.10.10.165:1400/xml/device_description.xml",
                     zone_name: "Kitchen (Move)",
                     icon: "",
@@ -380,17 +1047,19 @@ ZoneGroupState {
                     more_info: "RawBattPct:100,BattPct:100,BattChg:CHARGING,BattTmp:27",
                     ssl_port: 1443,
                     hhssl_port: 1843,
+                    channel_map_set: None,
+                    ht_sat_chan_map_set: None,
                 },
             ],
         },
         ZoneGroup {
-            coordinator: "RINCON_XXX",
-            id: "RINCON_XXX:2884078592",
+            coordinator: "RINCON_000E5800023101400",
+            id: "RINCON_000E5800023101400:2884078592",
             members: [
                 ZoneGroupMember {
                     satellites: [
                         Satellite {
-                            uuid: "RINCON_XXX",
+                            uuid: "RINCON_000E5800019001400",
                             location: "http://10.10.10.190:1400/xml/device_description.xml",
                             zone_name: "Primary Bedroom",
                             icon: "x-rincon-roomicon:masterbedroom",
@@ -418,9 +1087,13 @@ ZoneGroupState {
                             more_info: "",
                             ssl_port: 1443,
                             hhssl_port: 1843,
+                            channel_map_set: None,
+                            ht_sat_chan_map_set: Some(
+                                "RINCON_000E5800023101400:LF,RF;RINCON_000E5800019001400:RR",
+                            ),
                         },
                         Satellite {
-                            uuid: "RINCON_XXX",
+                            uuid: "RINCON_000E5800019801400",
                             location: "http://10.10.10.198:1400/xml/device_description.xml",
                             zone_name: "Primary Bedroom",
                             icon: "x-rincon-roomicon:masterbedroom",
@@ -448,9 +1121,13 @@ ZoneGroupState {
                             more_info: "",
                             ssl_port: 1443,
                             hhssl_port: 1843,
+                            channel_map_set: None,
+                            ht_sat_chan_map_set: Some(
+                                "RINCON_000E5800023101400:LF,RF;RINCON_000E5800019801400:LR",
+                            ),
                         },
                         Satellite {
-                            uuid: "RINCON_XXX",
+                            uuid: "RINCON_000E5800011601400",
                             location: "http://10.10.10.116:1400/xml/device_description.xml",
                             zone_name: "Sub",
                             icon: "",
@@ -478,9 +1155,13 @@ ZoneGroupState {
                             more_info: "",
                             ssl_port: 1443,
                             hhssl_port: 1843,
+                            channel_map_set: None,
+                            ht_sat_chan_map_set: Some(
+                                "RINCON_000E5800023101400:LF,RF;RINCON_000E5800011601400:SW",
+                            ),
                         },
                     ],
-                    uuid: "RINCON_XXX",
+                    uuid: "RINCON_000E5800023101400",
                     location: "http://10.10.10.231:1400/xml/device_description.xml",
                     zone_name: "Primary Bedroom",
                     icon: "",
@@ -508,16 +1189,20 @@ ZoneGroupState {
                     more_info: "",
                     ssl_port: 1443,
                     hhssl_port: 1843,
+                    channel_map_set: None,
+                    ht_sat_chan_map_set: Some(
+                        "RINCON_000E5800023101400:LF,RF;RINCON_000E5800011601400:SW;RINCON_000E5800019801400:LR;RINCON_000E5800019001400:RR",
+                    ),
                 },
             ],
         },
         ZoneGroup {
-            coordinator: "RINCON_XXX",
-            id: "RINCON_XXX:1940091512",
+            coordinator: "RINCON_000E5800015701400",
+            id: "RINCON_000E5800015701400:1940091512",
             members: [
                 ZoneGroupMember {
                     satellites: [],
-                    uuid: "RINCON_XXX",
+                    uuid: "RINCON_000E5800015701400",
                     location: "http://10.10.10.157:1400/xml/device_description.xml",
                     zone_name: "Great Room",
                     icon: "",
@@ -545,16 +1230,18 @@ ZoneGroupState {
                     more_info: "",
                     ssl_port: 1443,
                     hhssl_port: 1843,
+                    channel_map_set: None,
+                    ht_sat_chan_map_set: None,
                 },
             ],
         },
         ZoneGroup {
-            coordinator: "RINCON_XXX",
-            id: "RINCON_XXX:2667033389",
+            coordinator: "RINCON_000E5800015801400",
+            id: "RINCON_000E5800015801400:2667033389",
             members: [
                 ZoneGroupMember {
                     satellites: [],
-                    uuid: "RINCON_XXX",
+                    uuid: "RINCON_000E5800012001400",
                     location: "http://10.10.10.120:1400/xml/device_description.xml",
                     zone_name: "Other Room",
                     icon: "x-rincon-roomicon:living",
@@ -582,10 +1269,14 @@ ZoneGroupState {
                     more_info: "",
                     ssl_port: 1443,
                     hhssl_port: 1843,
+                    channel_map_set: Some(
+                        "RINCON_000E5800015801400:LF,LF;RINCON_000E5800012001400:RF,RF;RINCON_000E5800021701400:SW,SW",
+                    ),
+                    ht_sat_chan_map_set: None,
                 },
                 ZoneGroupMember {
                     satellites: [],
-                    uuid: "RINCON_XXX",
+                    uuid: "RINCON_000E5800015801400",
                     location: "http://10.10.10.158:1400/xml/device_description.xml",
                     zone_name: "Other Room",
                     icon: "x-rincon-roomicon:living",
@@ -613,10 +1304,14 @@ ZoneGroupState {
                     more_info: "",
                     ssl_port: 1443,
                     hhssl_port: 1843,
+                    channel_map_set: Some(
+                        "RINCON_000E5800015801400:LF,LF;RINCON_000E5800012001400:RF,RF;RINCON_000E5800021701400:SW,SW",
+                    ),
+                    ht_sat_chan_map_set: None,
                 },
                 ZoneGroupMember {
                     satellites: [],
-                    uuid: "RINCON_XXX",
+                    uuid: "RINCON_000E5800021701400",
                     location: "http://10.10.10.217:1400/xml/device_description.xml",
                     zone_name: "Other Room",
                     icon: "x-rincon-roomicon:living",
@@ -644,16 +1339,20 @@ ZoneGroupState {
                     more_info: "",
                     ssl_port: 1443,
                     hhssl_port: 1843,
+                    channel_map_set: Some(
+                        "RINCON_000E5800015801400:LF,LF;RINCON_000E5800012001400:RF,RF;RINCON_000E5800021701400:SW,SW",
+                    ),
+                    ht_sat_chan_map_set: None,
                 },
             ],
         },
         ZoneGroup {
-            coordinator: "RINCON_XXX",
-            id: "RINCON_XXX:97",
+            coordinator: "RINCON_000E5800023601400",
+            id: "RINCON_000E5800023601400:97",
             members: [
                 ZoneGroupMember {
                     satellites: [],
-                    uuid: "RINCON_XXX",
+                    uuid: "RINCON_000E5800023601400",
                     location: "http://10.10.10.236:1400/xml/device_description.xml",
                     zone_name: "Kitchen",
                     icon: "x-rincon-roomicon:masterbedroom",
@@ -681,11 +1380,575 @@ ZoneGroupState {
                     more_info: "",
                     ssl_port: 1443,
                     hhssl_port: 1843,
+                    channel_map_set: None,
+                    ht_sat_chan_map_set: None,
                 },
             ],
         },
     ],
 }
+"#
+        );
+    }
+
+    #[test]
+    fn test_battery() {
+        k9::snapshot!(
+            BatteryInfo::parse("RawBattPct:99,BattPct:100,BattChg:CHARGING,BattTmp:33"),
+            "
+Some(
+    BatteryInfo {
+        percent: 100,
+        charging: Charging,
+        temperature_c: Some(
+            33,
+        ),
+    },
+)
+"
+        );
+        k9::assert_equal!(BatteryInfo::parse(""), None);
+        k9::assert_equal!(BatteryInfo::parse("SomethingElse:1"), None);
+    }
+
+    #[test]
+    fn test_zone_info() {
+        use crate::device_properties::GetZoneInfoResponse;
+        use crate::DecodeSoapResponse;
+
+        let response = GetZoneInfoResponse::decode_soap_xml(
+            r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:GetZoneInfoResponse xmlns:u="urn:schemas-upnp-org:service:DeviceProperties:1"><SerialNumber>00-0E-58-A0-12-34:5</SerialNumber><SoftwareVersion>79.1-56030</SoftwareVersion><DisplaySoftwareVersion>16.3</DisplaySoftwareVersion><HardwareVersion>1.8.3.7-2.0</HardwareVersion><IPAddress>10.10.10.10</IPAddress><MACAddress>00:0E:58:A0:12:34</MACAddress><CopyrightInfo>© 2004-2024 Sonos, Inc. All Rights Reserved.</CopyrightInfo><ExtraInfo></ExtraInfo><HTAudioIn>0</HTAudioIn><Flags>0</Flags></u:GetZoneInfoResponse></s:Body></s:Envelope>"#,
+        )
+        .unwrap();
+        k9::snapshot!(
+            ZoneInfo::try_from(response).unwrap(),
+            r#"
+ZoneInfo {
+    serial_number: "00-0E-58-A0-12-34:5",
+    mac_address: "00:0E:58:A0:12:34",
+    ip_address: 10.10.10.10,
+    software_version: "79.1-56030",
+    display_software_version: Some(
+        "16.3",
+    ),
+    hardware_version: Some(
+        "1.8.3.7-2.0",
+    ),
+}
+"#
+        );
+
+        let response = GetZoneInfoResponse::decode_soap_xml(
+            r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:GetZoneInfoResponse xmlns:u="urn:schemas-upnp-org:service:DeviceProperties:1"><SerialNumber>00-0E-58-A0-12-34:5</SerialNumber><SoftwareVersion>79.1-56030</SoftwareVersion><IPAddress>10.10.10</IPAddress><MACAddress>00:0E:58:A0:12:34</MACAddress></u:GetZoneInfoResponse></s:Body></s:Envelope>"#,
+        )
+        .unwrap();
+        assert!(matches!(
+            ZoneInfo::try_from(response),
+            Err(crate::Error::InvalidIpAddress(ip)) if ip == "10.10.10"
+        ));
+    }
+
+    #[test]
+    fn test_all_zones_flat() {
+        let group_state = include_str!("../data/zone_group_state.xml");
+        let parsed = ZoneGroupState::decode_xml(group_state).unwrap();
+
+        k9::assert_equal!(parsed.all_members().count(), 11);
+        k9::assert_equal!(parsed.all_zones_flat(false).count(), 11);
+
+        let satellites: Vec<(&str, &str)> = parsed
+            .all_zones_flat(true)
+            .filter(|zone| zone.is_satellite())
+            .map(|zone| (zone.group_id, zone.location))
+            .collect();
+        k9::snapshot!(
+            satellites,
+            r#"
+[
+    (
+        "RINCON_000E5800019601400:3326086195",
+        "http://10.10.10.131:1400/xml/device_description.xml",
+    ),
+    (
+        "RINCON_000E5800019601400:3326086195",
+        "http://10.10.10.226:1400/xml/device_description.xml",
+    ),
+    (
+        "RINCON_000E5800023101400:2884078592",
+        "http://10.10.10.190:1400/xml/device_description.xml",
+    ),
+    (
+        "RINCON_000E5800023101400:2884078592",
+        "http://10.10.10.198:1400/xml/device_description.xml",
+    ),
+    (
+        "RINCON_000E5800023101400:2884078592",
+        "http://10.10.10.116:1400/xml/device_description.xml",
+    ),
+]
+"#
+        );
+    }
+
+    #[test]
+    fn test_by_room() {
+        let group_state = include_str!("../data/zone_group_state.xml");
+        let parsed = ZoneGroupState::decode_xml(group_state).unwrap();
+        let rooms = parsed.by_room();
+        let summary: Vec<(&str, usize, usize, bool)> = rooms
+            .iter()
+            .map(|(name, room)| {
+                (
+                    name.as_str(),
+                    room.uuids.len(),
+                    room.satellites.len(),
+                    room.is_grouped(),
+                )
+            })
+            .collect();
+        k9::snapshot!(
+            summary,
+            r#"
+[
+    (
+        "Beam",
+        1,
+        0,
+        false,
+    ),
+    (
+        "Great Room",
+        1,
+        0,
+        false,
+    ),
+    (
+        "Kitchen",
+        1,
+        0,
+        false,
+    ),
+    (
+        "Kitchen (Move)",
+        1,
+        0,
+        false,
+    ),
+    (
+        "Other Room",
+        3,
+        0,
+        false,
+    ),
+    (
+        "Primary Bath",
+        1,
+        0,
+        false,
+    ),
+    (
+        "Primary Bedroom",
+        1,
+        3,
+        false,
+    ),
+    (
+        "Some Room",
+        1,
+        2,
+        false,
+    ),
+    (
+        "Study",
+        1,
+        0,
+        false,
+    ),
+]
+"#
+        );
+    }
+
+    #[test]
+    fn test_group_management_event() {
+        use crate::group_management::GroupManagementEvent;
+        let event = GroupManagementEvent::decode_xml(
+            r#"<e:propertyset xmlns:e="urn:schemas-upnp-org:event-1-0"><e:property><GroupCoordinatorIsLocal>1</GroupCoordinatorIsLocal></e:property><e:property><LocalGroupUUID>RINCON_000E58A0123401400:1234567890</LocalGroupUUID></e:property><e:property><VirtualLineInGroupID></VirtualLineInGroupID></e:property><e:property><ResetVolumeAfter>0</ResetVolumeAfter></e:property><e:property><VolumeAVTransportURI></VolumeAVTransportURI></e:property></e:propertyset>"#,
+        )
+        .unwrap();
+        k9::snapshot!(
+            event,
+            r#"
+GroupManagementEvent {
+    group_coordinator_is_local: Some(
+        true,
+    ),
+    local_group_uuid: Some(
+        "RINCON_000E58A0123401400:1234567890",
+    ),
+    reset_volume_after: Some(
+        false,
+    ),
+    virtual_line_in_group_id: Some(
+        "",
+    ),
+    volume_av_transport_uri: Some(
+        "",
+    ),
+}
+"#
+        );
+    }
+
+    #[test]
+    fn test_bonded_config() {
+        let group_state = include_str!("../data/zone_group_state.xml");
+        let parsed = ZoneGroupState::decode_xml(group_state).unwrap();
+        let bedroom = parsed
+            .all_members()
+            .find(|member| member.zone_name == "Primary Bedroom")
+            .unwrap();
+        k9::snapshot!(
+            bedroom.bonded_config(),
+            r#"
+BondedConfig {
+    satellites: [
+        BondedSatellite {
+            uuid: "RINCON_000E5800019001400",
+            role: SurroundRight,
+        },
+        BondedSatellite {
+            uuid: "RINCON_000E5800019801400",
+            role: SurroundLeft,
+        },
+        BondedSatellite {
+            uuid: "RINCON_000E5800011601400",
+            role: Sub,
+        },
+    ],
+}
+"#
+        );
+
+        // Each satellite is matched by its uuid, so the result is the
+        // same when only the member's map, which lists the satellites
+        // in a different order, is available
+        let mut member_map_only = bedroom.clone();
+        for sat in &mut member_map_only.satellites {
+            sat.ht_sat_chan_map_set = None;
+        }
+        k9::assert_equal!(member_map_only.bonded_config(), bedroom.bonded_config());
+
+        let some_room = parsed
+            .all_members()
+            .find(|member| member.zone_name == "Some Room")
+            .unwrap();
+        let roles: Vec<SatelliteRole> = some_room
+            .bonded_config()
+            .satellites
+            .iter()
+            .map(|sat| sat.role)
+            .collect();
+        k9::assert_equal!(
+            roles,
+            vec![SatelliteRole::SurroundLeft, SatelliteRole::SurroundRight]
+        );
+
+        let config = BondedConfig {
+            satellites: vec![
+                BondedSatellite {
+                    uuid: "RINCON_SUB".to_string(),
+                    role: SatelliteRole::Sub,
+                },
+                BondedSatellite {
+                    uuid: "RINCON_LR".to_string(),
+                    role: SatelliteRole::SurroundLeft,
+                },
+            ],
+        };
+        assert!(config.has_sub());
+        k9::assert_equal!(config.surround_count(), 1);
+    }
+
+    #[test]
+    fn test_is_grouped() {
+        let group_state = include_str!("../data/zone_group_state.xml");
+        let mut parsed = ZoneGroupState::decode_xml(group_state).unwrap();
+
+        // A stereo pair on its own is not grouped
+        k9::assert_equal!(parsed.is_grouped("RINCON_000E5800012001400"), Some(false));
+        k9::assert_equal!(parsed.is_grouped("RINCON_unknown"), None);
+
+        // Merge the Kitchen into the Other Room group
+        let kitchen = parsed
+            .groups
+            .iter()
+            .position(|group| group.members[0].zone_name == "Kitchen")
+            .unwrap();
+        let kitchen = parsed.groups.remove(kitchen);
+        let other_room = parsed
+            .groups
+            .iter_mut()
+            .find(|group| group.members[0].zone_name == "Other Room")
+            .unwrap();
+        other_room.members.extend(kitchen.members.clone());
+        k9::assert_equal!(parsed.is_grouped(&kitchen.members[0].uuid), Some(true));
+        assert!(parsed.by_room()["Kitchen"].is_grouped());
+
+        // Distinct rooms that share a name are still grouped
+        let mut study = parsed
+            .groups
+            .iter()
+            .find(|group| group.members[0].zone_name == "Study")
+            .unwrap()
+            .clone();
+        let mut twin = study.members[0].clone();
+        twin.uuid = "RINCON_TWIN".to_string();
+        twin.location = "http://10.10.10.250:1400/xml/device_description.xml".to_string();
+        study.members.push(twin);
+        k9::assert_equal!(study.room_count(), 2);
+        let state = ZoneGroupState::from(vec![study]);
+        k9::assert_equal!(state.is_grouped("RINCON_TWIN"), Some(true));
+        assert!(state.by_room()["Study"].is_grouped());
+    }
+
+    #[test]
+    fn test_room_icon() {
+        let group_state = include_str!("../data/zone_group_state.xml");
+        let parsed = ZoneGroupState::decode_xml(group_state).unwrap();
+        let icons: Vec<(&str, Option<RoomIcon>)> = parsed
+            .all_members()
+            .map(|member| (member.zone_name.as_str(), member.room_icon()))
+            .collect();
+        k9::snapshot!(
+            icons,
+            r#"
+[
+    (
+        "Primary Bath",
+        None,
+    ),
+    (
+        "Some Room",
+        None,
+    ),
+    (
+        "Study",
+        None,
+    ),
+    (
+        "Beam",
+        Some(
+            MasterBedroom,
+        ),
+    ),
+    (
+        "Kitchen (Move)",
+        None,
+    ),
+    (
+        "Primary Bedroom",
+        None,
+    ),
+    (
+        "Great Room",
+        None,
+    ),
+    (
+        "Other Room",
+        Some(
+            Living,
+        ),
+    ),
+    (
+        "Other Room",
+        Some(
+            Living,
+        ),
+    ),
+    (
+        "Other Room",
+        Some(
+            Living,
+        ),
+    ),
+    (
+        "Kitchen",
+        Some(
+            MasterBedroom,
+        ),
+    ),
+]
+"#
+        );
+
+        k9::assert_equal!(
+            RoomIcon::parse("x-rincon-roomicon:gym"),
+            Some(RoomIcon::Other("gym".to_string()))
+        );
+        k9::assert_equal!(RoomIcon::parse(""), None);
+    }
+
+    #[test]
+    fn test_stereo_pair_map() {
+        let group_state = include_str!("../data/zone_group_state.xml");
+        let parsed = ZoneGroupState::decode_xml(group_state).unwrap();
+        let map = parsed.stereo_pair_map("RINCON_000E5800012001400").unwrap();
+        k9::assert_equal!(
+            map.to_string(),
+            "RINCON_000E5800015801400:LF,LF;RINCON_000E5800012001400:RF,RF;RINCON_000E5800021701400:SW,SW"
+        );
+        // Unlike channel_map, home theater maps are not considered
+        k9::assert_equal!(parsed.stereo_pair_map("RINCON_000E5800023101400"), None);
+        k9::assert_equal!(parsed.stereo_pair_map("RINCON_unknown"), None);
+    }
+
+    #[test]
+    fn test_group_id() {
+        let group_state = include_str!("../data/zone_group_state.xml");
+        let parsed = ZoneGroupState::decode_xml(group_state).unwrap();
+        let group = &parsed.groups[0];
+        k9::assert_equal!(group.id, "RINCON_000E5800016101400:3435548679");
+        k9::assert_equal!(group.coordinator_uuid(), "RINCON_000E5800016101400");
+        k9::assert_equal!(group.generation(), Some(3435548679));
+
+        let group = ZoneGroup {
+            coordinator: "RINCON_A".to_string(),
+            id: "RINCON_A".to_string(),
+            members: vec![],
+        };
+        k9::assert_equal!(group.coordinator_uuid(), "RINCON_A");
+        k9::assert_equal!(group.generation(), None);
+    }
+
+    #[test]
+    fn test_groupable_rooms() {
+        let group_state = include_str!("../data/zone_group_state.xml");
+        let parsed = ZoneGroupState::decode_xml(group_state).unwrap();
+        let kitchen = parsed
+            .all_members()
+            .find(|member| member.zone_name == "Kitchen")
+            .unwrap()
+            .uuid
+            .clone();
+
+        let rooms = parsed.groupable_rooms(&kitchen);
+        let names: Vec<&str> = rooms.iter().map(|room| room.name.as_str()).collect();
+        assert!(!names.contains(&"Kitchen"));
+        k9::snapshot!(
+            names,
+            r#"
+[
+    "Beam",
+    "Great Room",
+    "Kitchen (Move)",
+    "Other Room",
+    "Primary Bath",
+    "Primary Bedroom",
+    "Some Room",
+    "Study",
+]
+"#
+        );
+        // Each room is represented by its coordinator
+        for room in &rooms {
+            assert!(parsed
+                .groups
+                .iter()
+                .any(|group| group.coordinator == room.uuid));
+        }
+    }
+
+    #[test]
+    fn test_groupable_rooms_shared_name() {
+        let group_state = include_str!("../data/zone_group_state.xml");
+        let mut parsed = ZoneGroupState::decode_xml(group_state).unwrap();
+        let kitchen = parsed
+            .all_members()
+            .find(|member| member.zone_name == "Kitchen")
+            .unwrap()
+            .uuid
+            .clone();
+        for member in parsed
+            .groups
+            .iter_mut()
+            .flat_map(|group| group.members.iter_mut())
+        {
+            match member.zone_name.as_str() {
+                "Study" => member.zone_name = "Kitchen".to_string(),
+                "Beam" => member.zone_name = "Great Room".to_string(),
+                _ => {}
+            }
+        }
+
+        let rooms = parsed.groupable_rooms(&kitchen);
+        assert!(rooms.iter().all(|room| room.uuid != kitchen));
+        let names: Vec<&str> = rooms.iter().map(|room| room.name.as_str()).collect();
+        k9::snapshot!(
+            names,
+            r#"
+[
+    "Great Room",
+    "Great Room",
+    "Kitchen",
+    "Kitchen (Move)",
+    "Other Room",
+    "Primary Bath",
+    "Primary Bedroom",
+    "Some Room",
+]
+"#
+        );
+    }
+
+    #[test]
+    fn test_room_leaders() {
+        let group_state = include_str!("../data/zone_group_state.xml");
+        let parsed = ZoneGroupState::decode_xml(group_state).unwrap();
+        let mut pair = parsed
+            .groups
+            .iter()
+            .find(|group| group.coordinator == "RINCON_000E5800015801400")
+            .unwrap()
+            .clone();
+        let leaders = |group: &ZoneGroup| -> Vec<String> {
+            group
+                .room_leaders()
+                .iter()
+                .map(|member| member.uuid.clone())
+                .collect()
+        };
+        k9::assert_equal!(leaders(&pair), vec!["RINCON_000E5800015801400".to_string()]);
+
+        // Without the coordinator in the pair, the left-hand member
+        // is preferred over the right-hand member listed before it
+        pair.coordinator = "RINCON_ELSEWHERE".to_string();
+        k9::assert_equal!(leaders(&pair), vec!["RINCON_000E5800015801400".to_string()]);
+    }
+
+    #[test]
+    fn test_zone_group_attributes() {
+        use crate::zone_group_topology::GetZoneGroupAttributesResponse;
+        use crate::DecodeSoapResponse;
+
+        let response = GetZoneGroupAttributesResponse::decode_soap_xml(
+            r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:GetZoneGroupAttributesResponse xmlns:u="urn:schemas-upnp-org:service:ZoneGroupTopology:1"><CurrentZoneGroupName>Kitchen + 1</CurrentZoneGroupName><CurrentZoneGroupID>RINCON_000E58A0123401400:3435548679</CurrentZoneGroupID><CurrentZonePlayerUUIDsInGroup>RINCON_000E58A0123401400,RINCON_000E58B0123401400</CurrentZonePlayerUUIDsInGroup><CurrentMuseHouseholdId>Sonos_abcdef</CurrentMuseHouseholdId></u:GetZoneGroupAttributesResponse></s:Body></s:Envelope>"#,
+        )
+        .unwrap();
+        k9::snapshot!(
+            ZoneGroupAttributes::try_from(response).unwrap(),
+            r#"
+ZoneGroupAttributes {
+    current_zone_group_name: "Kitchen + 1",
+    current_zone_group_id: "RINCON_000E58A0123401400:3435548679",
+    member_uuids: [
+        "RINCON_000E58A0123401400",
+        "RINCON_000E58B0123401400",
+    ],
+    household_id: Some(
+        "Sonos_abcdef",
+    ),
+}
 "#
         );
     }